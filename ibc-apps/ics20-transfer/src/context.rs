@@ -1,7 +1,10 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::{Amount, Forwarding, Memo, PrefixedCoin, PrefixedDenom, TransferHook};
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::packet::Packet;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
@@ -59,14 +62,87 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
-    /// Returns a hash of the prefixed denom.
-    /// Implement only if the host chain supports hashed denominations.
-    fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
+    /// Returns the total supply of the given IBC voucher denom currently
+    /// minted on the host chain, or `None` if the host chain doesn't track
+    /// voucher supplies.
+    ///
+    /// Implement only if the host chain wants receives refused when minting
+    /// would overflow the supply. Hosts are then expected to keep the supply
+    /// in sync in
+    /// [`mint_coins_execute`](TokenTransferExecutionContext::mint_coins_execute)
+    /// and
+    /// [`burn_coins_execute`](TokenTransferExecutionContext::burn_coins_execute),
+    /// and to return zero for denoms that have never been minted.
+    fn get_denom_supply(
+        &self,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the amount of `denom` currently held in escrow for the given
+    /// channel, or `None` if the host chain doesn't track escrow balances.
+    ///
+    /// Implement only if the host chain wants the escrow invariant enforced,
+    /// i.e. that a channel never unescrows more than it has escrowed. Hosts
+    /// are then expected to keep the balance in sync in
+    /// [`escrow_coins_execute`](TokenTransferExecutionContext::escrow_coins_execute)
+    /// and
+    /// [`unescrow_coins_execute`](TokenTransferExecutionContext::unescrow_coins_execute).
+    fn escrowed_amount(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the total amount of `denom` currently held in escrow across all
+    /// channels, or `None` if the host chain doesn't track escrow balances.
+    ///
+    /// Hosts tracking escrow balances are expected to return zero for denoms
+    /// that have never been escrowed.
+    fn total_escrowed_amount(
+        &self,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the hash of the prefixed denom, as reported in the `trace_hash`
+    /// of the denomination trace event emitted when vouchers are minted.
+    ///
+    /// Defaults to the upper-case hex SHA-256 hash of the full denom trace,
+    /// i.e. [`denom_hash`](PrefixedDenom::denom_hash), as done by ibc-go.
+    /// Override only if the host chain hashes denominations differently, or
+    /// return `None` if it doesn't support hashed denominations.
+    fn denom_hash_string(&self, denom: &PrefixedDenom) -> Option<String> {
+        Some(denom.denom_hash())
+    }
+
+    /// Returns the denom trace whose [`denom_hash`](PrefixedDenom::denom_hash)
+    /// is `hash`, hex-encoded in upper case.
+    /// Implement only if the host chain keeps track of the denom traces of the
+    /// vouchers it mints.
+    fn denom_trace(&self, _hash: &str) -> Option<PrefixedDenom> {
         None
     }
+
+    /// Returns all the denom traces known to the host chain.
+    /// Implement only if the host chain keeps track of the denom traces of the
+    /// vouchers it mints.
+    fn denom_traces(&self) -> Vec<PrefixedDenom> {
+        Vec::new()
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
+///
+/// Also carries the ICS20 "hooks" callbacks, `on_recv_transfer`,
+/// `on_ack_transfer` and `on_timeout_transfer`, as provided methods rather than
+/// on a separate `TransferHooks` trait, so that hosts without contract calls
+/// need not implement anything for them.
 pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
     /// Executes the escrow of the tokens in a user account.
     ///
@@ -91,6 +167,8 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
     ) -> Result<(), TokenTransferError>;
 
     /// Executes minting of the tokens in a user account.
+    ///
+    /// The minted amount must be added to the supply of the coin's denom.
     fn mint_coins_execute(
         &mut self,
         account: &Self::AccountId,
@@ -99,6 +177,9 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
 
     /// Executes burning of the tokens in a user account.
     ///
+    /// The burned amount must be subtracted from the supply of the coin's
+    /// denom.
+    ///
     /// `memo` field allows to incorporate additional contextual details in the
     /// burn execution.
     fn burn_coins_execute(
@@ -107,4 +188,73 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Hands off received tokens whose memo carries packet-forward
+    /// instructions, once they have been credited to the receiver.
+    ///
+    /// The host may send the tokens on to the next hop as instructed. Errors
+    /// are logged rather than failing the receipt, so that the tokens stay
+    /// with the receiver. Does nothing by default.
+    fn forward_coins_execute(
+        &mut self,
+        _receiver: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _forwarding: &Forwarding,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Calls the contract requested by the memo of a received packet, once
+    /// its tokens have been credited to the receiver, following the ICS20
+    /// "hooks" pattern.
+    ///
+    /// Invoked for memos carrying a `{"wasm": {...}}` or `{"evm": {...}}`
+    /// directive, which the host parses from the memo of `data`. `coin` is the
+    /// token as credited to the receiver, i.e. with its denomination on this
+    /// chain.
+    ///
+    /// On failure, the host must leave its state as it was before the call:
+    /// the transfer module then takes the tokens back from the receiver and
+    /// writes an error acknowledgement, so that they are refunded on the
+    /// sending chain. The hook is only called once taking the tokens back has
+    /// been validated. Does nothing by default.
+    fn on_recv_transfer(
+        &mut self,
+        _packet: &Packet,
+        _data: &PacketData,
+        _coin: &PrefixedCoin,
+        _hook: TransferHook,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Calls the contract requested by the memo of a sent packet, once its
+    /// acknowledgement has been processed, i.e. after the tokens have been
+    /// refunded on an error acknowledgement.
+    ///
+    /// Errors are logged rather than failing the acknowledgement, as the
+    /// packet is settled by then. Does nothing by default.
+    fn on_ack_transfer(
+        &mut self,
+        _packet: &Packet,
+        _data: &PacketData,
+        _acknowledgement: &AcknowledgementStatus,
+        _hook: TransferHook,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Calls the contract requested by the memo of a timed out packet, once
+    /// its tokens have been refunded.
+    ///
+    /// Errors are logged rather than failing the timeout, as the packet is
+    /// settled by then. Does nothing by default.
+    fn on_timeout_transfer(
+        &mut self,
+        _packet: &Packet,
+        _data: &PacketData,
+        _hook: TransferHook,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
 }
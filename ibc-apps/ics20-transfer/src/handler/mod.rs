@@ -3,14 +3,40 @@ mod on_recv_packet;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
 use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::{is_sender_chain_source, PrefixedCoin};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Checks that unescrowing `coin` from the given channel doesn't take the
+/// escrowed amount of its denom below zero.
+///
+/// This is a no-op for host chains that don't track escrow balances; see
+/// [`TokenTransferValidationContext::escrowed_amount`].
+pub fn verify_escrow_invariant(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    match ctx.escrowed_amount(port_id, channel_id, &coin.denom)? {
+        Some(escrowed) if escrowed < coin.amount => {
+            Err(TokenTransferError::EscrowInvariantViolated {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                denom: coin.denom.to_string(),
+                unescrow_attempt: coin.amount.to_string(),
+                escrowed: escrowed.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
@@ -56,6 +82,12 @@ pub fn refund_packet_token_validate(
         packet.chan_id_on_a.clone(),
         &data.token.denom,
     ) {
+        verify_escrow_invariant(
+            ctx_a,
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &data.token,
+        )?;
         ctx_a.unescrow_coins_validate(
             &sender,
             &packet.port_id_on_a,
@@ -1,11 +1,12 @@
-use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::error::{AckErrorReason, TokenTransferError};
 use ibc_app_transfer_types::events::DenomTraceEvent;
 use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, TracePrefix};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::verify_escrow_invariant;
 use crate::context::TokenTransferExecutionContext;
 
 /// This function handles the transfer receiving logic.
@@ -19,26 +20,45 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     packet: &Packet,
     data: PacketData,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
+    process_recv_packet_with_reason(ctx_b, packet, data)
+        .map_err(|(extras, _, token_err)| (extras, token_err))
+}
+
+/// Same as [`process_recv_packet_execute`], also returning on failure the
+/// [`AckErrorReason`] to report in the error acknowledgement.
+pub(crate) fn process_recv_packet_with_reason<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+    data: PacketData,
+) -> Result<ModuleExtras, (ModuleExtras, AckErrorReason, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
-        .map_err(|err| (ModuleExtras::empty(), err))?;
+        .map_err(|err| (ModuleExtras::empty(), AckErrorReason::ReceiveDisabled, err))?;
 
     let receiver_account = data.receiver.clone().try_into().map_err(|_| {
         (
             ModuleExtras::empty(),
+            AckErrorReason::ParseFailure,
             TokenTransferError::ParseAccountFailure,
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
+    // move any trace prefixes carried in the base denom into the trace path,
+    // so that equivalent traces don't end up as distinct vouchers
+    let mut token = data.token.clone();
+    token.denom = token.denom.normalize();
+
+    let receiver_is_source = is_receiver_chain_source(
         packet.port_id_on_a.clone(),
         packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
+        &token.denom,
+    );
+
+    let (mut extras, coin) = if receiver_is_source {
         // sender chain is not the source, unescrow tokens
         let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
         let coin = {
-            let mut c = data.token;
+            let mut c = token;
             c.denom.remove_trace_prefix(&prefix);
             c
         };
@@ -53,6 +73,15 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         // a `TokenTransferAcknowledgement::Error` acknowledgement, which
         // gets relayed back to the sender so that the escrowed tokens
         // can be refunded.
+        verify_escrow_invariant(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b, &coin).map_err(
+            |token_err| {
+                (
+                    ModuleExtras::empty(),
+                    AckErrorReason::DenomTraceMismatch,
+                    token_err,
+                )
+            },
+        )?;
         ctx_b
             .unescrow_coins_validate(
                 &receiver_account,
@@ -60,7 +89,13 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
                 &packet.chan_id_on_b,
                 &coin,
             )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+            .map_err(|token_err| {
+                (
+                    ModuleExtras::empty(),
+                    AckErrorReason::UnescrowFailure,
+                    token_err,
+                )
+            })?;
         ctx_b
             .unescrow_coins_execute(
                 &receiver_account,
@@ -68,14 +103,20 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
                 &packet.chan_id_on_b,
                 &coin,
             )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+            .map_err(|token_err| {
+                (
+                    ModuleExtras::empty(),
+                    AckErrorReason::UnescrowFailure,
+                    token_err,
+                )
+            })?;
 
-        ModuleExtras::empty()
+        (ModuleExtras::empty(), coin)
     } else {
         // sender chain is the source, mint vouchers
         let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
         let coin = {
-            let mut c = data.token;
+            let mut c = token;
             c.denom.add_trace_prefix(prefix);
             c
         };
@@ -91,6 +132,21 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
             }
         };
 
+        // Hosts may track balances with fixed-width integers, so make sure
+        // near-max amounts can't wrap the voucher supply around.
+        ctx_b
+            .get_denom_supply(&coin.denom)
+            .and_then(|supply| match supply {
+                Some(supply) if supply.checked_add(coin.amount).is_none() => {
+                    Err(TokenTransferError::AmountOverflow {
+                        denom: coin.denom.to_string(),
+                        amount: coin.amount.to_string(),
+                    })
+                }
+                _ => Ok(()),
+            })
+            .map_err(|token_err| (extras.clone(), AckErrorReason::MintFailure, token_err))?;
+
         // Note: it is correct to do the validation here because `recv_packet()`
         // works slightly differently. We do not have a
         // `on_recv_packet_validate()` callback because regardless of whether or
@@ -103,14 +159,104 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         // can be refunded.
         ctx_b
             .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
+            .map_err(|token_err| (extras.clone(), AckErrorReason::MintFailure, token_err))?;
 
         ctx_b
             .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
+            .map_err(|token_err| (extras.clone(), AckErrorReason::MintFailure, token_err))?;
 
-        extras
+        (extras, coin)
     };
 
+    if let Some(hook) = data.memo.hook() {
+        // An error acknowledgement refunds the sender, so the hook may only
+        // fail the receipt if the credited tokens can be taken back. If they
+        // can't, the contract isn't called and the tokens stay credited.
+        let undo_res = undo_credit_validate(
+            ctx_b,
+            receiver_is_source,
+            &receiver_account,
+            packet,
+            &coin,
+            &data,
+        );
+
+        if let Err(undo_err) = undo_res {
+            extras
+                .log
+                .push(format!("skipped the {hook} hook: {undo_err}"));
+        } else if let Err(hook_err) = ctx_b.on_recv_transfer(packet, &data, &coin, hook) {
+            let undo_res = undo_credit_execute(
+                ctx_b,
+                receiver_is_source,
+                &receiver_account,
+                packet,
+                &coin,
+                &data,
+            );
+
+            if let Err(undo_err) = undo_res {
+                // the tokens are still credited, so the receipt must succeed
+                // lest they are also refunded on the sending chain
+                extras.log.push(hook_err.to_string());
+                extras.log.push(undo_err.to_string());
+            } else {
+                return Err((extras, AckErrorReason::HookFailure, hook_err));
+            }
+        }
+    }
+
+    if let Some(forwarding) = data.memo.forwarding() {
+        if let Err(err) = ctx_b.forward_coins_execute(&receiver_account, &coin, &forwarding) {
+            extras.log.push(err.to_string());
+        }
+    }
+
     Ok(extras)
 }
+
+/// Validates that the tokens credited by a receipt can be taken back, by
+/// escrowing the unescrowed tokens again or burning the minted vouchers.
+fn undo_credit_validate<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &Ctx,
+    receiver_is_source: bool,
+    receiver_account: &Ctx::AccountId,
+    packet: &Packet,
+    coin: &PrefixedCoin,
+    data: &PacketData,
+) -> Result<(), TokenTransferError> {
+    if receiver_is_source {
+        ctx_b.escrow_coins_validate(
+            receiver_account,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            coin,
+            &data.memo,
+        )
+    } else {
+        ctx_b.burn_coins_validate(receiver_account, coin, &data.memo)
+    }
+}
+
+/// Takes back the tokens credited by a receipt, as validated by
+/// [`undo_credit_validate`].
+fn undo_credit_execute<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    receiver_is_source: bool,
+    receiver_account: &Ctx::AccountId,
+    packet: &Packet,
+    coin: &PrefixedCoin,
+    data: &PacketData,
+) -> Result<(), TokenTransferError> {
+    if receiver_is_source {
+        ctx_b.escrow_coins_execute(
+            receiver_account,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            coin,
+            &data.memo,
+        )
+    } else {
+        ctx_b.burn_coins_execute(receiver_account, coin, &data.memo)
+    }
+}
@@ -1,4 +1,4 @@
-use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::error::{AckErrorReason, TokenTransferError};
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
 use ibc_app_transfer_types::packet::PacketData;
 use ibc_app_transfer_types::{ack_success_b64, VERSION};
@@ -14,7 +14,7 @@ use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
-    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    process_recv_packet_with_reason, refund_packet_token_execute, refund_packet_token_validate,
 };
 
 pub fn on_chan_open_init_validate(
@@ -171,14 +171,18 @@ pub fn on_recv_packet_execute(
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
     let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
-        return (ModuleExtras::empty(), ack.into());
+        let ack = AcknowledgementStatus::error(AckErrorReason::ParseFailure.into());
+        let extras = ModuleExtras::empty()
+            .with_log(TokenTransferError::PacketDataDeserialization.to_string());
+        return (extras, ack.into());
     };
 
-    let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
+    let (mut extras, ack) = match process_recv_packet_with_reason(ctx_b, packet, data.clone()) {
         Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
-        Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
+        Err((mut extras, reason, error)) => {
+            extras.log.push(error.to_string());
+            (extras, AcknowledgementStatus::error(reason.into()))
+        }
     };
 
     let recv_event = RecvEvent {
@@ -244,6 +248,12 @@ pub fn on_acknowledgement_packet_execute(
         }
     }
 
+    let hook_log = data.memo.hook().and_then(|hook| {
+        ctx.on_ack_transfer(packet, &data, &acknowledgement, hook)
+            .err()
+            .map(|err| err.to_string())
+    });
+
     let ack_event = AckEvent {
         sender: data.sender,
         receiver: data.receiver,
@@ -253,10 +263,10 @@ pub fn on_acknowledgement_packet_execute(
         acknowledgement: acknowledgement.clone(),
     };
 
-    let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
-        log: Vec::new(),
-    };
+    let mut extras = ModuleExtras::empty()
+        .with_event(ack_event)
+        .with_event(AckStatusEvent { acknowledgement });
+    extras.log.extend(hook_log);
 
     (extras, Ok(()))
 }
@@ -293,6 +303,12 @@ pub fn on_timeout_packet_execute(
         return (ModuleExtras::empty(), Err(err));
     }
 
+    let hook_log = data.memo.hook().and_then(|hook| {
+        ctx.on_timeout_transfer(packet, &data, hook)
+            .err()
+            .map(|err| err.to_string())
+    });
+
     let timeout_event = TimeoutEvent {
         refund_receiver: data.sender,
         refund_denom: data.token.denom,
@@ -300,10 +316,8 @@ pub fn on_timeout_packet_execute(
         memo: data.memo,
     };
 
-    let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
-        log: Vec::new(),
-    };
+    let mut extras = ModuleExtras::empty().with_event(timeout_event);
+    extras.log.extend(hook_log);
 
     (extras, Ok(()))
 }
@@ -354,6 +368,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_ack_error_reason() {
+        let ack_error: Vec<u8> =
+            AcknowledgementStatus::error(AckErrorReason::DenomTraceMismatch.into()).into();
+
+        assert_eq!(ack_error, br#"{"error":"denom trace mismatch"}"#);
+
+        let ack = serde_json::from_slice::<AcknowledgementStatus>(&ack_error).unwrap();
+        assert_eq!(
+            AckErrorReason::from_ack_status(&ack),
+            Some(AckErrorReason::DenomTraceMismatch)
+        );
+
+        let ack = AcknowledgementStatus::success(ack_success_b64());
+        assert_eq!(AckErrorReason::from_ack_status(&ack), None);
+    }
+
     #[test]
     fn test_ack_de() {
         fn de_json_assert_eq(json_str: &str, ack: AcknowledgementStatus) {
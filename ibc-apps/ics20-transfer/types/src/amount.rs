@@ -7,6 +7,7 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use primitive_types::U256;
+use uint::FromDecStrErr;
 
 use super::error::TokenTransferError;
 
@@ -99,6 +100,17 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    /// Parses an amount from an integer (`"1000000"`), decimal
+    /// (`"1000000.0"`) or scientific (`"1.0e6"`, `"1E6"`) notation.
+    ///
+    /// Only whole-number results are accepted: `"1.5"` or `"15e-1"` fail with
+    /// [`TokenTransferError::FractionalAmount`] rather than being truncated.
+    /// Unlike [`Amount::from_str`], which packet data is decoded with, this is
+    /// meant for user input only.
+    pub fn from_decimal_str(s: &str) -> Result<Self, TokenTransferError> {
+        Self::from_str(&normalize_amount(s)?)
+    }
 }
 
 impl AsRef<U256> for Amount {
@@ -116,6 +128,68 @@ impl FromStr for Amount {
     }
 }
 
+/// Rewrites an amount in decimal or scientific notation as an integer string.
+fn normalize_amount(s: &str) -> Result<String, TokenTransferError> {
+    // at most 78 decimal digits fit in a `U256`
+    const MAX_DIGITS: usize = 78;
+
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(s.to_string());
+    }
+
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => {
+            let exponent: i64 = exponent
+                .parse()
+                .map_err(|_| TokenTransferError::InvalidAmount(FromDecStrErr::InvalidCharacter))?;
+            (mantissa, exponent)
+        }
+        None => (s, 0),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(TokenTransferError::InvalidAmount(
+            FromDecStrErr::InvalidCharacter,
+        ));
+    }
+
+    let mut digits = format!("{integer}{fraction}");
+    let digits_len = digits.trim_start_matches('0').len();
+    let shift = exponent.saturating_sub(fraction.len() as i64);
+
+    if shift < 0 {
+        let cut = usize::try_from(shift.unsigned_abs()).unwrap_or(usize::MAX);
+        let (whole, rest) = digits.split_at(digits.len().saturating_sub(cut));
+        if rest.bytes().any(|b| b != b'0') {
+            return Err(TokenTransferError::FractionalAmount {
+                amount: s.to_string(),
+            });
+        }
+        digits = whole.to_string();
+    } else if digits_len > 0 {
+        let shift = usize::try_from(shift)
+            .map_err(|_| TokenTransferError::InvalidAmount(FromDecStrErr::InvalidLength))?;
+        if digits_len.saturating_add(shift) > MAX_DIGITS {
+            return Err(TokenTransferError::InvalidAmount(
+                FromDecStrErr::InvalidLength,
+            ));
+        }
+        digits.push_str(&"0".repeat(shift));
+    }
+
+    if digits.is_empty() {
+        digits.push('0');
+    }
+
+    Ok(digits)
+}
+
 impl From<u64> for Amount {
     fn from(v: u64) -> Self {
         Self(v.into())
@@ -134,7 +208,58 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::Amount;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::integer("1000000", 1_000_000)]
+    #[case::decimal("1000000.000", 1_000_000)]
+    #[case::scientific("1.0e6", 1_000_000)]
+    #[case::scientific_upper("1E6", 1_000_000)]
+    #[case::positive_exponent("25e+3", 25_000)]
+    #[case::negative_exponent("1000e-3", 1)]
+    #[case::zero("0.0e9", 0)]
+    fn test_amount_from_decimal_str(#[case] s: &str, #[case] expected: u64) {
+        assert_eq!(Amount::from_decimal_str(s).unwrap(), Amount::from(expected));
+    }
+
+    #[rstest]
+    #[case("1000000.000")]
+    #[case("1.0e6")]
+    #[case("1E6")]
+    fn test_amount_from_str_is_integer_only(#[case] s: &str) {
+        assert!(matches!(
+            Amount::from_str(s),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("1.5")]
+    #[case("15e-1")]
+    #[case("1.0000001e6")]
+    fn test_fractional_amount(#[case] s: &str) {
+        assert!(matches!(
+            Amount::from_decimal_str(s),
+            Err(TokenTransferError::FractionalAmount { amount }) if amount == s
+        ));
+    }
+
+    #[rstest]
+    #[case(".")]
+    #[case("-1")]
+    #[case("1.0e")]
+    #[case("1e6.5")]
+    #[case("1e99999999999")]
+    // truncates to `1e1` on 32-bit targets if the exponent is cast
+    #[case("1e4294967297")]
+    fn test_invalid_amount(#[case] s: &str) {
+        assert!(matches!(
+            Amount::from_decimal_str(s),
+            Err(TokenTransferError::InvalidAmount(_))
+        ));
+    }
 
     #[cfg(feature = "serde")]
     #[test]
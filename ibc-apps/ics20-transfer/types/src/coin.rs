@@ -118,6 +118,62 @@ impl From<BaseCoin> for PrefixedCoin {
     }
 }
 
+impl PrefixedCoin {
+    /// Adds the amount of `other` to this coin.
+    ///
+    /// Fails if the two coins don't have the same denom, or if the sum
+    /// overflows.
+    pub fn checked_add(&self, other: &PrefixedCoin) -> Result<Self, TokenTransferError> {
+        self.ensure_same_denom(other)?;
+
+        let amount = self.amount.checked_add(other.amount).ok_or_else(|| {
+            TokenTransferError::CoinOverflow {
+                coin: self.to_string(),
+                amount: other.amount.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+
+    /// Subtracts the amount of `other` from this coin.
+    ///
+    /// Fails if the two coins don't have the same denom, or if `other` is
+    /// larger than this coin.
+    pub fn checked_sub(&self, other: &PrefixedCoin) -> Result<Self, TokenTransferError> {
+        self.ensure_same_denom(other)?;
+
+        let amount = self.amount.checked_sub(other.amount).ok_or_else(|| {
+            TokenTransferError::CoinUnderflow {
+                coin: self.to_string(),
+                amount: other.amount.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+
+    /// Denoms are compared by their full path, so that a denom built with its
+    /// trace folded into the base denom matches the same denom parsed from
+    /// its string form.
+    fn ensure_same_denom(&self, other: &PrefixedCoin) -> Result<(), TokenTransferError> {
+        if self.denom != other.denom && self.denom.to_string() != other.denom.to_string() {
+            return Err(TokenTransferError::DenomMismatch {
+                expected: self.denom.to_string(),
+                actual: other.denom.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 impl<D: Display> Display for Coin<D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(f, "{}{}", self.amount, self.denom)
@@ -130,6 +186,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::denom::TracePath;
 
     #[rstest]
     #[case::nat("123stake", 123, "stake")]
@@ -192,6 +249,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_prefixed_coin_arithmetic() {
+        let coin = |s: &str| PrefixedCoin::from_str(s).unwrap();
+
+        assert_eq!(
+            coin("30transfer/channel-0/uatom")
+                .checked_add(&coin("12transfer/channel-0/uatom"))
+                .unwrap(),
+            coin("42transfer/channel-0/uatom")
+        );
+        assert_eq!(
+            coin("30stake").checked_sub(&coin("30stake")).unwrap(),
+            coin("0stake")
+        );
+
+        // the same denom with its trace folded into the base denom
+        let folded = PrefixedCoin {
+            denom: PrefixedDenom {
+                trace_path: TracePath::empty(),
+                base_denom: BaseDenom::from_str("transfer/channel-0/uatom").unwrap(),
+            },
+            amount: 5u64.into(),
+        };
+        assert_eq!(
+            coin("30transfer/channel-0/uatom")
+                .checked_sub(&folded)
+                .unwrap(),
+            coin("25transfer/channel-0/uatom")
+        );
+
+        assert!(matches!(
+            coin("30transfer/channel-0/uatom").checked_add(&coin("1transfer/channel-1/uatom")),
+            Err(TokenTransferError::DenomMismatch { .. })
+        ));
+        assert!(matches!(
+            coin("30stake").checked_sub(&coin("31stake")),
+            Err(TokenTransferError::CoinUnderflow { .. })
+        ));
+
+        let max = PrefixedCoin {
+            denom: PrefixedDenom::from_str("stake").unwrap(),
+            amount: U256::MAX.into(),
+        };
+        assert!(matches!(
+            max.checked_add(&coin("1stake")),
+            Err(TokenTransferError::CoinOverflow { .. })
+        ));
+    }
+
     #[rstest]
     #[case::semicolon_delimiter("123stake;1a1;999den0m")]
     #[case::mixed_delimiter("123stake,1a1;999den0m")]
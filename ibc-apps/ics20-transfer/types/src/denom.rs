@@ -8,8 +8,10 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+use sha2::{Digest, Sha256};
 
 use super::error::TokenTransferError;
+use crate::IBC_DENOM_PREFIX;
 
 /// The "base" of a denomination.
 ///
@@ -271,6 +273,46 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the SHA-256 hash of the full denom trace, hex-encoded in upper
+    /// case.
+    pub fn denom_hash(&self) -> String {
+        Sha256::digest(self.to_string().as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect()
+    }
+
+    /// Returns the canonical form of this denom, in which any trace prefixes
+    /// the base denom starts with are moved into the trace path.
+    ///
+    /// This is the form [`PrefixedDenom::from_str`] parses the full denom
+    /// into, so a denom received as a `DenomTrace` normalizes to the same
+    /// value, and hashes to the same `ibc/{hash}`, as its string form. The
+    /// base denom is otherwise left untouched: slashes are valid in a base
+    /// denom, and dropping some would merge distinct tokens.
+    pub fn normalize(&self) -> PrefixedDenom {
+        let denom = if self.trace_path.is_empty() {
+            self.base_denom.to_string()
+        } else {
+            format!("{}/{}", self.trace_path, self.base_denom)
+        };
+
+        // the base denom is not empty, so parsing always leaves a base denom
+        // behind the trace prefixes it strips
+        PrefixedDenom::from_str(&denom).unwrap_or_else(|_| self.clone())
+    }
+
+    /// Returns the denom under which the host chain holds this token:
+    /// `ibc/{hash}` if the token came from another chain, or the base denom
+    /// itself otherwise.
+    pub fn ibc_denom(&self) -> String {
+        if self.trace_path.is_empty() {
+            self.base_denom.to_string()
+        } else {
+            format!("{IBC_DENOM_PREFIX}/{}", self.denom_hash())
+        }
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -407,6 +449,64 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(
+        "transfer/channel-0/uatom",
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+    )]
+    #[case("uatom", "uatom")]
+    fn test_ibc_denom(#[case] denom: &str, #[case] ibc_denom: &str) {
+        let denom = PrefixedDenom::from_str(denom).unwrap();
+
+        assert_eq!(denom.ibc_denom(), ibc_denom);
+    }
+
+    #[rstest]
+    #[case("", "transfer/channel-0/uatom", "transfer/channel-0/uatom")]
+    #[case(
+        "transfer/channel-0",
+        "transfer/channel-1/uatom",
+        "transfer/channel-0/transfer/channel-1/uatom"
+    )]
+    #[case(
+        "",
+        "transfer/channel-0/transfer/channel-1/gamm/pool/1",
+        "transfer/channel-0/transfer/channel-1/gamm/pool/1"
+    )]
+    fn test_normalize_equivalent_traces(
+        #[case] path: &str,
+        #[case] base_denom: &str,
+        #[case] full_denom: &str,
+    ) {
+        let canonical = PrefixedDenom::from_str(full_denom).unwrap();
+
+        let denom = PrefixedDenom::try_from(RawDenomTrace {
+            path: path.to_string(),
+            base_denom: base_denom.to_string(),
+        })
+        .unwrap();
+
+        assert_ne!(denom, canonical);
+        assert_eq!(denom.normalize(), canonical);
+        assert_eq!(denom.normalize().ibc_denom(), canonical.ibc_denom());
+        assert_eq!(canonical.normalize(), canonical);
+    }
+
+    #[rstest]
+    #[case("transfer/channel-0", "/uatom")]
+    #[case("transfer/channel-0", "uatom//")]
+    #[case("", "gamm/pool/1/")]
+    fn test_normalize_keeps_base_denom(#[case] path: &str, #[case] base_denom: &str) {
+        let denom = PrefixedDenom::try_from(RawDenomTrace {
+            path: path.to_string(),
+            base_denom: base_denom.to_string(),
+        })
+        .unwrap();
+
+        assert_eq!(denom.normalize(), denom);
+        assert_eq!(denom.normalize().base_denom.as_str(), base_denom);
+    }
+
     #[rstest]
     #[case("transfer")]
     #[case("transfer/channel-1/ica")]
@@ -597,6 +697,85 @@ mod tests {
         assert_eq!(prefixed_denom.to_string(), "uatom");
     }
 
+    /// Walks a denom through a transfer over `transfer/channel-0`, checking the
+    /// source determination on both ends against the ibc-go semantics.
+    #[rstest]
+    #[case::native("uatom", false, "transfer/channel-0/uatom")]
+    #[case::single_hop(
+        "transfer/channel-5/uatom",
+        false,
+        "transfer/channel-0/transfer/channel-5/uatom"
+    )]
+    #[case::return_single_hop("transfer/channel-0/uatom", true, "uatom")]
+    #[case::return_multi_hop(
+        "transfer/channel-0/transfer/channel-5/uatom",
+        true,
+        "transfer/channel-5/uatom"
+    )]
+    #[case::inner_hop_only(
+        "transfer/channel-5/transfer/channel-0/uatom",
+        false,
+        "transfer/channel-0/transfer/channel-5/transfer/channel-0/uatom"
+    )]
+    #[case::other_port(
+        "oracle/channel-0/uatom",
+        false,
+        "transfer/channel-0/oracle/channel-0/uatom"
+    )]
+    #[case::other_channel(
+        "transfer/channel-1/uatom",
+        false,
+        "transfer/channel-0/transfer/channel-1/uatom"
+    )]
+    fn test_transfer_trace_prefix(
+        #[case] denom: &str,
+        #[case] receiver_is_source: bool,
+        #[case] received_denom: &str,
+    ) {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+        let prefix = TracePrefix::new(port_id.clone(), channel_id.clone());
+        let sent = PrefixedDenom::from_str(denom).unwrap();
+
+        assert_eq!(
+            is_receiver_chain_source(port_id.clone(), channel_id.clone(), &sent),
+            receiver_is_source
+        );
+        assert_eq!(
+            is_sender_chain_source(port_id, channel_id, &sent),
+            !receiver_is_source
+        );
+
+        // the receiver strips the prefix of a returning token, and adds its
+        // own prefix otherwise
+        let mut received = sent.clone();
+        if receiver_is_source {
+            received.remove_trace_prefix(&prefix);
+        } else {
+            received.add_trace_prefix(prefix.clone());
+        }
+        assert_eq!(received.to_string(), received_denom);
+
+        // removing a prefix the trace does not start with leaves it unchanged
+        let mut unchanged = sent.clone();
+        unchanged.remove_trace_prefix(&TracePrefix::new(
+            "oracle".parse().unwrap(),
+            ChannelId::new(7),
+        ));
+        assert_eq!(unchanged, sent);
+
+        // adding then removing a prefix is a round trip, even for native denoms
+        let mut round_trip = sent.clone();
+        round_trip.add_trace_prefix(prefix.clone());
+        round_trip.remove_trace_prefix(&prefix);
+        assert_eq!(round_trip, sent);
+
+        // removing from a native denom is a no-op
+        let mut native = PrefixedDenom::from_str("uatom").unwrap();
+        native.remove_trace_prefix(&prefix);
+        assert_eq!(native.to_string(), "uatom");
+    }
+
     #[rstest]
     #[case("", TracePath::empty(), Some(""))]
     #[case("transfer", TracePath::empty(), Some("transfer"))]
@@ -1,9 +1,10 @@
 //! Defines the token transfer error type
 use core::convert::Infallible;
+use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::Utf8Error;
 
 use displaydoc::Display;
-use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
 use ibc_core::channel::types::channel::Order;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
@@ -22,11 +23,29 @@ pub enum TokenTransferError {
         send_attempt: String,
         available_funds: String,
     },
+    /// amount overflow: adding `{amount}` to the tracked total of `{denom}` exceeds the maximum amount
+    AmountOverflow { denom: String, amount: String },
+    /// coin overflow: adding `{amount}` to `{coin}` exceeds the maximum amount
+    CoinOverflow { coin: String, amount: String },
+    /// coin underflow: subtracting `{amount}` from `{coin}` goes below zero
+    CoinUnderflow { coin: String, amount: String },
+    /// denom mismatch: expected `{expected}`, got `{actual}`
+    DenomMismatch { expected: String, actual: String },
+    /// escrow invariant violated: unescrowing `{unescrow_attempt}` of `{denom}` on port_id `{port_id}` and channel_id `{channel_id}` exceeds the escrowed `{escrowed}`
+    EscrowInvariantViolated {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: String,
+        unescrow_attempt: String,
+        escrowed: String,
+    },
     /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
     DestinationChannelNotFound {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// no denom trace found for hash `{hash}`
+    DenomTraceNotFound { hash: String },
     /// base denomination is empty
     EmptyBaseDenom,
     /// invalid prot id n trace at position: `{pos}`, validation error: `{validation_error}`
@@ -45,6 +64,8 @@ pub enum TokenTransferError {
     InvalidTraceLength { len: u64 },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// amount `{amount}` is not a whole number
+    FractionalAmount { amount: String },
     /// invalid token
     InvalidToken,
     /// expected `{expect_order}` channel, got `{got_order}`
@@ -125,3 +146,74 @@ impl From<TokenTransferError> for StatusValue {
         StatusValue::new(err.to_string()).expect("error message must not be empty")
     }
 }
+
+/// Reason why an ICS-20 packet failed to be received, as reported in the error
+/// acknowledgement written for it.
+///
+/// Error acknowledgements only carry the reason's message, so that they keep
+/// the `{"error":"..."}` format of ibc-go while staying deterministic. The
+/// underlying error is left to the module's logs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AckErrorReason {
+    /// The packet data or the receiver account could not be parsed.
+    ParseFailure,
+    /// The host chain doesn't accept incoming transfers.
+    ReceiveDisabled,
+    /// The returning tokens don't match the ones escrowed for their trace.
+    DenomTraceMismatch,
+    /// The host chain failed to unescrow the returning tokens.
+    UnescrowFailure,
+    /// The host chain failed to mint the vouchers.
+    MintFailure,
+    /// The contract call requested by the memo failed.
+    HookFailure,
+}
+
+impl AckErrorReason {
+    const ALL: [Self; 6] = [
+        Self::ParseFailure,
+        Self::ReceiveDisabled,
+        Self::DenomTraceMismatch,
+        Self::UnescrowFailure,
+        Self::MintFailure,
+        Self::HookFailure,
+    ];
+
+    /// Returns the message carried by error acknowledgements for this reason.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ParseFailure => "failed to parse packet data",
+            Self::ReceiveDisabled => "receive is disabled",
+            Self::DenomTraceMismatch => "denom trace mismatch",
+            Self::UnescrowFailure => "failed to unescrow tokens",
+            Self::MintFailure => "failed to mint vouchers",
+            Self::HookFailure => "contract call failed",
+        }
+    }
+
+    /// Returns the reason an error acknowledgement was written for, or `None`
+    /// if the acknowledgement is successful or its message isn't one of ours.
+    pub fn from_ack_status(ack: &AcknowledgementStatus) -> Option<Self> {
+        match ack {
+            AcknowledgementStatus::Error(value) => {
+                let message = value.to_string();
+                Self::ALL
+                    .into_iter()
+                    .find(|reason| reason.as_str() == message)
+            }
+            AcknowledgementStatus::Success(_) => None,
+        }
+    }
+}
+
+impl Display for AckErrorReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<AckErrorReason> for StatusValue {
+    fn from(reason: AckErrorReason) -> Self {
+        StatusValue::new(reason.as_str()).expect("error message must not be empty")
+    }
+}
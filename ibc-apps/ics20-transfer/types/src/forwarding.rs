@@ -0,0 +1,131 @@
+//! Defines the instructions of the packet-forward middleware (PFM), which
+//! routes a transfer through intermediate chains.
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// A hop of a multi-hop transfer, as carried by a memo of the form
+/// `{"forward": {"receiver": ..., "port": ..., "channel": ..., "next": ...}}`.
+///
+/// The receiving chain is asked to send the tokens on to `receiver` over the
+/// given port and channel. `next`, if set, holds the instructions for the
+/// chain after that one, either inline or as a JSON string. The `timeout`
+/// and `retries` fields of PFM are left to the forwarding host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forwarding {
+    pub receiver: Signer,
+    #[cfg_attr(feature = "serde", serde(rename = "port"))]
+    pub port_id: PortId,
+    #[cfg_attr(feature = "serde", serde(rename = "channel"))]
+    pub channel_id: ChannelId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "next_hop", skip_serializing_if = "Option::is_none")
+    )]
+    pub next: Option<Box<Forwarding>>,
+}
+
+/// The memo of a transfer to forward, which may carry other fields besides
+/// the forwarding instructions.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct ForwardingMemo {
+    pub forward: Forwarding,
+}
+
+#[cfg(feature = "serde")]
+mod next_hop {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// PFM accepts the next hop either as a nested memo or as a string
+    /// holding one.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NextHop {
+        Memo(ForwardingMemo),
+        Json(String),
+    }
+
+    pub fn serialize<S: Serializer>(
+        next: &Option<Box<Forwarding>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        next.as_ref()
+            .map(|forwarding| ForwardingMemo {
+                forward: forwarding.as_ref().clone(),
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Box<Forwarding>>, D::Error> {
+        let memo = match Option::<NextHop>::deserialize(deserializer)? {
+            None => return Ok(None),
+            Some(NextHop::Memo(memo)) => memo,
+            Some(NextHop::Json(json)) => {
+                serde_json::from_str::<ForwardingMemo>(&json).map_err(serde::de::Error::custom)?
+            }
+        };
+
+        Ok(Some(Box::new(memo.forward)))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Memo;
+
+    fn hop(receiver: &str, channel_id: u64, next: Option<Forwarding>) -> Forwarding {
+        Forwarding {
+            receiver: receiver.to_string().into(),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(channel_id),
+            next: next.map(Box::new),
+        }
+    }
+
+    #[rstest]
+    #[case::single_hop(
+        r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-1","timeout":"10m","retries":2}}"#,
+        hop("osmo1", 1, None)
+    )]
+    #[case::nested_next(
+        r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-1","next":{"forward":{"receiver":"juno1","port":"transfer","channel":"channel-2"}}}}"#,
+        hop("osmo1", 1, Some(hop("juno1", 2, None)))
+    )]
+    #[case::stringified_next(
+        r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-1","next":"{\"forward\":{\"receiver\":\"juno1\",\"port\":\"transfer\",\"channel\":\"channel-2\"}}"}}"#,
+        hop("osmo1", 1, Some(hop("juno1", 2, None)))
+    )]
+    fn test_parse_forwarding_memo(#[case] memo: &str, #[case] forwarding: Forwarding) {
+        assert_eq!(Memo::from(memo).forwarding(), Some(forwarding));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::text("thanks for the tokens")]
+    #[case::other_json(r#"{"wasm":{"contract":"osmo1","msg":{}}}"#)]
+    #[case::missing_channel(r#"{"forward":{"receiver":"osmo1","port":"transfer"}}"#)]
+    fn test_opaque_memo(#[case] memo: &str) {
+        assert_eq!(Memo::from(memo).forwarding(), None);
+    }
+
+    #[test]
+    fn test_forwarding_roundtrip() {
+        let forwarding = hop("osmo1", 1, Some(hop("juno1", 2, None)));
+
+        let memo = serde_json::to_string(&ForwardingMemo {
+            forward: forwarding.clone(),
+        })
+        .unwrap();
+
+        assert_eq!(Memo::from(memo).forwarding(), Some(forwarding));
+    }
+}
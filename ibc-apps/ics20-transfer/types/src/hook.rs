@@ -0,0 +1,87 @@
+//! Defines the contract calls that a transfer memo may request, following the
+//! ICS20 "hooks" pattern.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+#[cfg(feature = "serde")]
+use ibc_core::primitives::prelude::*;
+
+/// The kind of contract call requested by a memo of the form
+/// `{"wasm": {...}}` or `{"evm": {...}}`.
+///
+/// The directive object itself is left to the host to parse from the memo, as
+/// its schema (contract address, call data, ...) depends on the host's
+/// execution environment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferHook {
+    Wasm,
+    Evm,
+}
+
+impl TransferHook {
+    /// Returns the memo key under which the hook directive is carried.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Wasm => "wasm",
+            Self::Evm => "evm",
+        }
+    }
+}
+
+impl Display for TransferHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The memo of a transfer carrying a hook directive, which may carry other
+/// fields besides it. Directives that aren't JSON objects are rejected.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct HookMemo {
+    wasm: Option<Directive>,
+    evm: Option<Directive>,
+}
+
+#[cfg(feature = "serde")]
+type Directive = BTreeMap<String, serde::de::IgnoredAny>;
+
+#[cfg(feature = "serde")]
+impl HookMemo {
+    pub fn hook(&self) -> Option<TransferHook> {
+        match (&self.wasm, &self.evm) {
+            (Some(_), _) => Some(TransferHook::Wasm),
+            (None, Some(_)) => Some(TransferHook::Evm),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Memo;
+
+    #[rstest]
+    #[case::wasm(
+        r#"{"wasm":{"contract":"osmo1","msg":{"swap":{}}}}"#,
+        TransferHook::Wasm
+    )]
+    #[case::evm(r#"{"evm":{"contract":"0x5f","data":"0xabcd"}}"#, TransferHook::Evm)]
+    #[case::extra_fields(r#"{"note":"hi","wasm":{"contract":"osmo1"}}"#, TransferHook::Wasm)]
+    fn test_parse_hook_memo(#[case] memo: &str, #[case] hook: TransferHook) {
+        assert_eq!(Memo::from(memo).hook(), Some(hook));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::text("thanks for the tokens")]
+    #[case::not_an_object(r#"{"wasm":"osmo1"}"#)]
+    #[case::forwarding(
+        r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-1"}}"#
+    )]
+    fn test_no_hook(#[case] memo: &str) {
+        assert_eq!(Memo::from(memo).hook(), None);
+    }
+}
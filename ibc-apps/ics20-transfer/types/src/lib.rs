@@ -18,11 +18,15 @@ extern crate std;
 mod amount;
 mod coin;
 mod denom;
+mod forwarding;
+mod hook;
 mod memo;
 
 pub use amount::*;
 pub use coin::*;
 pub use denom::*;
+pub use forwarding::*;
+pub use hook::*;
 pub mod error;
 pub mod events;
 pub mod msgs;
@@ -43,6 +47,10 @@ pub const MODULE_ID_STR: &str = "transfer";
 /// typically bind with.
 pub const PORT_ID_STR: &str = "transfer";
 
+/// The prefix of the denoms under which the host chain holds tokens received
+/// from other chains, followed by the hash of their denom trace.
+pub const IBC_DENOM_PREFIX: &str = "ibc";
+
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
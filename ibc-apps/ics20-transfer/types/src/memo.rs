@@ -9,6 +9,11 @@ use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
 
+#[cfg(feature = "serde")]
+use crate::forwarding::{Forwarding, ForwardingMemo};
+#[cfg(feature = "serde")]
+use crate::hook::{HookMemo, TransferHook};
+
 /// Represents the token transfer memo
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -27,6 +32,26 @@ use ibc_core::primitives::prelude::*;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Memo(String);
 
+impl Memo {
+    /// Returns the packet-forward instructions carried by the memo, or `None`
+    /// if the memo isn't a forwarding memo. Such memos are left as they are.
+    #[cfg(feature = "serde")]
+    pub fn forwarding(&self) -> Option<Forwarding> {
+        serde_json::from_str::<ForwardingMemo>(&self.0)
+            .ok()
+            .map(|memo| memo.forward)
+    }
+
+    /// Returns the contract call requested by the memo, or `None` if the memo
+    /// carries neither a `wasm` nor an `evm` hook directive.
+    #[cfg(feature = "serde")]
+    pub fn hook(&self) -> Option<TransferHook> {
+        serde_json::from_str::<HookMemo>(&self.0)
+            .ok()
+            .and_then(|memo| memo.hook())
+    }
+}
+
 impl AsRef<str> for Memo {
     fn as_ref(&self) -> &str {
         &self.0
@@ -3,6 +3,8 @@
 use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
+#[cfg(feature = "serde")]
+use ibc_core::primitives::utils::escape_html;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 
@@ -60,6 +62,49 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+#[cfg(feature = "serde")]
+impl PacketData {
+    /// Returns the JSON encoding that ibc-go commits to for this packet data.
+    ///
+    /// Unlike the `serde` representation, keys are sorted, empty fields (such
+    /// as an unset memo) are omitted and HTML characters are escaped, as done
+    /// by `GetBytes` in ibc-go. Indexers can hash these bytes to reproduce
+    /// packet commitments made by ibc-go chains.
+    pub fn to_canonical_json(&self) -> Vec<u8> {
+        /// Fields are declared in key order.
+        #[derive(serde::Serialize)]
+        struct CanonicalPacketData<'a> {
+            #[serde(skip_serializing_if = "is_empty")]
+            amount: &'a str,
+            #[serde(skip_serializing_if = "is_empty")]
+            denom: &'a str,
+            #[serde(skip_serializing_if = "is_empty")]
+            memo: &'a str,
+            #[serde(skip_serializing_if = "is_empty")]
+            receiver: &'a str,
+            #[serde(skip_serializing_if = "is_empty")]
+            sender: &'a str,
+        }
+
+        fn is_empty(field: &&str) -> bool {
+            field.is_empty()
+        }
+
+        let raw_pkt_data = RawPacketData::from(self.clone());
+
+        let json = serde_json::to_string(&CanonicalPacketData {
+            amount: &raw_pkt_data.amount,
+            denom: &raw_pkt_data.denom,
+            memo: &raw_pkt_data.memo,
+            receiver: &raw_pkt_data.receiver,
+            sender: &raw_pkt_data.sender,
+        })
+        .expect("serializing string fields never fails");
+
+        escape_html(&json).into_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
@@ -118,4 +163,27 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    /// The expected bytes were derived by hand from the encoding rules of
+    /// `FungibleTokenPacketData.GetBytes` in ibc-go, not generated by ibc-go.
+    #[test]
+    fn test_packet_data_canonical_json() {
+        let packet_data = PacketData::new_dummy();
+
+        assert_eq!(
+            String::from_utf8(packet_data.to_canonical_json()).unwrap(),
+            r#"{"amount":"10","denom":"uatom","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#
+        );
+
+        let packet_data = PacketData {
+            token: "100transfer/channel-0/uatom".parse().unwrap(),
+            memo: r#"{"note":"<a&b>"}"#.to_string().into(),
+            ..packet_data
+        };
+
+        assert_eq!(
+            String::from_utf8(packet_data.to_canonical_json()).unwrap(),
+            r#"{"amount":"100","denom":"transfer/channel-0/uatom","memo":"{\"note\":\"\u003ca\u0026b\u003e\"}","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#
+        );
+    }
 }
@@ -0,0 +1,53 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{Fee, PacketFee};
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::FeesInEscrowPath;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// Returns the fees escrowed for the packet identified by the given path,
+    /// in the order they were paid. Returns an empty list if there are none.
+    fn fees_in_escrow(&self, path: &FeesInEscrowPath) -> Result<Vec<PacketFee>, FeeError>;
+
+    /// Returns whether relayers may be incentivized for the packets sent on
+    /// the given channel.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, FeeError>;
+
+    /// Validates that the given fee can be escrowed from the payer account.
+    fn escrow_fee_validate(&self, payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Moves the total of the given fee from the payer account into the fee
+    /// escrow.
+    fn escrow_fee_execute(&mut self, payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError>;
+
+    /// Moves the given coins out of the fee escrow into the receiver account.
+    ///
+    /// Either all the coins are moved or, on error, none of them are: the
+    /// middleware keeps in escrow whatever it fails to pay out.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+
+    /// Stores the fees escrowed for the packet identified by the given path.
+    fn store_fees_in_escrow(
+        &mut self,
+        path: &FeesInEscrowPath,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError>;
+
+    /// Deletes the fees escrowed for the packet identified by the given path,
+    /// once they have been distributed.
+    fn delete_fees_in_escrow(&mut self, path: &FeesInEscrowPath) -> Result<(), FeeError>;
+}
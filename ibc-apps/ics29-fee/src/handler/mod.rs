@@ -0,0 +1,7 @@
+//! Implements IBC handlers responsible for processing relayer incentivization
+//! (ICS-29) messages.
+mod pay_packet_fee;
+mod pay_packet_fee_async;
+
+pub use pay_packet_fee::*;
+pub use pay_packet_fee_async::*;
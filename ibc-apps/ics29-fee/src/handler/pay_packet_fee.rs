@@ -0,0 +1,163 @@
+//! Defines the main handler of the `MsgPayPacketFee` message.
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::IncentivizedPacketEvent;
+use ibc_app_fee_types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc_app_fee_types::{PacketFee, MODULE_ID_STR};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::types::channel::State as ChannelState;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::host::types::path::{ChannelEndPath, FeesInEscrowPath, SeqSendPath};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for the next packet sent on a channel. Equivalent to calling
+/// [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(send_packet_ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(send_packet_ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of a fee for the next packet sent on a channel, which
+/// must be open and have fee incentivization enabled.
+pub fn pay_packet_fee_validate<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    validate_fee_enabled_channel(
+        send_packet_ctx_a,
+        fee_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    )?;
+
+    let payer = msg
+        .signer
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_validate(&payer, &msg.fee)
+}
+
+/// Escrows a fee for the next packet sent on a channel.
+///
+/// The fee is recorded under the [`FeesInEscrowPath`] of the sequence that
+/// the channel assigns to its next packet, after any fee already escrowed for
+/// that packet.
+pub fn pay_packet_fee_execute<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_fee = PacketFee::new(msg.fee, msg.signer, Vec::new());
+
+    escrow_packet_fee_execute(
+        send_packet_ctx_a,
+        fee_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        sequence,
+        packet_fee,
+    )
+}
+
+/// Checks that the given channel exists, is open and has fee incentivization
+/// enabled.
+pub(crate) fn validate_fee_enabled_channel<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    fee_ctx_a: &FeeCtx,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+    let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a
+        .verify_state_matches(&ChannelState::Open)
+        .map_err(ContextError::from)?;
+
+    if !fee_ctx_a.is_fee_enabled(port_id_on_a, chan_id_on_a)? {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Escrows the given packet fee from its refund address, and records it under
+/// the [`FeesInEscrowPath`] of the given packet, after any fee already
+/// escrowed for that packet.
+pub(crate) fn escrow_packet_fee_execute<SendPacketCtx, FeeCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    fee_ctx_a: &mut FeeCtx,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    sequence: Sequence,
+    packet_fee: PacketFee,
+) -> Result<(), FeeError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let payer = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_execute(&payer, &packet_fee.fee)?;
+
+    let fees_path = FeesInEscrowPath::new(port_id_on_a, chan_id_on_a, sequence);
+    let mut packet_fees = fee_ctx_a.fees_in_escrow(&fees_path)?;
+    let fee = packet_fee.fee.clone();
+    packet_fees.push(packet_fee);
+    fee_ctx_a.store_fees_in_escrow(&fees_path, packet_fees)?;
+
+    // emit events and logs
+    {
+        send_packet_ctx_a.log_message(format!(
+            "fee escrowed for packet {sequence} on port {port_id_on_a} and channel {chan_id_on_a}"
+        ))?;
+
+        let incentivized_event = IncentivizedPacketEvent {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+            sequence,
+            fee,
+        };
+        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(incentivized_event).into())?;
+
+        send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
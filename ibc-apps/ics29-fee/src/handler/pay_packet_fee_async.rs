@@ -0,0 +1,79 @@
+//! Defines the main handler of the `MsgPayPacketFeeAsync` message.
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::msgs::pay_packet_fee_async::MsgPayPacketFeeAsync;
+use ibc_core::host::types::path::CommitmentPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use super::pay_packet_fee::{escrow_packet_fee_execute, validate_fee_enabled_channel};
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for a packet that has already been sent. Equivalent to
+/// calling [`pay_packet_fee_async_validate`], followed by
+/// [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_async_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_async_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of a fee for a packet that has already been sent.
+///
+/// The packet must still be in flight, that is its commitment must not have
+/// been deleted by an acknowledgement or a timeout, so that the fee is
+/// eventually paid out.
+pub fn pay_packet_fee_async_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    validate_fee_enabled_channel(ctx_a, fee_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    let commitment_path_on_a =
+        CommitmentPath::new(&msg.port_id_on_a, &msg.chan_id_on_a, msg.seq_on_a);
+    ctx_a.get_packet_commitment(&commitment_path_on_a)?;
+
+    let payer = msg
+        .packet_fee
+        .refund_address
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_validate(&payer, &msg.packet_fee.fee)
+}
+
+/// Escrows a fee for a packet that has already been sent.
+///
+/// The fee is recorded under the [`FeesInEscrowPath`] of the packet named by
+/// the message, after any fee already escrowed for that packet.
+///
+/// [`FeesInEscrowPath`]: ibc_core::host::types::path::FeesInEscrowPath
+pub fn pay_packet_fee_async_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    escrow_packet_fee_execute(
+        ctx_a,
+        fee_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.seq_on_a,
+        msg.packet_fee,
+    )
+}
@@ -0,0 +1,134 @@
+//! Provides the packet callbacks that pay out the fees escrowed for a packet.
+//!
+//! They take the host's fee context, so that hosts wiring the fee logic into
+//! their own [`Module`](ibc_core::router::module::Module) implementation share
+//! the fee escrow with the rest of their store.
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::DistributeFeeEvent;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::path::FeesInEscrowPath;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::FeeExecutionContext;
+
+/// How a packet's lifecycle ended on its sending chain.
+enum PacketOutcome {
+    Acknowledged,
+    TimedOut,
+}
+
+/// Pays the acknowledgement fees escrowed for the given packet to the
+/// relayer, and refunds the rest. To be called once the application has
+/// successfully processed the acknowledgement.
+pub fn on_acknowledgement_packet_execute(
+    ctx_a: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    relayer: &Signer,
+) -> ModuleExtras {
+    distribute_packet_fees(ctx_a, packet, relayer, PacketOutcome::Acknowledged)
+}
+
+/// Pays the timeout fees escrowed for the given packet to the relayer, and
+/// refunds the rest. To be called once the application has successfully
+/// processed the timeout.
+pub fn on_timeout_packet_execute(
+    ctx_a: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    relayer: &Signer,
+) -> ModuleExtras {
+    distribute_packet_fees(ctx_a, packet, relayer, PacketOutcome::TimedOut)
+}
+
+/// Pays out the fees escrowed for the given packet.
+///
+/// Relayers are paid the fee for the step they relayed, that is the
+/// acknowledgement fee or the timeout fee. Everything else is refunded to the
+/// account that paid the fee.
+///
+/// Failures are recorded in the returned logs rather than failing the
+/// callback, so that the application's own handling of the packet goes
+/// through. Whatever could not be paid out stays in escrow, and only fully
+/// distributed fees are removed from it.
+fn distribute_packet_fees(
+    ctx_a: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    relayer: &Signer,
+    outcome: PacketOutcome,
+) -> ModuleExtras {
+    let fees_path =
+        FeesInEscrowPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+    let packet_fees = match ctx_a.fees_in_escrow(&fees_path) {
+        Ok(packet_fees) => packet_fees,
+        Err(e) => return ModuleExtras::empty().with_log(e.to_string()),
+    };
+
+    if packet_fees.is_empty() {
+        return ModuleExtras::empty();
+    }
+
+    let mut extras = ModuleExtras::empty();
+    let mut undistributed = Vec::new();
+
+    for mut packet_fee in packet_fees {
+        let relayer_fee = match outcome {
+            PacketOutcome::Acknowledged => &mut packet_fee.fee.ack_fee,
+            PacketOutcome::TimedOut => &mut packet_fee.fee.timeout_fee,
+        };
+
+        // the relayer's fee is refunded if it can't be paid out to them
+        match distribute_fee(ctx_a, relayer, relayer_fee, &mut extras) {
+            Ok(()) => relayer_fee.clear(),
+            Err(e) => extras = extras.with_log(e.to_string()),
+        }
+
+        let refund = packet_fee.fee.total();
+        if let Err(e) = distribute_fee(ctx_a, &packet_fee.refund_address, &refund, &mut extras) {
+            extras = extras.with_log(e.to_string());
+            undistributed.push(packet_fee);
+        }
+    }
+
+    let res = if undistributed.is_empty() {
+        ctx_a.delete_fees_in_escrow(&fees_path)
+    } else {
+        ctx_a.store_fees_in_escrow(&fees_path, undistributed)
+    };
+
+    if let Err(e) = res {
+        extras = extras.with_log(e.to_string());
+    }
+
+    extras
+}
+
+fn distribute_fee(
+    ctx_a: &mut impl FeeExecutionContext,
+    receiver: &Signer,
+    coins: &[PrefixedCoin],
+    extras: &mut ModuleExtras,
+) -> Result<(), FeeError> {
+    if coins.is_empty() {
+        return Ok(());
+    }
+
+    let receiver_account = receiver
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    ctx_a.distribute_fee_execute(&receiver_account, coins)?;
+
+    extras.events.push(
+        DistributeFeeEvent {
+            receiver: receiver.clone(),
+            fee: coins.to_vec(),
+        }
+        .into(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Defines the fee middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// missing fee
+    MissingFee,
+    /// missing packet ID
+    MissingPacketId,
+    /// fee must contain at least one coin
+    EmptyFee,
+    /// relayers must not be set, as restricting who may relay a packet is not supported
+    RelayersNotSupported,
+    /// fee incentivization is not enabled on port `{port_id}` and channel `{channel_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// failed to parse account ID
+    ParseAccountFailure,
+    /// insufficient funds: tried to escrow `{fee}`, payer only has `{available_funds}`
+    InsufficientFunds {
+        fee: String,
+        available_funds: String,
+    },
+    /// failed to decode raw msg: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for FeeError {
+    fn from(err: IdentifierError) -> FeeError {
+        Self::InvalidIdentifier(err)
+    }
+}
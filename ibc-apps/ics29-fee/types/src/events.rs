@@ -0,0 +1,78 @@
+//! Defines all fee middleware event types
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::{display_coins, Fee, MODULE_ID_STR};
+
+const EVENT_TYPE_INCENTIVIZED_PACKET: &str = "incentivized_ibc_packet";
+const EVENT_TYPE_DISTRIBUTE_FEE: &str = "distribute_fee";
+
+/// Contains all events variants that can be emitted from the fee middleware
+pub enum Event {
+    IncentivizedPacket(IncentivizedPacketEvent),
+    DistributeFee(DistributeFeeEvent),
+}
+
+/// Event emitted when a fee is escrowed for a packet
+pub struct IncentivizedPacketEvent {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub fee: Fee,
+}
+
+impl From<IncentivizedPacketEvent> for ModuleEvent {
+    fn from(ev: IncentivizedPacketEvent) -> Self {
+        let IncentivizedPacketEvent {
+            port_id,
+            channel_id,
+            sequence,
+            fee,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_INCENTIVIZED_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("port_id", port_id).into(),
+                ("channel_id", channel_id).into(),
+                ("packet_sequence", sequence).into(),
+                ("recv_fee", display_coins(&fee.recv_fee)).into(),
+                ("ack_fee", display_coins(&fee.ack_fee)).into(),
+                ("timeout_fee", display_coins(&fee.timeout_fee)).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when escrowed fees are paid out, either to a relayer or back
+/// to the refund account
+pub struct DistributeFeeEvent {
+    pub receiver: Signer,
+    pub fee: Vec<PrefixedCoin>,
+}
+
+impl From<DistributeFeeEvent> for ModuleEvent {
+    fn from(ev: DistributeFeeEvent) -> Self {
+        let DistributeFeeEvent { receiver, fee } = ev;
+        Self {
+            kind: EVENT_TYPE_DISTRIBUTE_FEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("receiver", receiver).into(),
+                ("fee", display_coins(&fee)).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::IncentivizedPacket(ev) => ev.into(),
+            Event::DistributeFee(ev) => ev.into(),
+        }
+    }
+}
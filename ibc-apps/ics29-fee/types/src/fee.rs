@@ -0,0 +1,165 @@
+//! Defines the fees paid to relayers for relaying a packet.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::apps::fee::v1::{Fee as RawFee, PacketFee as RawPacketFee};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The fees paid to the relayers that deliver a packet, its acknowledgement
+/// or its timeout.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fee {
+    /// Fee paid to the relayer that delivers the packet to the counterparty
+    pub recv_fee: Vec<PrefixedCoin>,
+    /// Fee paid to the relayer that delivers the acknowledgement back
+    pub ack_fee: Vec<PrefixedCoin>,
+    /// Fee paid to the relayer that delivers the timeout back
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl Fee {
+    /// Returns `true` if none of the fees carries a coin.
+    pub fn is_empty(&self) -> bool {
+        self.recv_fee.is_empty() && self.ack_fee.is_empty() && self.timeout_fee.is_empty()
+    }
+
+    /// Returns all the coins to escrow in order to pay the fee.
+    pub fn total(&self) -> Vec<PrefixedCoin> {
+        self.recv_fee
+            .iter()
+            .chain(&self.ack_fee)
+            .chain(&self.timeout_fee)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Display for Fee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "recv_fee: [{}], ack_fee: [{}], timeout_fee: [{}]",
+            display_coins(&self.recv_fee),
+            display_coins(&self.ack_fee),
+            display_coins(&self.timeout_fee)
+        )
+    }
+}
+
+/// Formats a list of coins as a comma separated string, as the Cosmos SDK
+/// does.
+pub fn display_coins(coins: &[PrefixedCoin]) -> String {
+    coins
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn coins_try_from_raw(
+    raw_coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+) -> Result<Vec<PrefixedCoin>, FeeError> {
+    raw_coins
+        .into_iter()
+        .map(|coin| coin.try_into().map_err(FeeError::InvalidCoin))
+        .collect()
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw_fee: RawFee) -> Result<Self, Self::Error> {
+        Ok(Fee {
+            recv_fee: coins_try_from_raw(raw_fee.recv_fee)?,
+            ack_fee: coins_try_from_raw(raw_fee.ack_fee)?,
+            timeout_fee: coins_try_from_raw(raw_fee.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        RawFee {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A fee escrowed for a packet, along with the account to refund whatever is
+/// not paid out to relayers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    pub relayers: Vec<Signer>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer, relayers: Vec<Signer>) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers,
+        }
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_packet_fee: RawPacketFee) -> Result<Self, Self::Error> {
+        Ok(PacketFee {
+            fee: raw_packet_fee.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            refund_address: raw_packet_fee.refund_address.into(),
+            relayers: raw_packet_fee
+                .relayers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        RawPacketFee {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
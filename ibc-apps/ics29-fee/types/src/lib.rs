@@ -0,0 +1,31 @@
+//! Implementation of the IBC [relayer incentivization](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md) (ICS-29) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod fee;
+
+pub use fee::*;
+pub mod error;
+pub mod events;
+pub mod msgs;
+
+/// Re-exports ICS-29 fee proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::apps::fee;
+}
+
+/// Module identifier for the ICS29 fee middleware.
+pub const MODULE_ID_STR: &str = "feeibc";
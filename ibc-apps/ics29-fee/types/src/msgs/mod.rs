@@ -0,0 +1,3 @@
+//! Defines the fee middleware message types
+pub mod pay_packet_fee;
+pub mod pay_packet_fee_async;
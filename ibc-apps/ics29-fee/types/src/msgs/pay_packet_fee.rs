@@ -0,0 +1,94 @@
+//! Defines the message used to incentivize the relaying of the next packet
+//! sent on a channel
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::MsgPayPacketFee as RawMsgPayPacketFee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::Fee;
+
+pub const PAY_PACKET_FEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+
+/// Message used to escrow a fee for the next packet sent on a channel.
+///
+/// The fee is paid out to the relayers of that packet once it is
+/// acknowledged or timed out, and the rest is refunded to the signer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFee {
+    /// the fee to escrow
+    pub fee: Fee,
+    /// the port on which the incentivized packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the incentivized packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, which is also refunded
+    pub signer: Signer,
+}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        if !raw_msg.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        let fee: Fee = raw_msg.fee.ok_or(FeeError::MissingFee)?.try_into()?;
+
+        if fee.is_empty() {
+            return Err(FeeError::EmptyFee);
+        }
+
+        Ok(MsgPayPacketFee {
+            fee,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: Vec::new(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_TYPE_URL => {
+                MsgPayPacketFee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Defines the message used to incentivize the relaying of a packet that has
+//! already been sent
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::MsgPayPacketFeeAsync as RawMsgPayPacketFeeAsync;
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::PacketFee;
+
+pub const PAY_PACKET_FEE_ASYNC_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFeeAsync";
+
+/// Message used to escrow a fee for a packet that has already been sent,
+/// identified by its port, channel and sequence.
+///
+/// The fee is paid out to the relayers of that packet once it is
+/// acknowledged or timed out, and the rest is refunded to the refund address
+/// of the packet fee, which is also the account paying it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFeeAsync {
+    /// the port on which the incentivized packet was sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the incentivized packet was sent
+    pub chan_id_on_a: ChannelId,
+    /// the sequence of the incentivized packet
+    pub seq_on_a: Sequence,
+    /// the fee to escrow, along with the account paying it
+    pub packet_fee: PacketFee,
+}
+
+impl TryFrom<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFeeAsync) -> Result<Self, Self::Error> {
+        let packet_id = raw_msg.packet_id.ok_or(FeeError::MissingPacketId)?;
+
+        let packet_fee: PacketFee = raw_msg.packet_fee.ok_or(FeeError::MissingFee)?.try_into()?;
+
+        if !packet_fee.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        if packet_fee.fee.is_empty() {
+            return Err(FeeError::EmptyFee);
+        }
+
+        Ok(MsgPayPacketFeeAsync {
+            port_id_on_a: packet_id.port_id.parse()?,
+            chan_id_on_a: packet_id.channel_id.parse()?,
+            seq_on_a: packet_id.sequence.into(),
+            packet_fee,
+        })
+    }
+}
+
+impl From<MsgPayPacketFeeAsync> for RawMsgPayPacketFeeAsync {
+    fn from(domain_msg: MsgPayPacketFeeAsync) -> Self {
+        RawMsgPayPacketFeeAsync {
+            packet_id: Some(RawPacketId {
+                port_id: domain_msg.port_id_on_a.to_string(),
+                channel_id: domain_msg.chan_id_on_a.to_string(),
+                sequence: domain_msg.seq_on_a.value(),
+            }),
+            packet_fee: Some(domain_msg.packet_fee.into()),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {}
+
+impl TryFrom<Any> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_ASYNC_TYPE_URL => {
+                MsgPayPacketFeeAsync::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use ibc_core::primitives::Signer;
 use crate::types::error::NftTransferError;
 use crate::types::{
     ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    DEFAULT_MAX_TOKENS_PER_PACKET,
 };
 
 pub trait NftContext {
@@ -119,6 +120,37 @@ pub trait NftTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), NftTransferError>;
 
+    /// Returns the port and channel through which the given NFT is currently
+    /// escrowed on the host chain, or `None` if it isn't escrowed.
+    ///
+    /// Implement only if the host chain keeps track of escrowed NFTs, in
+    /// [`escrow_nft_execute`](NftTransferExecutionContext::escrow_nft_execute)
+    /// and
+    /// [`unescrow_nft_execute`](NftTransferExecutionContext::unescrow_nft_execute).
+    fn token_escrow_info(
+        &self,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Option<(PortId, ChannelId)> {
+        None
+    }
+
+    /// Returns the maximum size, in bytes, of the memo accepted in NFT
+    /// transfers sent or received by the host chain.
+    ///
+    /// Returns `None`, i.e. no limit, by default.
+    fn max_memo_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the maximum number of tokens accepted in a single NFT transfer
+    /// packet sent or received by the host chain.
+    ///
+    /// Returns [`DEFAULT_MAX_TOKENS_PER_PACKET`] by default.
+    fn max_tokens_per_packet(&self) -> usize {
+        DEFAULT_MAX_TOKENS_PER_PACKET
+    }
+
     /// Returns a hash of the prefixed class ID and the token ID.
     /// Implement only if the host chain supports hashed class ID and token ID.
     fn token_hash_string(
@@ -136,7 +168,8 @@ pub trait NftTransferValidationContext {
         token_id: &TokenId,
     ) -> Result<Self::Nft, NftTransferError>;
 
-    /// Returns the NFT class
+    /// Returns the NFT class, or [`NftTransferError::NftClassNotFound`] if the
+    /// host chain stores no class under `class_id`.
     fn get_nft_class(&self, class_id: &PrefixedClassId)
         -> Result<Self::NftClass, NftTransferError>;
 }
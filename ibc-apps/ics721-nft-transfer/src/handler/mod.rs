@@ -7,10 +7,48 @@ use ibc_core::channel::types::packet::Packet;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
-use crate::context::{NftTransferExecutionContext, NftTransferValidationContext};
+use crate::context::{NftClassContext, NftTransferExecutionContext, NftTransferValidationContext};
 use crate::types::error::NftTransferError;
-use crate::types::is_sender_chain_source;
 use crate::types::packet::PacketData;
+use crate::types::{is_sender_chain_source, Memo, PrefixedClassId, TokenIds};
+
+/// Checks the memo, if any, against the size limit set by the host chain.
+pub(crate) fn validate_memo_size(
+    ctx: &impl NftTransferValidationContext,
+    memo: Option<&Memo>,
+) -> Result<(), NftTransferError> {
+    match (memo, ctx.max_memo_size()) {
+        (Some(memo), Some(max_memo_size)) => memo.validate_size(max_memo_size),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the number of tokens against the per-packet limit set by the host
+/// chain.
+pub(crate) fn validate_token_count(
+    ctx: &impl NftTransferValidationContext,
+    token_ids: &TokenIds,
+) -> Result<(), NftTransferError> {
+    token_ids.validate_count(ctx.max_tokens_per_packet())
+}
+
+/// Checks that the class stored by the host chain under `class_id` carries
+/// that same class ID, so that received NFTs can't end up in another class.
+pub(crate) fn validate_class_id(
+    ctx: &impl NftTransferValidationContext,
+    class_id: &PrefixedClassId,
+) -> Result<(), NftTransferError> {
+    let nft_class = ctx.get_nft_class(class_id)?;
+
+    if nft_class.get_id().as_ref() != class_id.to_string() {
+        return Err(NftTransferError::ClassIdMismatch {
+            expected: class_id.clone(),
+            actual: nft_class.get_id().clone(),
+        });
+    }
+
+    Ok(())
+}
 
 pub fn refund_packet_nft_execute(
     ctx_a: &mut impl NftTransferExecutionContext,
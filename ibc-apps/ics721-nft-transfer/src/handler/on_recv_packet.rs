@@ -2,6 +2,7 @@ use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::{validate_class_id, validate_memo_size, validate_token_count};
 use crate::context::NftTransferExecutionContext;
 use crate::types::error::NftTransferError;
 use crate::types::events::TokenTraceEvent;
@@ -25,6 +26,10 @@ where
         .can_receive_nft()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
+    validate_memo_size(ctx_b, data.memo.as_ref()).map_err(|err| (ModuleExtras::empty(), err))?;
+
+    validate_token_count(ctx_b, &data.token_ids).map_err(|err| (ModuleExtras::empty(), err))?;
+
     let receiver_account = data
         .receiver
         .clone()
@@ -44,6 +49,8 @@ where
             c
         };
 
+        validate_class_id(ctx_b, &class_id).map_err(|err| (ModuleExtras::empty(), err))?;
+
         // Note: the validation is called before the execution.
         // Refer to ICS-20 `process_recv_packet_execute()`.
         for token_id in data.token_ids.as_ref() {
@@ -77,6 +84,13 @@ where
             c
         };
 
+        // A class already stored under the class ID must carry that same ID
+        // before its metadata gets updated below.
+        match validate_class_id(ctx_b, &class_id) {
+            Ok(()) | Err(NftTransferError::NftClassNotFound) => {}
+            Err(nft_error) => return Err((ModuleExtras::empty(), nft_error)),
+        }
+
         let mut extras = ModuleExtras {
             events: vec![],
             log: Vec::new(),
@@ -9,9 +9,11 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::nft_transfer::v1::ClassTrace as RawClassTrace;
+use sha2::{Digest, Sha256};
 
 use crate::data::Data;
 use crate::error::NftTransferError;
+use crate::IBC_CLASS_ID_PREFIX;
 
 /// Class ID for an NFT
 #[cfg_attr(
@@ -90,6 +92,41 @@ impl PrefixedClassId {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the SHA-256 hash of the full class trace, hex-encoded in upper
+    /// case.
+    pub fn class_hash(&self) -> String {
+        Sha256::digest(self.to_string().as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect()
+    }
+
+    /// Returns the class ID under which the host chain stores this class:
+    /// `nft-transfer/{hash}` if the class came from another chain, or the base
+    /// class ID itself otherwise.
+    pub fn ibc_class_id(&self) -> String {
+        if self.trace_path.is_empty() {
+            self.base_class_id.to_string()
+        } else {
+            format!("{IBC_CLASS_ID_PREFIX}/{}", self.class_hash())
+        }
+    }
+}
+
+/// Extracts the class hash from a hashed class ID, as returned by
+/// [`PrefixedClassId::ibc_class_id`] for classes that came from another chain.
+///
+/// The hash can't be turned back into a class trace by itself; host chains
+/// are expected to keep a mapping from hashes to the traces they received.
+pub fn parse_class_hash(ibc_class_id: &str) -> Result<&str, NftTransferError> {
+    ibc_class_id
+        .strip_prefix(IBC_CLASS_ID_PREFIX)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .filter(|hash| hash.len() == 64 && hash.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')))
+        .ok_or_else(|| NftTransferError::InvalidIbcClassId {
+            ibc_class_id: ibc_class_id.to_string(),
+        })
 }
 
 /// Returns true if the class ID originally came from the sender chain and false otherwise.
@@ -380,6 +417,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ibc_class_id() -> Result<(), NftTransferError> {
+        let class_id = PrefixedClassId::from_str("nft-transfer/channel-0/myclass")?;
+        let hash = "AB8BAB2C754D2AA3345646A6AB448B4ADCC5D1FDBB9C847CF45D5AB7E4F50D79";
+
+        assert_eq!(class_id.class_hash(), hash);
+        assert_eq!(
+            class_id.ibc_class_id(),
+            format!("nft-transfer/{hash}"),
+            "traced class is hashed"
+        );
+        assert_eq!(parse_class_hash(&class_id.ibc_class_id())?, hash);
+
+        let class_id = PrefixedClassId::from_str("myclass")?;
+        assert_eq!(class_id.ibc_class_id(), "myclass", "native class is kept");
+
+        assert!(parse_class_hash("myclass").is_err(), "missing prefix");
+        assert!(
+            parse_class_hash("nft-transfer/AB8BAB2C").is_err(),
+            "truncated hash"
+        );
+        assert!(
+            parse_class_hash(&format!("nft-transfer/{}", hash.to_lowercase())).is_err(),
+            "lower case hash"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_trace_path() -> Result<(), NftTransferError> {
         assert!(TracePath::from_str("").is_ok(), "empty trace path");
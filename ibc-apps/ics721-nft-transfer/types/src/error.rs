@@ -10,6 +10,8 @@ use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 
+use crate::class::{ClassId, PrefixedClassId};
+
 #[derive(Display, Debug)]
 pub enum NftTransferError {
     /// context error: `{0}`
@@ -46,6 +48,8 @@ pub enum NftTransferError {
     InvalidTokenId,
     /// duplicated token IDs
     DuplicatedTokenIds,
+    /// `{count}` token IDs exceed the maximum of `{max_count}` per packet
+    TooManyTokens { count: u64, max_count: u64 },
     /// The length of token IDs mismatched that of token URIs or token data
     TokenMismatched,
     /// invalid json data
@@ -67,6 +71,13 @@ pub enum NftTransferError {
     NftNotFound,
     /// nft class is not found
     NftClassNotFound,
+    /// invalid hashed class ID: `{ibc_class_id}`
+    InvalidIbcClassId { ibc_class_id: String },
+    /// class ID mismatch: expected `{expected}`, stored class has `{actual}`
+    ClassIdMismatch {
+        expected: PrefixedClassId,
+        actual: ClassId,
+    },
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -82,6 +93,8 @@ pub enum NftTransferError {
         port_id: PortId,
         exp_port_id: PortId,
     },
+    /// memo of `{length}` bytes exceeds the maximum allowed size of `{max_length}` bytes
+    MemoTooLarge { length: u64, max_length: u64 },
     /// decoding raw msg error: `{reason}`
     DecodeRawMsg { reason: String },
     /// unknown msg type: `{msg_type}`
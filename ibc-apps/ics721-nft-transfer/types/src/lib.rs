@@ -42,9 +42,17 @@ pub const MODULE_ID_STR: &str = "nft_transfer";
 /// The port identifier that the ICS-721 applications typically bind with.
 pub const PORT_ID_STR: &str = "nft-transfer";
 
+/// The prefix of the hashed class IDs under which a host chain stores the
+/// classes it received from other chains.
+pub const IBC_CLASS_ID_PREFIX: &str = "nft-transfer";
+
 /// ICS-721 application current version.
 pub const VERSION: &str = "ics721-1";
 
+/// The default maximum number of tokens that can be transferred in a single
+/// ICS-721 packet.
+pub const DEFAULT_MAX_TOKENS_PER_PACKET: usize = 100;
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
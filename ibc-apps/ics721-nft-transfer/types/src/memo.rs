@@ -9,6 +9,8 @@ use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
 
+use crate::error::NftTransferError;
+
 /// Represents the token transfer memo
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -27,6 +29,19 @@ use ibc_core::primitives::prelude::*;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Memo(String);
 
+impl Memo {
+    /// Checks that the memo is no larger than `max_length` bytes.
+    pub fn validate_size(&self, max_length: usize) -> Result<(), NftTransferError> {
+        if self.0.len() > max_length {
+            return Err(NftTransferError::MemoTooLarge {
+                length: self.0.len() as u64,
+                max_length: max_length as u64,
+            });
+        }
+        Ok(())
+    }
+}
+
 impl AsRef<str> for Memo {
     fn as_ref(&self) -> &str {
         &self.0
@@ -58,3 +73,23 @@ impl FromStr for Memo {
         Ok(Self(memo.to_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memo_size() {
+        let memo = Memo::from("a".repeat(32));
+
+        assert!(memo.validate_size(32).is_ok());
+        assert!(matches!(
+            memo.validate_size(31),
+            Err(NftTransferError::MemoTooLarge {
+                length: 32,
+                max_length: 31
+            })
+        ));
+        assert!(Memo::from("").validate_size(0).is_ok());
+    }
+}
@@ -5,6 +5,8 @@ use base64::Engine;
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
+#[cfg(feature = "serde")]
+use ibc_core::primitives::utils::escape_html;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::nft_transfer::v1::NonFungibleTokenPacketData as RawPacketData;
 
@@ -197,6 +199,80 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+#[cfg(feature = "serde")]
+impl PacketData {
+    /// Returns the JSON encoding that ibc-go commits to for this packet data.
+    ///
+    /// Keys are sorted, empty fields are omitted, class and token data are
+    /// base64-encoded and HTML characters are escaped, as done by `GetBytes`
+    /// of `NonFungibleTokenPacketData` in Go. Indexers can hash these bytes
+    /// to reproduce packet commitments made by ibc-go chains.
+    pub fn to_canonical_json(&self) -> Vec<u8> {
+        canonical_json(&RawPacketData::from(self.clone()))
+    }
+}
+
+/// Encodes raw packet data as `GetBytes` of `NonFungibleTokenPacketData`
+/// does in Go.
+///
+/// Unlike ICS-20, the Go module marshals the packet data without `OrigName`,
+/// so keys are the camelCase JSON names of the proto fields, as in the
+/// ICS-721 spec. Token URIs and token data consisting only of empty strings
+/// are dropped before marshalling, like empty ones.
+#[cfg(feature = "serde")]
+fn canonical_json(raw_pkt_data: &RawPacketData) -> Vec<u8> {
+    /// Fields are declared in key order.
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CanonicalPacketData<'a> {
+        #[serde(skip_serializing_if = "is_empty")]
+        class_data: &'a str,
+        #[serde(skip_serializing_if = "is_empty")]
+        class_id: &'a str,
+        #[serde(skip_serializing_if = "is_empty")]
+        class_uri: &'a str,
+        #[serde(skip_serializing_if = "is_empty")]
+        memo: &'a str,
+        #[serde(skip_serializing_if = "is_empty")]
+        receiver: &'a str,
+        #[serde(skip_serializing_if = "is_empty")]
+        sender: &'a str,
+        #[serde(skip_serializing_if = "is_blank_list")]
+        token_data: &'a [String],
+        #[serde(skip_serializing_if = "is_empty_list")]
+        token_ids: &'a [String],
+        #[serde(skip_serializing_if = "is_blank_list")]
+        token_uris: &'a [String],
+    }
+
+    fn is_empty(field: &&str) -> bool {
+        field.is_empty()
+    }
+
+    fn is_empty_list(field: &&[String]) -> bool {
+        field.is_empty()
+    }
+
+    fn is_blank_list(field: &&[String]) -> bool {
+        field.iter().all(String::is_empty)
+    }
+
+    let json = serde_json::to_string(&CanonicalPacketData {
+        class_data: &raw_pkt_data.class_data,
+        class_id: &raw_pkt_data.class_id,
+        class_uri: &raw_pkt_data.class_uri,
+        memo: &raw_pkt_data.memo,
+        receiver: &raw_pkt_data.receiver,
+        sender: &raw_pkt_data.sender,
+        token_data: &raw_pkt_data.token_data,
+        token_ids: &raw_pkt_data.token_ids,
+        token_uris: &raw_pkt_data.token_uris,
+    })
+    .expect("serializing string fields never fails");
+
+    escape_html(&json).into_bytes()
+}
+
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
@@ -304,6 +380,43 @@ mod tests {
         PacketData::new_min_dummy().deser_json_assert_eq(dummy_min_json_packet_data_with_null());
     }
 
+    /// The expected bytes were derived by hand from the encoding rules of
+    /// `NonFungibleTokenPacketData.GetBytes` in ibc-go, not generated by ibc-go.
+    #[test]
+    fn test_packet_data_canonical_json() {
+        assert_eq!(
+            String::from_utf8(PacketData::new_min_dummy().to_canonical_json()).unwrap(),
+            r#"{"classId":"class","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenIds":["token_0"]}"#
+        );
+
+        assert_eq!(
+            String::from_utf8(PacketData::new_dummy(Some("<memo>")).to_canonical_json()).unwrap(),
+            r#"{"classData":"eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0=","classId":"class","classUri":"http://example.com/","memo":"\u003cmemo\u003e","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenData":["eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0=","eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0="],"tokenIds":["token_0","token_1"],"tokenUris":["http://example.com/","http://example.com/"]}"#
+        );
+    }
+
+    /// Token URIs and token data are dropped when they consist only of empty
+    /// strings, and kept as they are otherwise.
+    #[test]
+    fn test_canonical_json_drops_blank_token_lists() {
+        let mut raw_pkt_data = RawPacketData::from(PacketData::new_min_dummy());
+        raw_pkt_data.token_ids = vec!["token_0".to_string(), "token_1".to_string()];
+        raw_pkt_data.token_uris = vec![String::new(), String::new()];
+        raw_pkt_data.token_data = vec![String::new(), String::new()];
+
+        assert_eq!(
+            String::from_utf8(canonical_json(&raw_pkt_data)).unwrap(),
+            r#"{"classId":"class","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenIds":["token_0","token_1"]}"#
+        );
+
+        raw_pkt_data.token_uris = vec![DUMMY_URI.to_string(), String::new()];
+
+        assert_eq!(
+            String::from_utf8(canonical_json(&raw_pkt_data)).unwrap(),
+            r#"{"classId":"class","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenIds":["token_0","token_1"],"tokenUris":["http://example.com",""]}"#
+        );
+    }
+
     #[test]
     fn test_invalid_packet_data() {
         // the number of tokens is mismatched
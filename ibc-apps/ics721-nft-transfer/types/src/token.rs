@@ -73,6 +73,17 @@ impl TokenIds {
     pub fn as_ref(&self) -> Vec<&TokenId> {
         self.0.iter().collect()
     }
+
+    /// Checks that there are no more than `max_count` token IDs.
+    pub fn validate_count(&self, max_count: usize) -> Result<(), NftTransferError> {
+        if self.0.len() > max_count {
+            return Err(NftTransferError::TooManyTokens {
+                count: self.0.len() as u64,
+                max_count: max_count as u64,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl Display for TokenIds {
@@ -266,4 +277,19 @@ mod tests {
             .unwrap();
         borsh_roundtrip(TokenUri(uri));
     }
+
+    #[test]
+    fn test_token_ids_count() {
+        let token_ids =
+            TokenIds::try_from(vec!["token_0".to_string(), "token_1".to_string()]).unwrap();
+
+        assert!(token_ids.validate_count(2).is_ok());
+        assert!(matches!(
+            token_ids.validate_count(1),
+            Err(NftTransferError::TooManyTokens {
+                count: 2,
+                max_count: 1
+            })
+        ));
+    }
 }
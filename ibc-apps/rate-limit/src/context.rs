@@ -0,0 +1,73 @@
+//! Defines the main context traits of the rate-limit middleware
+
+use ibc_app_transfer_types::PrefixedDenom;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+use crate::types::{Flow, Quota};
+
+/// Methods required in rate-limit middleware validation, to be implemented by
+/// the host
+pub trait RateLimitValidationContext {
+    /// Returns the current timestamp of the host, against which windows are
+    /// opened and expired.
+    fn host_timestamp(&self) -> Result<Timestamp, RateLimitError>;
+
+    /// Returns the quota configured for the given denom over the given
+    /// channel, if any. Transfers of a denom without a quota are not rate
+    /// limited.
+    fn quota(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Quota>, RateLimitError>;
+
+    /// Returns the flow of the given denom over the given channel recorded in
+    /// the last window, if any.
+    fn flow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Flow>, RateLimitError>;
+}
+
+/// Methods required in rate-limit middleware execution, to be implemented by
+/// the host
+pub trait RateLimitExecutionContext: RateLimitValidationContext {
+    /// Stores the quota for the given denom over the given channel.
+    fn store_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        quota: Quota,
+    ) -> Result<(), RateLimitError>;
+
+    /// Deletes the quota for the given denom over the given channel.
+    fn delete_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError>;
+
+    /// Stores the flow of the given denom over the given channel.
+    fn store_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        flow: Flow,
+    ) -> Result<(), RateLimitError>;
+
+    /// Deletes the flow of the given denom over the given channel.
+    fn delete_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError>;
+}
@@ -0,0 +1,51 @@
+//! Defines the rate-limit middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::types::FlowDirection;
+
+/// The message of the error acknowledgement written by the middleware for a
+/// received transfer that would exceed its quota.
+pub const QUOTA_EXCEEDED_ACK_ERROR: &str = "rate limit exceeded";
+
+#[derive(Display, Debug)]
+pub enum RateLimitError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// `{direction}` of `{amount}` `{denom}` over port `{port_id}` and channel `{channel_id}` would exceed the quota of `{max_amount}` per window
+    QuotaExceeded {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        direction: FlowDirection,
+        amount: Amount,
+        max_amount: Amount,
+    },
+    /// quota window must not be zero
+    ZeroWindow,
+    /// flow amount overflow
+    AmountOverflow,
+    /// timestamp overflow when computing the end of the window
+    TimestampOverflow,
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RateLimitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for RateLimitError {
+    fn from(err: ContextError) -> RateLimitError {
+        Self::ContextError(err)
+    }
+}
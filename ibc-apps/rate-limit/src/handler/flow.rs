@@ -0,0 +1,106 @@
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use crate::error::RateLimitError;
+use crate::types::{Flow, FlowDirection};
+
+/// Checks that moving the given amount of a denom over the given channel
+/// stays within the quota configured for it.
+///
+/// Outgoing transfers are checked with [`send_transfer_validate`](super::send_transfer_validate);
+/// incoming ones are checked by the middleware.
+pub fn record_flow_validate(
+    ctx: &impl RateLimitValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    direction: FlowDirection,
+    amount: Amount,
+) -> Result<(), RateLimitError> {
+    checked_flow(ctx, port_id, channel_id, denom, direction, amount)?;
+
+    Ok(())
+}
+
+/// Checks that moving the given amount of a denom over the given channel
+/// stays within the quota configured for it, then records it in the flow of
+/// the current window.
+pub fn record_flow_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    direction: FlowDirection,
+    amount: Amount,
+) -> Result<(), RateLimitError> {
+    if let Some(flow) = checked_flow(ctx, port_id, channel_id, denom, direction, amount)? {
+        ctx.store_flow(port_id, channel_id, denom, flow)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the given amount from the flow of the current window, as when a
+/// sent transfer is refunded after a timeout or an error acknowledgement.
+///
+/// Amounts recorded in a window that is already over are left alone.
+pub fn undo_flow_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    direction: FlowDirection,
+    amount: Amount,
+) -> Result<(), RateLimitError> {
+    let Some(mut flow) = ctx.flow(port_id, channel_id, denom)? else {
+        return Ok(());
+    };
+
+    if flow.is_expired(&ctx.host_timestamp()?) {
+        return Ok(());
+    }
+
+    flow.undo(direction, amount);
+
+    ctx.store_flow(port_id, channel_id, denom, flow)
+}
+
+/// Returns the flow of the current window with the given amount recorded in
+/// it, or `None` if the denom has no quota over the channel.
+fn checked_flow(
+    ctx: &impl RateLimitValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    direction: FlowDirection,
+    amount: Amount,
+) -> Result<Option<Flow>, RateLimitError> {
+    let Some(quota) = ctx.quota(port_id, channel_id, denom)? else {
+        return Ok(None);
+    };
+
+    let now = ctx.host_timestamp()?;
+
+    let mut flow = match ctx.flow(port_id, channel_id, denom)? {
+        Some(flow) if !flow.is_expired(&now) => flow,
+        _ => Flow::new(now, quota.window)?,
+    };
+
+    flow.record(direction, amount)?;
+
+    let max_amount = quota.max_amount(direction);
+
+    if flow.net_amount(direction) > max_amount {
+        return Err(RateLimitError::QuotaExceeded {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            denom: denom.clone(),
+            direction,
+            amount,
+            max_amount,
+        });
+    }
+
+    Ok(Some(flow))
+}
@@ -0,0 +1,9 @@
+//! Implements the handlers that update the quotas of the rate-limit
+//! middleware and record the flows checked against them.
+mod flow;
+mod quota;
+mod send_transfer;
+
+pub use flow::*;
+pub use quota::*;
+pub use send_transfer::*;
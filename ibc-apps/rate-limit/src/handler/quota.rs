@@ -0,0 +1,37 @@
+use ibc_app_transfer_types::PrefixedDenom;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::RateLimitExecutionContext;
+use crate::error::RateLimitError;
+use crate::types::Quota;
+
+/// Sets the quota for the given denom over the given channel, replacing any
+/// previous one.
+///
+/// The flow recorded under the previous quota is discarded, so that the new
+/// quota applies from a fresh window.
+pub fn set_quota(
+    ctx: &mut impl RateLimitExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    quota: Quota,
+) -> Result<(), RateLimitError> {
+    quota.validate_basic()?;
+
+    ctx.delete_flow(port_id, channel_id, denom)?;
+    ctx.store_quota(port_id, channel_id, denom, quota)
+}
+
+/// Removes the quota for the given denom over the given channel, along with
+/// its flow. Transfers of the denom over the channel are no longer rate
+/// limited.
+pub fn remove_quota(
+    ctx: &mut impl RateLimitExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<(), RateLimitError> {
+    ctx.delete_flow(port_id, channel_id, denom)?;
+    ctx.delete_quota(port_id, channel_id, denom)
+}
@@ -0,0 +1,46 @@
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+
+use super::{record_flow_execute, record_flow_validate};
+use crate::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use crate::error::RateLimitError;
+use crate::types::FlowDirection;
+
+/// Checks that the tokens sent by the given transfer stay within the outflow
+/// quota of their denom over the sending channel.
+///
+/// Sending a packet doesn't go through the module callbacks, so hosts call
+/// this alongside `send_transfer_validate` of the token transfer app.
+pub fn send_transfer_validate(
+    ctx_a: &impl RateLimitValidationContext,
+    msg: &MsgTransfer,
+) -> Result<(), RateLimitError> {
+    record_flow_validate(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.packet_data.token.denom,
+        FlowDirection::Outflow,
+        msg.packet_data.token.amount,
+    )
+}
+
+/// Checks the tokens sent by the given transfer against the outflow quota of
+/// their denom over the sending channel, then records them in the outflow of
+/// the current window.
+///
+/// Hosts call this alongside `send_transfer_execute` of the token transfer
+/// app. The amount is removed from the outflow again by the middleware if the
+/// transfer is refunded.
+pub fn send_transfer_execute(
+    ctx_a: &mut impl RateLimitExecutionContext,
+    msg: &MsgTransfer,
+) -> Result<(), RateLimitError> {
+    record_flow_execute(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.packet_data.token.denom,
+        FlowDirection::Outflow,
+        msg.packet_data.token.amount,
+    )
+}
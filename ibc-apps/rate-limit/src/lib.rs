@@ -0,0 +1,39 @@
+//! Implementation of a middleware that rate limits the net flow of ICS-20
+//! fungible tokens over each channel.
+//!
+//! The host configures a [`Quota`](types::Quota) per port, channel and denom,
+//! which caps the net amount of that denom flowing out of and into the chain
+//! over the channel within a time window. Once a window is over, the next
+//! transfer opens a new one and the flow starts again from zero.
+//!
+//! Received packets are checked by the [`RateLimitMiddleware`](middleware::RateLimitMiddleware),
+//! which writes an error acknowledgement for any transfer that would exceed
+//! the quota. Sending a packet does not go through the module callbacks, so
+//! hosts check outgoing transfers by calling
+//! [`send_transfer_validate`](handler::send_transfer_validate) and
+//! [`send_transfer_execute`](handler::send_transfer_execute) alongside the
+//! `send_transfer` handlers of the token transfer app, on the same context
+//! as the one wrapped by the middleware.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+pub mod error;
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod middleware;
+pub mod types;
@@ -0,0 +1,470 @@
+//! Defines the rate-limit middleware, which wraps the ICS-20 token transfer
+//! module in order to rate limit the tokens it receives.
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::{is_receiver_chain_source, Amount, PrefixedDenom, TracePrefix};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::RateLimitExecutionContext;
+use crate::error::QUOTA_EXCEEDED_ACK_ERROR;
+use crate::handler::{record_flow_execute, record_flow_validate, undo_flow_execute};
+use crate::types::FlowDirection;
+
+/// Wraps the ICS-20 token transfer module, forwarding all its callbacks to
+/// it, and rate limits the tokens it receives.
+///
+/// A received transfer that would exceed the quota of its denom is answered
+/// with an error acknowledgement, without being passed to the transfer
+/// module. The amounts of sent transfers that are refunded, following a
+/// timeout or an error acknowledgement, are removed from the outflow.
+///
+/// The wrapped module is the host's rate-limit context, so that the quotas
+/// and flows are kept in the host's store along with the transfers they
+/// track, and can be shared with the send-side checks of
+/// [`send_transfer_validate`](crate::handler::send_transfer_validate) and
+/// [`send_transfer_execute`](crate::handler::send_transfer_execute).
+///
+/// Packets that don't carry ICS-20 packet data are forwarded untouched.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<M> {
+    app: M,
+}
+
+impl<M> RateLimitMiddleware<M> {
+    pub fn new(app: M) -> Self {
+        Self { app }
+    }
+
+    /// Returns the wrapped application module.
+    pub fn app(&self) -> &M {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut M {
+        &mut self.app
+    }
+}
+
+impl<M> RateLimitMiddleware<M>
+where
+    M: RateLimitExecutionContext,
+{
+    /// Removes the amount of a refunded transfer from the outflow of the
+    /// sending channel.
+    ///
+    /// Failures are recorded in the returned logs rather than failing the
+    /// callback, so that the refund itself goes through.
+    fn undo_outflow(&mut self, packet: &Packet) -> ModuleExtras {
+        let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+            return ModuleExtras::empty();
+        };
+
+        match undo_flow_execute(
+            &mut self.app,
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &data.token.denom,
+            FlowDirection::Outflow,
+            data.token.amount,
+        ) {
+            Ok(()) => ModuleExtras::empty(),
+            Err(e) => ModuleExtras::empty().with_log(e.to_string()),
+        }
+    }
+}
+
+/// Returns the denom and amount of the tokens carried by the given packet, as
+/// they are known on the receiving chain, or `None` if the packet doesn't
+/// carry ICS-20 packet data.
+fn received_token(packet: &Packet) -> Option<(PrefixedDenom, Amount)> {
+    let data = serde_json::from_slice::<PacketData>(&packet.data).ok()?;
+
+    let mut denom = data.token.denom;
+
+    if is_receiver_chain_source(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        &denom,
+    ) {
+        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+        denom.remove_trace_prefix(&prefix);
+    } else {
+        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+        denom.add_trace_prefix(prefix);
+    }
+
+    Some((denom, data.token.amount))
+}
+
+/// Returns the status of the error acknowledgement written for a received
+/// transfer that would exceed its quota.
+///
+/// The status carries a constant message, so that the acknowledgement doesn't
+/// depend on how the error is formatted; the error itself goes to the logs.
+fn quota_exceeded() -> StatusValue {
+    StatusValue::new(QUOTA_EXCEEDED_ACK_ERROR).expect("constant is not empty")
+}
+
+/// Returns `true` if the given acknowledgement is an ICS-20 error
+/// acknowledgement. Acknowledgements that can't be parsed are left to the
+/// wrapped module.
+fn is_error_ack(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .is_ok_and(|ack| !ack.is_successful())
+}
+
+impl<M> Module for RateLimitMiddleware<M>
+where
+    M: Module + RateLimitExecutionContext,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.app.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.app.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.app.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.app.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.app
+            .on_chan_open_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app
+            .on_chan_open_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.app.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.app.on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.app.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.app.on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.app
+            .on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app
+            .on_chan_upgrade_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        self.app
+            .on_chan_upgrade_open_execute(port_id, channel_id, order, connection_hops, version)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let Some((denom, amount)) = received_token(packet) else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        if let Err(e) = record_flow_validate(
+            &self.app,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            &denom,
+            FlowDirection::Inflow,
+            amount,
+        ) {
+            let extras = ModuleExtras::empty().with_log(e.to_string());
+            return (
+                extras,
+                AcknowledgementStatus::error(quota_exceeded()).into(),
+            );
+        }
+
+        let (mut extras, ack) = self.app.on_recv_packet_execute(packet, relayer);
+
+        if is_error_ack(&ack) {
+            return (extras, ack);
+        }
+
+        if let Err(e) = record_flow_execute(
+            &mut self.app,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            &denom,
+            FlowDirection::Inflow,
+            amount,
+        ) {
+            extras = extras.with_log(e.to_string());
+        }
+
+        (extras, ack)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.app
+            .on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if res.is_err() || !is_error_ack(acknowledgement) {
+            return (extras, res);
+        }
+
+        let rate_limit_extras = self.undo_outflow(packet);
+
+        (extras.merge(rate_limit_extras), res)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.app.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, res) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        if res.is_err() {
+            return (extras, res);
+        }
+
+        let rate_limit_extras = self.undo_outflow(packet);
+
+        (extras.merge(rate_limit_extras), res)
+    }
+}
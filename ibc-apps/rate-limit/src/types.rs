@@ -0,0 +1,143 @@
+//! Defines the quotas enforced by the rate-limit middleware and the flows
+//! they are checked against.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
+
+use ibc_app_transfer_types::Amount;
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+
+/// The direction in which tokens move over a channel, as seen from the host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowDirection {
+    /// Tokens received from the counterparty
+    Inflow,
+    /// Tokens sent to the counterparty
+    Outflow,
+}
+
+impl Display for FlowDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Inflow => write!(f, "inflow"),
+            Self::Outflow => write!(f, "outflow"),
+        }
+    }
+}
+
+/// The maximum net amount of a denom that may flow over a channel within a
+/// time window.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Quota {
+    /// Maximum amount received, net of the amount sent, within a window
+    pub max_inflow: Amount,
+    /// Maximum amount sent, net of the amount received, within a window
+    pub max_outflow: Amount,
+    /// Length of a window
+    pub window: Duration,
+}
+
+impl Quota {
+    pub fn new(
+        max_inflow: Amount,
+        max_outflow: Amount,
+        window: Duration,
+    ) -> Result<Self, RateLimitError> {
+        let quota = Self {
+            max_inflow,
+            max_outflow,
+            window,
+        };
+        quota.validate_basic()?;
+
+        Ok(quota)
+    }
+
+    pub fn validate_basic(&self) -> Result<(), RateLimitError> {
+        if self.window.is_zero() {
+            return Err(RateLimitError::ZeroWindow);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the maximum net amount allowed in the given direction.
+    pub fn max_amount(&self, direction: FlowDirection) -> Amount {
+        match direction {
+            FlowDirection::Inflow => self.max_inflow,
+            FlowDirection::Outflow => self.max_outflow,
+        }
+    }
+}
+
+/// The amounts of a denom that flowed over a channel within the current
+/// window.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flow {
+    pub inflow: Amount,
+    pub outflow: Amount,
+    /// Host timestamp at which the window ends
+    pub window_end: Timestamp,
+}
+
+impl Flow {
+    /// Opens an empty window of the given length, starting at `now`.
+    pub fn new(now: Timestamp, window: Duration) -> Result<Self, RateLimitError> {
+        let window_end = (now + window).map_err(|_| RateLimitError::TimestampOverflow)?;
+
+        Ok(Self {
+            inflow: Amount::from(0),
+            outflow: Amount::from(0),
+            window_end,
+        })
+    }
+
+    /// Returns `true` if the window is over at `now`.
+    pub fn is_expired(&self, now: &Timestamp) -> bool {
+        now >= &self.window_end
+    }
+
+    /// Returns the amount that flowed in the given direction, net of the
+    /// amount that flowed in the opposite one, or zero if the latter is
+    /// larger.
+    pub fn net_amount(&self, direction: FlowDirection) -> Amount {
+        let (amount, opposite) = match direction {
+            FlowDirection::Inflow => (self.inflow, self.outflow),
+            FlowDirection::Outflow => (self.outflow, self.inflow),
+        };
+
+        amount.checked_sub(opposite).unwrap_or(Amount::from(0))
+    }
+
+    /// Adds the given amount to the flow in the given direction.
+    pub fn record(
+        &mut self,
+        direction: FlowDirection,
+        amount: Amount,
+    ) -> Result<(), RateLimitError> {
+        let flow = self.amount_mut(direction);
+        *flow = flow
+            .checked_add(amount)
+            .ok_or(RateLimitError::AmountOverflow)?;
+
+        Ok(())
+    }
+
+    /// Removes the given amount from the flow in the given direction, as when
+    /// a sent transfer is refunded. The flow never goes below zero.
+    pub fn undo(&mut self, direction: FlowDirection, amount: Amount) {
+        let flow = self.amount_mut(direction);
+        *flow = flow.checked_sub(amount).unwrap_or(Amount::from(0));
+    }
+
+    fn amount_mut(&mut self, direction: FlowDirection) -> &mut Amount {
+        match direction {
+            FlowDirection::Inflow => &mut self.inflow,
+            FlowDirection::Outflow => &mut self.outflow,
+        }
+    }
+}
@@ -27,3 +27,18 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [relayer
+/// incentivization](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) middleware.
+pub mod fee {
+    #[doc(inline)]
+    pub use ibc_app_fee::*;
+}
+
+/// Re-exports the implementation of the middleware that rate limits the net
+/// flow of ICS-20 fungible tokens over each channel.
+pub mod rate_limit {
+    #[doc(inline)]
+    pub use ibc_app_rate_limit::*;
+}
@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 use cosmwasm_std::{Deps, DepsMut, Empty, Env, Order, Storage};
 use cw_storage_plus::{Bound, Map};
+use ibc_client_wasm_types::checksum::Checksum;
 use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
 use ibc_core::client::context::client_state::ClientStateCommon;
 use ibc_core::client::types::error::ClientError;
@@ -21,8 +22,6 @@ use crate::api::ClientType;
 use crate::types::{ContractError, GenesisMetadata, HeightTravel, MigrationPrefix};
 use crate::utils::AnyCodec;
 
-type Checksum = Vec<u8>;
-
 /// - [`Height`] can not be used directly as keys in the map,
 /// as it doesn't implement some cw_storage specific traits.
 /// - Only a sorted set is needed. So the value type is set to
@@ -276,7 +275,7 @@ where
     /// Returns the checksum of the current contract.
     pub fn obtain_checksum(&self) -> Result<Checksum, ClientError> {
         match &self.checksum {
-            Some(checksum) => Ok(checksum.clone()),
+            Some(checksum) => Ok(*checksum),
             None => {
                 let client_state_value = self.retrieve(ClientStatePath::leaf())?;
 
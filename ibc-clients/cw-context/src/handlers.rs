@@ -1,4 +1,5 @@
 use cosmwasm_std::{to_json_binary, Binary};
+use ibc_client_wasm_types::checksum::Checksum;
 use ibc_core::client::context::prelude::*;
 use ibc_core::client::types::error::ClientError;
 use ibc_core::host::types::path::ClientConsensusStatePath;
@@ -27,7 +28,11 @@ where
 
         let any_consensus_state = Any::decode(&mut msg.consensus_state.as_slice())?;
 
-        self.set_checksum(msg.checksum);
+        let checksum = Checksum::try_from(msg.checksum).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })?;
+
+        self.set_checksum(checksum);
 
         client_state.initialise(self, &self.client_id(), any_consensus_state)?;
 
@@ -9,7 +9,7 @@ use ibc_core_commitment_types::commitment::{
 use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof};
 use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
 use ibc_core_commitment_types::specs::ProofSpecs;
-use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::identifiers::{ChainId, ClientType};
 use ibc_core_host::types::path::{Path, UpgradeClientPath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
@@ -27,6 +27,10 @@ impl ClientStateCommon for ClientState {
         tm_client_type()
     }
 
+    fn chain_id(&self) -> Option<ChainId> {
+        Some(self.0.chain_id.clone())
+    }
+
     fn latest_height(&self) -> Height {
         self.0.latest_height
     }
@@ -8,6 +8,7 @@ use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
 
 use super::ClientState;
 
@@ -322,10 +323,38 @@ where
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
 {
+    let host_timestamp = ctx.host_timestamp()?;
+
+    prune_expired_consensus_states(client_state, ctx, client_id, host_timestamp)?;
+
+    Ok(())
+}
+
+/// Removes the consensus states, along with their update metadata, that have
+/// expired as of `now`, i.e. that are older than the client's trusting period.
+/// Returns the number of consensus states removed.
+pub fn prune_expired_consensus_states<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    now: Timestamp,
+) -> Result<usize, ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let now = now.into_tm_time().ok_or_else(|| ClientError::Other {
+        description: String::from("host timestamp is not a valid TM timestamp"),
+    })?;
+
     let mut heights = ctx.consensus_state_heights(client_id)?;
 
     heights.sort();
 
+    let mut pruned = 0;
+
     for height in heights {
         let client_consensus_state_path = ClientConsensusStatePath::new(
             client_id.clone(),
@@ -336,13 +365,6 @@ where
         let tm_consensus_state: ConsensusStateType =
             consensus_state.try_into().map_err(Into::into)?;
 
-        let host_timestamp =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::Other {
-                    description: String::from("host timestamp is not a valid TM timestamp"),
-                })?;
-
         let tm_consensus_state_timestamp = tm_consensus_state.timestamp();
         let tm_consensus_state_expiry = (tm_consensus_state_timestamp
             + client_state.trusting_period)
@@ -352,15 +374,17 @@ where
                 ),
             })?;
 
-        if tm_consensus_state_expiry > host_timestamp {
+        if tm_consensus_state_expiry > now {
             break;
         }
 
         ctx.delete_consensus_state(client_consensus_state_path)?;
         ctx.delete_update_meta(client_id.clone(), height)?;
+
+        pruned += 1;
     }
 
-    Ok(())
+    Ok(pruned)
 }
 
 /// Update the `client_state`'s ID, trusting period, latest height, processed height,
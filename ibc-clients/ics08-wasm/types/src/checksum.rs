@@ -0,0 +1,141 @@
+//! Defines the checksum identifying the code of a Wasm light client contract.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_primitives::prelude::*;
+use subtle_encoding::hex;
+
+use crate::error::Error;
+
+/// Length in bytes of a [`Checksum`].
+pub const CHECKSUM_LENGTH: usize = 32;
+
+/// The SHA-256 hash of the code of a Wasm light client contract, as stored by
+/// the host chain.
+///
+/// Displayed and parsed as a lowercase hex string. Defaults to all zeroes,
+/// which the `cosmwasm` encoding of a client state decodes a missing checksum
+/// to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checksum([u8; CHECKSUM_LENGTH]);
+
+impl Checksum {
+    pub fn new(bytes: [u8; CHECKSUM_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl AsRef<[u8]> for Checksum {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Checksum {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = value.try_into().map_err(|_| Error::InvalidChecksumLength {
+            expected: CHECKSUM_LENGTH,
+            actual: value.len(),
+        })?;
+
+        Ok(Self(bytes))
+    }
+}
+
+impl TryFrom<Vec<u8>> for Checksum {
+    type Error = Error;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl From<Checksum> for Vec<u8> {
+    fn from(value: Checksum) -> Self {
+        value.to_vec()
+    }
+}
+
+impl Display for Checksum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl FromStr for Checksum {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s.to_lowercase()).map_err(|_| Error::DecodeError {
+            reason: format!("invalid hex checksum `{s}`"),
+        })?;
+
+        Self::try_from(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Checksum {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Checksum {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const CHECKSUM_HEX: &str = "2469f43c3ca20d476442bd3d98cbd97a180776ab37332aa7b02cae5a620acfc6";
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let checksum = Checksum::from_str(CHECKSUM_HEX).unwrap();
+
+        assert_eq!(checksum.to_string(), CHECKSUM_HEX);
+        assert_eq!(
+            Checksum::from_str(&CHECKSUM_HEX.to_uppercase()).unwrap(),
+            checksum
+        );
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0; 31])]
+    #[case(&[0; 33])]
+    fn test_invalid_length(#[case] bytes: &[u8]) {
+        assert!(matches!(
+            Checksum::try_from(bytes),
+            Err(Error::InvalidChecksumLength { expected: CHECKSUM_LENGTH, actual })
+                if actual == bytes.len()
+        ));
+    }
+
+    #[rstest]
+    #[case("2469f43c")]
+    #[case("not a checksum")]
+    fn test_invalid_hex(#[case] s: &str) {
+        assert!(Checksum::from_str(s).is_err());
+    }
+}
@@ -7,12 +7,32 @@ use crate::Bytes;
 
 pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
 
+/// The message of the underlying client, wrapped as opaque bytes.
+///
+/// Converting from and to [`RawClientMessage`] moves the bytes without
+/// copying them, which matters for large headers.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ClientMessage {
     pub data: Bytes,
 }
 
+impl ClientMessage {
+    pub fn new(data: Bytes) -> Self {
+        Self { data }
+    }
+
+    /// Tags the wrapped bytes as a header of the underlying client.
+    pub fn into_header(self) -> WasmClientMessageKind {
+        WasmClientMessageKind::Header(self.data)
+    }
+
+    /// Tags the wrapped bytes as a misbehaviour of the underlying client.
+    pub fn into_misbehaviour(self) -> WasmClientMessageKind {
+        WasmClientMessageKind::Misbehaviour(self.data)
+    }
+}
+
 impl Protobuf<RawClientMessage> for ClientMessage {}
 
 impl From<RawClientMessage> for ClientMessage {
@@ -27,6 +47,52 @@ impl From<ClientMessage> for RawClientMessage {
     }
 }
 
+/// A [`ClientMessage`] tagged with the kind of message it wraps.
+///
+/// The raw proto carries no such discriminant, so the kind is only known to
+/// the code that produced or routed the message. Converting to the raw proto
+/// drops the tag and keeps the wrapped bytes as is.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WasmClientMessageKind {
+    Header(Bytes),
+    Misbehaviour(Bytes),
+}
+
+impl WasmClientMessageKind {
+    pub fn is_header(&self) -> bool {
+        matches!(self, Self::Header(_))
+    }
+
+    pub fn is_misbehaviour(&self) -> bool {
+        matches!(self, Self::Misbehaviour(_))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Header(data) | Self::Misbehaviour(data) => data,
+        }
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        match self {
+            Self::Header(data) | Self::Misbehaviour(data) => data,
+        }
+    }
+}
+
+impl From<WasmClientMessageKind> for ClientMessage {
+    fn from(value: WasmClientMessageKind) -> Self {
+        Self::new(value.into_bytes())
+    }
+}
+
+impl From<WasmClientMessageKind> for RawClientMessage {
+    fn from(value: WasmClientMessageKind) -> Self {
+        ClientMessage::from(value).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -44,4 +110,36 @@ mod tests {
             raw_msg,
         )
     }
+
+    #[test]
+    fn test_conversions_do_not_copy() {
+        let raw_msg = RawClientMessage {
+            data: vec![0x42; 1 << 20],
+        };
+        let data_ptr = raw_msg.data.as_ptr();
+
+        let header = ClientMessage::from(raw_msg.clone()).into_header();
+        assert_eq!(header.as_bytes(), raw_msg.data);
+
+        let raw_msg = RawClientMessage::from(ClientMessage::from(raw_msg).into_header());
+        assert_eq!(raw_msg.data.as_ptr(), data_ptr);
+    }
+
+    #[rstest]
+    #[case(WasmClientMessageKind::Header(b"header".to_vec()))]
+    #[case(WasmClientMessageKind::Misbehaviour(b"misbehaviour".to_vec()))]
+    fn test_kind_roundtrip(#[case] kind: WasmClientMessageKind) {
+        let encoded = Protobuf::<RawClientMessage>::encode_vec(ClientMessage::from(kind.clone()));
+        let msg = <ClientMessage as Protobuf<RawClientMessage>>::decode_vec(&encoded).unwrap();
+
+        assert_eq!(msg.data, kind.as_bytes());
+
+        let decoded = if kind.is_header() {
+            msg.into_header()
+        } else {
+            msg.into_misbehaviour()
+        };
+
+        assert_eq!(decoded, kind);
+    }
 }
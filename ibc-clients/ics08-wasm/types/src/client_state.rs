@@ -7,6 +7,7 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawClientState;
 
+use crate::checksum::Checksum;
 use crate::error::Error;
 #[cfg(feature = "cosmwasm")]
 use crate::serializer::Base64;
@@ -21,9 +22,10 @@ pub struct ClientState {
     #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
     #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
     pub data: Bytes,
+    /// Checksum of the code of the contract backing this client.
     #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
     #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
-    pub checksum: Bytes,
+    pub checksum: Checksum,
     pub latest_height: Height,
 }
 
@@ -33,7 +35,7 @@ impl From<ClientState> for RawClientState {
     fn from(value: ClientState) -> Self {
         Self {
             data: value.data,
-            checksum: value.checksum,
+            checksum: value.checksum.into(),
             latest_height: Some(value.latest_height.into()),
         }
     }
@@ -52,9 +54,11 @@ impl TryFrom<RawClientState> for ClientState {
             .map_err(|_| Error::InvalidLatestHeight {
                 reason: "invalid protobuf latest height".to_string(),
             })?;
+        let checksum = raw.checksum.try_into()?;
+
         Ok(Self {
             data: raw.data,
-            checksum: raw.checksum,
+            checksum,
             latest_height,
         })
     }
@@ -101,7 +105,7 @@ mod tests {
     use super::*;
 
     #[rstest]
-    #[case(b"data", b"checksum", 1)]
+    #[case(b"data", &[0xab; 32], 1)]
     fn test_roundtrip(#[case] data: &[u8], #[case] checksum: &[u8], #[case] height: u64) {
         let raw_client_state = RawClientState {
             data: data.to_vec(),
@@ -118,4 +122,45 @@ mod tests {
             client_state
         );
     }
+
+    #[test]
+    fn test_invalid_checksum_length() {
+        let raw_client_state = RawClientState {
+            data: b"data".to_vec(),
+            checksum: b"checksum".to_vec(),
+            latest_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 1,
+            }),
+        };
+
+        assert!(matches!(
+            ClientState::try_from(raw_client_state),
+            Err(Error::InvalidChecksumLength {
+                expected: 32,
+                actual: 8
+            })
+        ));
+    }
+
+    #[cfg(feature = "cosmwasm")]
+    #[test]
+    fn test_cosmwasm_checksum_is_base64() {
+        let client_state = ClientState {
+            data: b"data".to_vec(),
+            checksum: Checksum::new([0xab; 32]),
+            latest_height: Height::new(0, 1).unwrap(),
+        };
+
+        let json = concat!(
+            r#"{"data":"ZGF0YQ==","#,
+            r#""checksum":"q6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6s=","#,
+            r#""latest_height":{"revision_number":0,"revision_height":1}}"#
+        );
+        assert_eq!(serde_json::to_string(&client_state).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<ClientState>(json).unwrap(),
+            client_state
+        );
+    }
 }
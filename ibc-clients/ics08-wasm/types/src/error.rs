@@ -13,6 +13,8 @@ pub enum Error {
     DecodeError { reason: String },
     /// invalid client state latest height: `{reason}`
     InvalidLatestHeight { reason: String },
+    /// invalid checksum length: expected `{expected}` bytes, got `{actual}`
+    InvalidChecksumLength { expected: usize, actual: usize },
 }
 
 #[cfg(feature = "std")]
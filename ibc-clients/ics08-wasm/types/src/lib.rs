@@ -12,6 +12,7 @@
     rust_2018_idioms
 )]
 
+pub mod checksum;
 pub mod client_message;
 pub mod client_state;
 pub mod consensus_state;
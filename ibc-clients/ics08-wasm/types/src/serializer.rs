@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use ibc_primitives::prelude::*;
@@ -7,18 +9,27 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub struct Base64;
 
 impl Base64 {
-    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]>,
+        S: Serializer,
+    {
         let encoded = BASE64_STANDARD.encode(bytes);
         String::serialize(&encoded, serializer)
     }
 
-    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        T::Error: Display,
+        D: Deserializer<'de>,
+    {
         let base64 = String::deserialize(deserializer)?;
         let bytes = BASE64_STANDARD
             .decode(base64.as_bytes())
             .map_err(Error::custom)?;
 
-        Ok(bytes)
+        T::try_from(bytes).map_err(Error::custom)
     }
 }
 
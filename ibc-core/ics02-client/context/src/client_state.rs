@@ -5,7 +5,7 @@ use ibc_core_client_types::{Height, Status};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
-use ibc_core_host_types::identifiers::{ClientId, ClientType};
+use ibc_core_host_types::identifiers::{ChainId, ClientId, ClientType};
 use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
@@ -27,6 +27,12 @@ pub trait ClientStateCommon: Convertible<Any> {
     /// Type of client associated with this state (eg. Tendermint)
     fn client_type(&self) -> ClientType;
 
+    /// Identifier of the chain tracked by this client, if its client type
+    /// records one.
+    fn chain_id(&self) -> Option<ChainId> {
+        None
+    }
+
     /// Latest height the client was updated to
     fn latest_height(&self) -> Height;
 
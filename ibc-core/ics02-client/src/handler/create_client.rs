@@ -43,6 +43,13 @@ where
 
     client_state.verify_consensus_state(consensus_state)?;
 
+    #[cfg(not(feature = "loopback"))]
+    if let (Some(host_chain_id), Some(chain_id)) = (ctx.host_chain_id(), client_state.chain_id()) {
+        if host_chain_id == chain_id {
+            return Err(ClientError::SelfTrackingNotAllowed { chain_id }.into());
+        }
+    }
+
     if client_val_ctx.client_state(&client_id).is_ok() {
         return Err(ClientError::ClientStateAlreadyExists { client_id }.into());
     };
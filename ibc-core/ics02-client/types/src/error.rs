@@ -5,7 +5,7 @@ use core::convert::Infallible;
 use displaydoc::Display;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::{ClientId, ClientType};
+use ibc_core_host_types::identifiers::{ChainId, ClientId, ClientType};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 
@@ -27,6 +27,8 @@ pub enum ClientError {
     ClientStateNotFound { client_id: ClientId },
     /// client state already exists: `{client_id}`
     ClientStateAlreadyExists { client_id: ClientId },
+    /// client would track the host chain `{chain_id}` itself, which requires the `loopback` feature
+    SelfTrackingNotAllowed { chain_id: ChainId },
     /// Substitute client height `{substitute_height}` is not greater than subject client height `{subject_height}` during client recovery
     ClientRecoveryHeightMismatch {
         subject_height: Height,
@@ -36,6 +38,12 @@ pub enum ClientError {
     ClientRecoveryStateMismatch,
     /// consensus state not found at: `{client_id}` at height `{height}`
     ConsensusStateNotFound { client_id: ClientId, height: Height },
+    /// consensus state of client `{client_id}` at height `{height}` was pruned; the oldest one retained is at height `{oldest_height}`
+    ConsensusStatePruned {
+        client_id: ClientId,
+        height: Height,
+        oldest_height: Height,
+    },
     /// Processed time or height for the client `{client_id}` at height `{height}` not found
     UpdateMetaDataNotFound { client_id: ClientId, height: Height },
     /// header verification failed with reason: `{reason}`
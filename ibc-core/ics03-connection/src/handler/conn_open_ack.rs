@@ -51,6 +51,17 @@ where
     let client_state_of_a_on_b =
         Ctx::HostClientState::try_from(msg.client_state_of_a_on_b.clone()).map_err(Into::into)?;
 
+    // The consensus state proven alongside the client state can't be more
+    // recent than that client state.
+    let client_height = client_state_of_a_on_b.latest_height();
+    if msg.consensus_height_of_a_on_b > client_height {
+        return Err(ConnectionError::ConsensusHeightAboveClientHeight {
+            consensus_height: msg.consensus_height_of_a_on_b,
+            client_height,
+        }
+        .into());
+    }
+
     ctx_a.validate_self_client(client_state_of_a_on_b)?;
 
     msg.version
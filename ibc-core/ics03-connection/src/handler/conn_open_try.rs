@@ -41,6 +41,17 @@ where
     let client_state_of_b_on_a =
         Ctx::HostClientState::try_from(msg.client_state_of_b_on_a.clone()).map_err(Into::into)?;
 
+    // The consensus state proven alongside the client state can't be more
+    // recent than that client state.
+    let client_height = client_state_of_b_on_a.latest_height();
+    if msg.consensus_height_of_b_on_a > client_height {
+        return Err(ConnectionError::ConsensusHeightAboveClientHeight {
+            consensus_height: msg.consensus_height_of_b_on_a,
+            client_height,
+        }
+        .into());
+    }
+
     ctx_b.validate_self_client(client_state_of_b_on_a)?;
 
     let host_height = ctx_b.host_height().map_err(|_| ConnectionError::Other {
@@ -206,7 +206,7 @@ impl Protobuf<RawConnectionEnd> for ConnectionEnd {}
 impl TryFrom<RawConnectionEnd> for ConnectionEnd {
     type Error = ConnectionError;
     fn try_from(value: RawConnectionEnd) -> Result<Self, Self::Error> {
-        let state = value.state.try_into()?;
+        let state = State::try_from_i32(value.state)?;
 
         if value.client_id.is_empty() {
             return Err(ConnectionError::EmptyProtoConnectionEnd);
@@ -488,8 +488,9 @@ impl State {
         }
     }
 
-    /// Parses the State out from a i32.
-    pub fn from_i32(s: i32) -> Result<Self, ConnectionError> {
+    /// Parses the State out from a i32, failing with
+    /// [`ConnectionError::InvalidState`] for out-of-range values.
+    pub fn try_from_i32(s: i32) -> Result<Self, ConnectionError> {
         match s {
             0 => Ok(Self::Uninitialized),
             1 => Ok(Self::Init),
@@ -502,6 +503,11 @@ impl State {
         }
     }
 
+    /// Parses the State out from a i32.
+    pub fn from_i32(s: i32) -> Result<Self, ConnectionError> {
+        Self::try_from_i32(s)
+    }
+
     /// Returns whether or not this connection state is `Open`.
     pub fn is_open(self) -> bool {
         self == State::Open
@@ -530,16 +536,7 @@ impl Display for State {
 impl TryFrom<i32> for State {
     type Error = ConnectionError;
     fn try_from(value: i32) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Self::Uninitialized),
-            1 => Ok(Self::Init),
-            2 => Ok(Self::TryOpen),
-            3 => Ok(Self::Open),
-            _ => Err(ConnectionError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3".to_string(),
-                actual: value.to_string(),
-            }),
-        }
+        Self::try_from_i32(value)
     }
 }
 
@@ -548,3 +545,21 @@ impl From<State> for i32 {
         value as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_try_from_i32() {
+        assert_eq!(State::try_from_i32(0).unwrap(), State::Uninitialized);
+        assert_eq!(State::try_from_i32(1).unwrap(), State::Init);
+        assert_eq!(State::try_from_i32(2).unwrap(), State::TryOpen);
+        assert_eq!(State::try_from_i32(3).unwrap(), State::Open);
+
+        assert!(matches!(
+            State::try_from_i32(4),
+            Err(ConnectionError::InvalidState { actual, .. }) if actual == "4"
+        ));
+    }
+}
@@ -20,6 +20,11 @@ pub enum ConnectionError {
         target_height: Height,
         current_height: Height,
     },
+    /// consensus height `{consensus_height}` is above the latest height `{client_height}` of the proven client state
+    ConsensusHeightAboveClientHeight {
+        consensus_height: Height,
+        client_height: Height,
+    },
     /// identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
     /// ConnectionEnd domain object could not be constructed out of empty proto object
@@ -38,6 +43,8 @@ pub enum ConnectionError {
     FeatureNotSupported { feature: String },
     /// no common features
     NoCommonFeatures,
+    /// no common feature for the common version \"`{version}`\"
+    NoCommonFeature { version: Version },
     /// missing proof height
     MissingProofHeight,
     /// missing consensus height
@@ -87,6 +94,97 @@ pub enum ConnectionError {
     Other { description: String },
 }
 
+/// Stable classification of [`ConnectionError`]s, e.g. for mapping them onto
+/// gRPC status codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionErrorCode {
+    /// The requested connection or object does not exist
+    NotFound,
+    /// The input is malformed or otherwise invalid regardless of the state
+    InvalidArgument,
+    /// The input is well-formed but the current state does not allow the operation
+    FailedPrecondition,
+    /// An unexpected failure on the host side
+    Internal,
+}
+
+impl ConnectionErrorCode {
+    /// Returns the stable string representation of the error code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NOT_FOUND",
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::FailedPrecondition => "FAILED_PRECONDITION",
+            Self::Internal => "INTERNAL",
+        }
+    }
+}
+
+impl core::fmt::Display for ConnectionErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ConnectionError {
+    /// Returns the [`ConnectionErrorCode`] classifying this error.
+    pub fn code(&self) -> ConnectionErrorCode {
+        match self {
+            Self::ConnectionNotFound { .. } => ConnectionErrorCode::NotFound,
+            Self::InvalidConsensusHeight { .. }
+            | Self::ConsensusHeightAboveClientHeight { .. }
+            | Self::InvalidIdentifier(_)
+            | Self::EmptyProtoConnectionEnd
+            | Self::EmptyVersions
+            | Self::InvalidVersionLength
+            | Self::VersionNotSupported { .. }
+            | Self::EmptyFeatures
+            | Self::FeatureNotSupported { .. }
+            | Self::MissingProofHeight
+            | Self::MissingConsensusHeight
+            | Self::InvalidProof
+            | Self::InvalidSigner { .. }
+            | Self::InvalidCounterparty
+            | Self::MissingCounterparty
+            | Self::MissingClientState
+            | Self::InvalidClientState { .. } => ConnectionErrorCode::InvalidArgument,
+            Self::InvalidState { .. }
+            | Self::NoCommonVersion
+            | Self::NoCommonFeatures
+            | Self::NoCommonFeature { .. }
+            | Self::VerifyConnectionState(_)
+            | Self::ConsensusStateVerificationFailure { .. }
+            | Self::ClientStateVerificationFailure { .. }
+            | Self::NotEnoughBlocksElapsed { .. }
+            | Self::NotEnoughTimeElapsed { .. } => ConnectionErrorCode::FailedPrecondition,
+            Self::Client(_)
+            | Self::TimestampOverflow(_)
+            | Self::CounterOverflow
+            | Self::Other { .. } => ConnectionErrorCode::Internal,
+        }
+    }
+}
+
+/// Maps a missing connection to `NOT_FOUND`, a malformed identifier to
+/// `INVALID_ARGUMENT`, proof verification failures to `FAILED_PRECONDITION`
+/// and everything else to `INTERNAL`, keeping the `Display` message.
+#[cfg(feature = "server")]
+impl From<ConnectionError> for tonic::Status {
+    fn from(e: ConnectionError) -> Self {
+        let message = e.to_string();
+        match e {
+            ConnectionError::ConnectionNotFound { .. } => Self::not_found(message),
+            ConnectionError::InvalidIdentifier(_) => Self::invalid_argument(message),
+            ConnectionError::VerifyConnectionState(_)
+            | ConnectionError::ConsensusStateVerificationFailure { .. }
+            | ConnectionError::ClientStateVerificationFailure { .. } => {
+                Self::failed_precondition(message)
+            }
+            _ => Self::internal(message),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ConnectionError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -105,3 +203,72 @@ impl std::error::Error for ConnectionError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::ConnectionId;
+    use ibc_primitives::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_error_code() {
+        let not_found = ConnectionError::ConnectionNotFound {
+            connection_id: ConnectionId::zero(),
+        };
+        assert_eq!(not_found.code(), ConnectionErrorCode::NotFound);
+        assert_eq!(
+            not_found.to_string(),
+            "no connection was found for the previous connection id provided `connection-0`"
+        );
+
+        assert_eq!(
+            ConnectionError::EmptyVersions.code(),
+            ConnectionErrorCode::InvalidArgument
+        );
+        assert_eq!(
+            ConnectionError::NoCommonVersion.code(),
+            ConnectionErrorCode::FailedPrecondition
+        );
+        assert_eq!(
+            ConnectionError::CounterOverflow.code(),
+            ConnectionErrorCode::Internal
+        );
+        assert_eq!(ConnectionErrorCode::NotFound.as_str(), "NOT_FOUND");
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_into_status() {
+        let err = ConnectionError::ConnectionNotFound {
+            connection_id: ConnectionId::zero(),
+        };
+        let message = err.to_string();
+
+        let status = tonic::Status::from(err);
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), message);
+
+        let status = tonic::Status::from(ConnectionError::InvalidIdentifier(
+            IdentifierError::RevisionNumberOverflow,
+        ));
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let status = tonic::Status::from(ConnectionError::VerifyConnectionState(
+            client_error::ClientError::MissingRawClientState,
+        ));
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+        let status = tonic::Status::from(ConnectionError::InvalidProof);
+        assert_eq!(status.code(), tonic::Code::Internal);
+
+        let status = tonic::Status::from(ConnectionError::NotEnoughBlocksElapsed {
+            current_host_height: Height::min(0),
+            earliest_valid_height: Height::min(0),
+        });
+        assert_eq!(status.code(), tonic::Code::Internal);
+
+        let status = tonic::Status::from(ConnectionError::CounterOverflow);
+        assert_eq!(status.code(), tonic::Code::Internal);
+    }
+}
@@ -59,6 +59,22 @@ impl Version {
         Ok(())
     }
 
+    /// Returns this version restricted to the features it shares with the
+    /// counterparty version of the same identifier, in the order of this
+    /// version's features.
+    pub fn intersect_features(&self, counterparty: &Version) -> Result<Version, ConnectionError> {
+        if self.identifier != counterparty.identifier {
+            return Err(ConnectionError::VersionNotSupported {
+                version: counterparty.clone(),
+            });
+        }
+
+        Ok(Version {
+            identifier: self.identifier.clone(),
+            features: get_feature_set_intersection(&self.features, &counterparty.features)?,
+        })
+    }
+
     /// Returns the lists of supported versions
     pub fn compatibles() -> Vec<Self> {
         vec![Self {
@@ -117,24 +133,32 @@ impl Display for Version {
 /// handshake procedure.
 ///
 /// NOTE: Empty feature set is not currently allowed for a chosen version.
+///
+/// If at least one version identifier is shared but none of the shared
+/// versions has a common feature, `NoCommonFeature` is returned for the first
+/// such version instead of `NoCommonVersion`.
 pub fn pick_version(
     supported_versions: &[Version],
     counterparty_versions: &[Version],
 ) -> Result<Version, ConnectionError> {
     let mut intersection: Vec<Version> = Vec::new();
+    let mut featureless_match: Option<Version> = None;
     for sv in supported_versions.iter() {
         if let Ok(cv) = find_supported_version(sv, counterparty_versions) {
-            if let Ok(feature_set) = get_feature_set_intersection(&sv.features, &cv.features) {
-                intersection.push(Version {
-                    identifier: cv.identifier,
-                    features: feature_set,
-                })
+            match sv.intersect_features(&cv) {
+                Ok(version) => intersection.push(version),
+                Err(_) => {
+                    featureless_match.get_or_insert(cv);
+                }
             }
         }
     }
 
     if intersection.is_empty() {
-        return Err(ConnectionError::NoCommonVersion);
+        return Err(match featureless_match {
+            Some(version) => ConnectionError::NoCommonFeature { version },
+            None => ConnectionError::NoCommonVersion,
+        });
     }
 
     intersection.sort_by(|a, b| a.identifier.cmp(&b.identifier));
@@ -388,6 +412,98 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn pick_common_version_without_common_feature() {
+        let supported = vec![Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_ORDERED".to_string()],
+        }];
+        let counterparty = vec![Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        }];
+
+        let err = pick_version(&supported, &counterparty).unwrap_err();
+        match err {
+            ConnectionError::NoCommonFeature { version } => {
+                assert_eq!(version, counterparty[0]);
+            }
+            e => panic!("expected NoCommonFeature error, got {e:?}"),
+        }
+
+        let (supported, counterparty) = disjoint();
+        assert!(matches!(
+            pick_version(&supported, &counterparty),
+            Err(ConnectionError::NoCommonVersion)
+        ));
+    }
+
+    #[test]
+    fn intersect_features() {
+        let ordered_unordered = Version::compatibles().remove(0);
+        let unordered = Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        };
+
+        assert_eq!(
+            ordered_unordered.intersect_features(&unordered).unwrap(),
+            unordered
+        );
+        assert_eq!(
+            unordered.intersect_features(&ordered_unordered).unwrap(),
+            unordered
+        );
+
+        let random = Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_RANDOM".to_string()],
+        };
+        assert!(matches!(
+            ordered_unordered.intersect_features(&random),
+            Err(ConnectionError::NoCommonFeatures)
+        ));
+
+        let other_identifier = Version {
+            identifier: "2".to_string(),
+            features: get_dummy_features(),
+        };
+        assert!(matches!(
+            ordered_unordered.intersect_features(&other_identifier),
+            Err(ConnectionError::VersionNotSupported { version }) if version == other_identifier
+        ));
+    }
+
+    #[test]
+    fn pick_single_common_version() {
+        let supported = vec![
+            Version::compatibles().remove(0),
+            Version {
+                identifier: "2".to_string(),
+                features: get_dummy_features(),
+            },
+        ];
+        let counterparty = vec![
+            Version {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_UNORDERED".to_string(), "ORDER_RANDOM".to_string()],
+            },
+            Version {
+                identifier: "5".to_string(),
+                features: get_dummy_features(),
+            },
+        ];
+
+        assert_eq!(
+            pick_version(&supported, &counterparty).unwrap(),
+            Version {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_UNORDERED".to_string()],
+            }
+        );
+    }
+
     #[test]
     fn serialize() {
         let def = Version {
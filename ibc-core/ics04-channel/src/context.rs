@@ -1,7 +1,7 @@
 //! ICS4 (channel) context.
 
 use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::commitment::{CommitmentHasher, PacketCommitment, Sha256Hasher};
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
@@ -21,11 +21,20 @@ pub trait SendPacketValidationContext {
     /// Returns the ChannelEnd for the given `port_id` and `chan_id`.
     fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
 
+    /// Returns whether packet flow on the given channel end has been paused.
+    fn is_channel_paused(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError>;
+
     /// Returns the ConnectionState for the given identifier `connection_id`.
     fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the hasher used to compute packet commitments. Defaults to
+    /// SHA-256.
+    fn commitment_hasher(&self) -> &dyn CommitmentHasher {
+        &Sha256Hasher
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -42,6 +51,10 @@ where
         self.channel_end(channel_end_path)
     }
 
+    fn is_channel_paused(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.is_channel_paused(channel_end_path)
+    }
+
     fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
         self.connection_end(connection_id)
     }
@@ -52,6 +65,10 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn commitment_hasher(&self) -> &dyn CommitmentHasher {
+        self.commitment_hasher()
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -1,11 +1,9 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
@@ -15,6 +13,9 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::upgrade::update_flush_state;
+use super::verify_connection_open;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -83,6 +84,13 @@ where
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
             ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
         }
+
+        update_flush_state(
+            ctx_a,
+            &msg.packet.port_id_on_a,
+            &msg.packet.chan_id_on_a,
+            chan_end_on_a,
+        )?;
     }
 
     // emit events and logs
@@ -113,7 +121,18 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+    // Packets sent before an upgrade are still acknowledged while the
+    // channel end is flushed.
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be Open or Flushing".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
@@ -123,9 +142,7 @@ where
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
-    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
-
-    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_a = verify_connection_open(ctx_a, conn_id_on_a)?;
 
     let commitment_path_on_a =
         CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
@@ -139,13 +156,7 @@ where
         return Ok(());
     };
 
-    if commitment_on_a
-        != compute_packet_commitment(
-            &packet.data,
-            &packet.timeout_height_on_b,
-            &packet.timeout_timestamp_on_b,
-        )
-    {
+    if commitment_on_a != packet.commitment_with(ctx_a.commitment_hasher()) {
         return Err(PacketError::IncorrectPacketCommitment {
             sequence: packet.seq_on_a,
         }
@@ -184,7 +195,9 @@ where
         );
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
+        let ack_commitment = msg
+            .acknowledgement
+            .commitment_with(ctx_a.commitment_hasher());
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
@@ -5,8 +5,8 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseConfirm;
 use ibc_core_channel_types::msgs::MsgChannelCloseConfirm;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
-use ibc_core_handler_types::error::ContextError;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::{ContextError, StoreError};
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
@@ -14,6 +14,8 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::verify_connection_open;
+
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -104,9 +106,7 @@ where
     // Validate that the channel end is in a state where it can be closed.
     chan_end_on_b.verify_not_closed()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
-
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_b = verify_connection_open(ctx_b, &chan_end_on_b.connection_hops()[0])?;
 
     // Verify proofs
     {
@@ -119,15 +119,32 @@ where
         client_state_of_a_on_b
             .status(ctx_b.get_client_validation_context(), client_id_on_b)?
             .verify_is_active()?;
+
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
+        // The proof height must have a consensus state, i.e. it must not have
+        // been pruned or never been recorded.
         let client_cons_state_path_on_b = ClientConsensusStatePath::new(
             client_id_on_b.clone(),
             msg.proof_height_on_a.revision_number(),
             msg.proof_height_on_a.revision_height(),
         );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let consensus_state_of_a_on_b = client_val_ctx_b
+            .consensus_state(&client_cons_state_path_on_b)
+            .map_err(|err| match err {
+                ContextError::ClientError(
+                    ClientError::ConsensusStateNotFound { .. }
+                    | ClientError::ConsensusStatePruned { .. },
+                )
+                | ContextError::StoreError(StoreError::NotFound { .. }) => {
+                    ChannelError::MissingConsensusStateAtProofHeight {
+                        client_id: client_id_on_b.clone(),
+                        proof_height: msg.proof_height_on_a,
+                    }
+                    .into()
+                }
+                err => err,
+            })?;
         let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = &chan_end_on_b.counterparty().port_id;
         let chan_id_on_a = chan_end_on_b
@@ -140,13 +157,15 @@ where
             },
         )?;
 
-        let expected_chan_end_on_a = ChannelEnd::new(
+        let mut expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Closed,
             *chan_end_on_b.ordering(),
             Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
             vec![conn_id_on_a.clone()],
             chan_end_on_b.version().clone(),
         )?;
+        // an upgraded channel end keeps its upgrade sequence once closed
+        expected_chan_end_on_a.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
@@ -4,7 +4,6 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseInit;
 use ibc_core_channel_types::msgs::MsgChannelCloseInit;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ChannelEndPath;
@@ -12,6 +11,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::verify_connection_open;
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -105,9 +106,7 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
-
-    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_a = verify_connection_open(ctx_a, &chan_end_on_a.connection_hops()[0])?;
 
     let client_id_on_a = conn_end_on_a.client_id();
 
@@ -4,7 +4,6 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenAck;
 use ibc_core_channel_types::msgs::MsgChannelOpenAck;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
@@ -13,6 +12,8 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::verify_connection_open;
+
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -101,9 +102,7 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
-
-    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_a = verify_connection_open(ctx_a, &chan_end_on_a.connection_hops()[0])?;
 
     // Verify proofs
     {
@@ -134,7 +133,9 @@ where
         let expected_chan_end_on_b = ChannelEnd::new(
             ChannelState::TryOpen,
             // Note: Both ends of a channel must have the same ordering, so it's
-            // fine to use A's ordering here
+            // fine to use A's ordering here. This is also what enforces the
+            // agreement: if B opened its end with another ordering, the proof
+            // below fails to verify.
             *chan_end_on_a.ordering(),
             Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
             vec![conn_id_on_b.clone()],
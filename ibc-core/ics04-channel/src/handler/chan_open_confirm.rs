@@ -5,7 +5,6 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenConfirm;
 use ibc_core_channel_types::msgs::MsgChannelOpenConfirm;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
@@ -14,6 +13,8 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::verify_connection_open;
+
 pub fn chan_open_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -106,9 +107,7 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_b.verify_connection_hops_length()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
-
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_b = verify_connection_open(ctx_b, &chan_end_on_b.connection_hops()[0])?;
 
     // Verify proofs
     {
@@ -142,6 +141,8 @@ where
 
         let expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Open,
+            // Both ends must have the same ordering: if A's differs from B's,
+            // the proof below fails to verify.
             *chan_end_on_b.ordering(),
             Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
             vec![conn_id_on_a.clone()],
@@ -5,7 +5,6 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenTry;
 use ibc_core_channel_types::msgs::MsgChannelOpenTry;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ChannelId;
@@ -17,6 +16,8 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::verify_connection_open;
+
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -123,9 +124,7 @@ where
 
     msg.verify_connection_hops_length()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
-
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_b = verify_connection_open(ctx_b, &msg.connection_hops_on_b[0])?;
 
     let conn_version = conn_end_on_b.versions();
 
@@ -0,0 +1,236 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeAck`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeAck;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeAck;
+use ibc_core_channel_types::upgrade::Upgrade;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_connection_id, current_fields, expected_counterparty_chan_end,
+    get_upgrade, upgrade_timeout, verify_counterparty_membership, verify_upgrade_not_timed_out,
+};
+
+pub fn chan_upgrade_ack_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    // An incompatible or timed out counterparty upgrade is not an error: the
+    // upgrade is aborted on execution so that the counterparty can cancel it.
+    let upgrade = get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+    if verify_upgrade_compatible(ctx_a, &upgrade, &msg.counterparty_upgrade).is_ok() {
+        module.on_chan_upgrade_ack_validate(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            &msg.counterparty_upgrade.fields.version,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn chan_upgrade_ack_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let mut upgrade = get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    if let Err(e) = verify_upgrade_compatible(ctx_a, &upgrade, &msg.counterparty_upgrade) {
+        return abort_upgrade(
+            ctx_a,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            chan_end_on_a,
+            e.to_string(),
+        );
+    }
+
+    let extras = module.on_chan_upgrade_ack_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.counterparty_upgrade.fields.version,
+    )?;
+
+    // state changes
+    {
+        // Packets sent from now on wait for the upgrade, so the ones already
+        // sent are flushed before it is applied.
+        let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        upgrade.next_sequence_send = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+        upgrade.timeout = upgrade_timeout(ctx_a)?;
+
+        let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        ctx_a.store_channel_upgrade(&upgrade_path_on_a, upgrade)?;
+
+        let counterparty_upgrade_path_on_a =
+            ChannelCounterpartyUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        ctx_a.store_counterparty_channel_upgrade(
+            &counterparty_upgrade_path_on_a,
+            msg.counterparty_upgrade.clone(),
+        )?;
+
+        if ctx_a.has_inflight_packets(&chan_end_path_on_a)? {
+            chan_end_on_a.set_state(State::Flushing);
+        } else {
+            chan_end_on_a.set_state(State::FlushComplete);
+        }
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message(format!(
+            "success: channel upgrade ack with channel identifier {} and upgrade sequence {}",
+            msg.chan_id_on_a,
+            chan_end_on_a.upgrade_sequence()
+        ))?;
+
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeAckChannel(UpgradeAck::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a.counterparty().port_id().clone(),
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The upgrade was initiated on this end, which stays open until the
+    // counterparty agrees to it.
+    chan_end_on_a.verify_state_matches(&State::Open)?;
+
+    get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    // Verify proofs
+    {
+        let port_id_on_b = chan_end_on_a.counterparty().port_id();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        // The counterparty flushes its packets with the current parameters
+        // of the channel.
+        let expected_chan_end_on_b = expected_counterparty_chan_end(
+            ctx_a,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            State::Flushing,
+            &current_fields(&chan_end_on_a),
+            chan_end_on_a.upgrade_sequence(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &chan_end_on_a,
+            &msg.proof_chan_end_on_b,
+            msg.proof_height_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+            expected_chan_end_on_b.encode_vec(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &chan_end_on_a,
+            &msg.proof_upgrade_on_b,
+            msg.proof_height_on_b,
+            Path::ChannelUpgrade(ChannelUpgradePath::new(port_id_on_b, chan_id_on_b)),
+            msg.counterparty_upgrade.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the upgrade the counterparty agreed to matches the proposed
+/// upgrade and has not timed out yet.
+fn verify_upgrade_compatible<Ctx>(
+    ctx_a: &Ctx,
+    upgrade: &Upgrade,
+    counterparty_upgrade: &Upgrade,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if upgrade.fields.ordering != counterparty_upgrade.fields.ordering {
+        return Err(ChannelError::IncompatibleUpgrade {
+            description: format!(
+                "proposed ordering {} but the counterparty agreed to {}",
+                upgrade.fields.ordering, counterparty_upgrade.fields.ordering
+            ),
+        }
+        .into());
+    }
+
+    if upgrade.fields.version != counterparty_upgrade.fields.version {
+        return Err(ChannelError::IncompatibleUpgrade {
+            description: format!(
+                "proposed version {} but the counterparty agreed to {}",
+                upgrade.fields.version, counterparty_upgrade.fields.version
+            ),
+        }
+        .into());
+    }
+
+    let expected_conn_id_on_b =
+        counterparty_connection_id(ctx_a, &upgrade.fields.connection_hops[0])?;
+    let conn_id_on_b = &counterparty_upgrade.fields.connection_hops[0];
+
+    if &expected_conn_id_on_b != conn_id_on_b {
+        return Err(ChannelError::IncompatibleCounterpartyUpgrade {
+            expected: expected_conn_id_on_b,
+            actual: conn_id_on_b.clone(),
+        }
+        .into());
+    }
+
+    verify_upgrade_not_timed_out(ctx_a, counterparty_upgrade)
+}
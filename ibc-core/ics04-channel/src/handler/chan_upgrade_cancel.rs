@@ -0,0 +1,156 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeCancel;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, get_upgrade, verify_counterparty_membership};
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    if is_authority_cancel(ctx_a, &chan_end_on_a, &msg) {
+        return Ok(());
+    }
+
+    let (Some(error_receipt_on_b), Some(proof_error_receipt_on_b), Some(proof_height_on_b)) = (
+        &msg.error_receipt_on_b,
+        &msg.proof_error_receipt_on_b,
+        msg.proof_height_on_b,
+    ) else {
+        return Err(ChannelError::MissingErrorReceipt.into());
+    };
+
+    // A channel end that completed flushing can only cancel the upgrade the
+    // counterparty aborted, not an earlier one.
+    let sequence_valid = if chan_end_on_a.state == State::FlushComplete {
+        error_receipt_on_b.sequence == chan_end_on_a.upgrade_sequence()
+    } else {
+        error_receipt_on_b.sequence >= chan_end_on_a.upgrade_sequence()
+    };
+    if !sequence_valid {
+        return Err(ChannelError::InvalidErrorReceiptSequence {
+            local: chan_end_on_a.upgrade_sequence(),
+            receipt: error_receipt_on_b.sequence,
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let port_id_on_b = chan_end_on_a.counterparty().port_id();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &chan_end_on_a,
+            proof_error_receipt_on_b,
+            proof_height_on_b,
+            Path::ChannelUpgradeError(ChannelUpgradeErrorPath::new(port_id_on_b, chan_id_on_b)),
+            error_receipt_on_b.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Both ends have to agree on the upgrade sequence for the next upgrade
+    // to succeed.
+    let message = match &msg.error_receipt_on_b {
+        Some(error_receipt_on_b) if !is_authority_cancel(ctx_a, &chan_end_on_a, &msg) => {
+            if error_receipt_on_b.sequence > chan_end_on_a.upgrade_sequence() {
+                chan_end_on_a.set_upgrade_sequence(error_receipt_on_b.sequence);
+            }
+
+            format!(
+                "upgrade cancelled by the counterparty: {}",
+                error_receipt_on_b.message
+            )
+        }
+        _ => format!("upgrade cancelled by {}", msg.signer),
+    };
+
+    abort_upgrade(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        chan_end_on_a.clone(),
+        message,
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message(format!(
+            "success: channel upgrade cancel with channel identifier {} and upgrade sequence {}",
+            msg.chan_id_on_a,
+            chan_end_on_a.upgrade_sequence()
+        ))?;
+
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeCancelChannel(UpgradeCancel::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a.counterparty().port_id().clone(),
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
+
+/// Returns whether the upgrade is cancelled by the upgrade authority, which
+/// may do so without the consent of the counterparty, i.e. without proving
+/// its error receipt.
+///
+/// This is not allowed once the channel end completed flushing, as the
+/// counterparty may then already have moved on to the upgraded parameters.
+fn is_authority_cancel<Ctx>(
+    ctx_a: &Ctx,
+    chan_end_on_a: &ChannelEnd,
+    msg: &MsgChannelUpgradeCancel,
+) -> bool
+where
+    Ctx: ValidationContext,
+{
+    chan_end_on_a.state != State::FlushComplete
+        && ctx_a.validate_upgrade_authority(&msg.signer).is_ok()
+}
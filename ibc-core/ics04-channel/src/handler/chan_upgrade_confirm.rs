@@ -0,0 +1,172 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeConfirm`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeConfirm;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeConfirm;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, current_fields, expected_counterparty_chan_end, get_upgrade,
+    open_upgraded_channel, verify_counterparty_membership, verify_upgrade_not_timed_out,
+};
+
+pub fn chan_upgrade_confirm_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)
+}
+
+pub fn chan_upgrade_confirm_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let mut chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // A timed out upgrade is not an error: it is aborted so that the
+    // counterparty can cancel it as well.
+    if let Err(e) = verify_upgrade_not_timed_out(ctx_b, &msg.counterparty_upgrade) {
+        return abort_upgrade(
+            ctx_b,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            chan_end_on_b,
+            e.to_string(),
+        );
+    }
+
+    // state changes
+    {
+        let counterparty_upgrade_path_on_b =
+            ChannelCounterpartyUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        ctx_b.store_counterparty_channel_upgrade(
+            &counterparty_upgrade_path_on_b,
+            msg.counterparty_upgrade.clone(),
+        )?;
+
+        if !ctx_b.has_inflight_packets(&chan_end_path_on_b)? {
+            chan_end_on_b.set_state(State::FlushComplete);
+        }
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message(format!(
+            "success: channel upgrade confirm with channel identifier {} and upgrade sequence {}",
+            msg.chan_id_on_b,
+            chan_end_on_b.upgrade_sequence()
+        ))?;
+
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeConfirmChannel(UpgradeConfirm::new(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b.counterparty().port_id().clone(),
+            chan_id_on_a,
+            chan_end_on_b.upgrade_sequence(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+    }
+
+    // Both ends flushed their packets: the upgrade completes right away.
+    if chan_end_on_b.state == State::FlushComplete
+        && msg.counterparty_chan_state == State::FlushComplete
+    {
+        open_upgraded_channel(
+            ctx_b,
+            module,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            chan_end_on_b,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&State::Flushing)?;
+
+    if !matches!(
+        msg.counterparty_chan_state,
+        State::Flushing | State::FlushComplete
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Counterparty channel state must be Flushing or FlushComplete".to_string(),
+            actual: msg.counterparty_chan_state.to_string(),
+        }
+        .into());
+    }
+
+    get_upgrade(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b)?;
+
+    // Verify proofs
+    {
+        let port_id_on_a = chan_end_on_b.counterparty().port_id();
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        let expected_chan_end_on_a = expected_counterparty_chan_end(
+            ctx_b,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            msg.counterparty_chan_state,
+            &current_fields(&chan_end_on_b),
+            chan_end_on_b.upgrade_sequence(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_b,
+            &chan_end_on_b,
+            &msg.proof_chan_end_on_a,
+            msg.proof_height_on_a,
+            Path::ChannelEnd(ChannelEndPath::new(port_id_on_a, chan_id_on_a)),
+            expected_chan_end_on_a.encode_vec(),
+        )?;
+
+        verify_counterparty_membership(
+            ctx_b,
+            &chan_end_on_b,
+            &msg.proof_upgrade_on_a,
+            msg.proof_height_on_a,
+            Path::ChannelUpgrade(ChannelUpgradePath::new(port_id_on_a, chan_id_on_a)),
+            msg.counterparty_upgrade.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeInit`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeInit;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeInit;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+use super::verify_connection_open;
+
+pub fn chan_upgrade_init_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    module.on_chan_upgrade_init_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_init_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_init_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let upgrade_sequence = chan_end_on_a.upgrade_sequence() + 1;
+
+    // state changes
+    {
+        // A new upgrade replaces the one in progress, which the counterparty
+        // may have agreed to already: it is told to cancel it.
+        let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        if ctx_a.channel_upgrade(&upgrade_path_on_a)?.is_some() {
+            let error_receipt = ErrorReceipt::new(
+                chan_end_on_a.upgrade_sequence(),
+                "upgrade replaced by a new upgrade".to_string(),
+            );
+            ctx_a.store_channel_upgrade_error(
+                &ChannelUpgradeErrorPath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+                error_receipt,
+            )?;
+        }
+
+        chan_end_on_a.set_upgrade_sequence(upgrade_sequence);
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        // The timeout and the next send sequence are only set once the
+        // counterparty has agreed to the upgrade.
+        let upgrade = Upgrade::new(
+            UpgradeFields::new(msg.fields.ordering, msg.fields.connection_hops, version),
+            UpgradeTimeout::none(),
+            Sequence::from(0),
+        );
+
+        ctx_a.store_channel_upgrade(&upgrade_path_on_a, upgrade)?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message(format!(
+            "success: channel upgrade init with channel identifier {} and upgrade sequence {upgrade_sequence}",
+            msg.chan_id_on_a
+        ))?;
+
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeInitChannel(UpgradeInit::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a.counterparty().port_id().clone(),
+            chan_id_on_b,
+            upgrade_sequence,
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeInit) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    // Upgrades change the parameters of a channel used by others, so only
+    // the upgrade authority of the host may propose them.
+    ctx_a.validate_upgrade_authority(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Only open channels can be upgraded.
+    chan_end_on_a.verify_state_matches(&State::Open)?;
+
+    if msg.fields.ordering == chan_end_on_a.ordering
+        && msg.fields.connection_hops == chan_end_on_a.connection_hops
+        && msg.fields.version == chan_end_on_a.version
+    {
+        return Err(ChannelError::UpgradeFieldsUnchanged.into());
+    }
+
+    msg.fields.validate_basic()?;
+
+    let conn_end_on_a = verify_connection_open(ctx_a, &msg.fields.connection_hops[0])?;
+
+    conn_end_on_a.versions()[0].verify_feature_supported(msg.fields.ordering.to_string())?;
+
+    Ok(())
+}
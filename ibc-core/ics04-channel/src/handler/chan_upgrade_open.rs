@@ -0,0 +1,119 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeOpen`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeOpen;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::{ChannelEndPath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    current_fields, expected_counterparty_chan_end, get_upgrade, open_upgraded_channel,
+    verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_open_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_open_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    open_upgraded_channel(
+        ctx_a,
+        module,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        chan_end_on_a,
+    )
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeOpen) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&State::FlushComplete)?;
+
+    let upgrade = get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    // The counterparty either flushed its packets as well, or already
+    // completed the upgrade and moved on to the upgraded parameters.
+    let expected_chan_end_on_b = match msg.counterparty_chan_state {
+        State::FlushComplete => expected_counterparty_chan_end(
+            ctx_a,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            State::FlushComplete,
+            &current_fields(&chan_end_on_a),
+            chan_end_on_a.upgrade_sequence(),
+        )?,
+        State::Open => {
+            // The counterparty may have initiated a new upgrade since.
+            if msg.counterparty_upgrade_sequence < chan_end_on_a.upgrade_sequence() {
+                return Err(ChannelError::StaleCounterpartyUpgradeSequence {
+                    local: chan_end_on_a.upgrade_sequence(),
+                    counterparty: msg.counterparty_upgrade_sequence,
+                }
+                .into());
+            }
+
+            expected_counterparty_chan_end(
+                ctx_a,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                State::Open,
+                &upgrade.fields,
+                msg.counterparty_upgrade_sequence,
+            )?
+        }
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be FlushComplete or Open".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    };
+
+    // Verify proofs
+    {
+        let port_id_on_b = chan_end_on_a.counterparty().port_id();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &chan_end_on_a,
+            &msg.proof_chan_end_on_b,
+            msg.proof_height_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+            expected_chan_end_on_b.encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
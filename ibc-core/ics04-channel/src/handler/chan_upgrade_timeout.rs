@@ -0,0 +1,170 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+
+use ibc_core_channel_types::channel::{Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTimedOut;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ChannelCounterpartyUpgradePath, ChannelEndPath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_connection_id, counterparty_timestamp, get_upgrade,
+    verify_counterparty_membership,
+};
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !matches!(chan_end_on_a.state, State::Flushing | State::FlushComplete) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be Flushing or FlushComplete".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    let upgrade = get_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    // The upgrade times out on the deadline the counterparty set when agreeing
+    // to it, measured on the counterparty.
+    let counterparty_upgrade_path_on_a =
+        ChannelCounterpartyUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let counterparty_upgrade = ctx_a
+        .counterparty_channel_upgrade(&counterparty_upgrade_path_on_a)?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    let timestamp_of_b = counterparty_timestamp(ctx_a, &chan_end_on_a, msg.proof_height_on_b)?;
+    if !counterparty_upgrade
+        .timeout
+        .has_expired(msg.proof_height_on_b, &timestamp_of_b)
+    {
+        return Err(ChannelError::UpgradeTimeoutNotReached {
+            chain_height: msg.proof_height_on_b,
+            chain_timestamp: timestamp_of_b,
+        }
+        .into());
+    }
+
+    let chan_end_on_b = &msg.counterparty_chan_end;
+
+    chan_end_on_b.verify_counterparty_matches(&Counterparty::new(
+        msg.port_id_on_a.clone(),
+        Some(msg.chan_id_on_a.clone()),
+    ))?;
+
+    match chan_end_on_b.state {
+        State::Flushing => {}
+        State::Open => {
+            // The counterparty may only have completed the upgrade if this end
+            // already flushed its packets. It then has to open its end as
+            // well, instead of cancelling the upgrade.
+            let upgraded_conn_id_on_b =
+                counterparty_connection_id(ctx_a, &upgrade.fields.connection_hops[0])?;
+
+            if chan_end_on_b.upgrade_sequence() == chan_end_on_a.upgrade_sequence()
+                && chan_end_on_b.ordering == upgrade.fields.ordering
+                && chan_end_on_b.version == upgrade.fields.version
+                && chan_end_on_b.connection_hops == [upgraded_conn_id_on_b]
+            {
+                return Err(ChannelError::CounterpartyUpgradeCompleted.into());
+            }
+        }
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be Flushing or Open".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    }
+
+    if chan_end_on_b.upgrade_sequence() < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::StaleCounterpartyUpgradeSequence {
+            local: chan_end_on_a.upgrade_sequence(),
+            counterparty: chan_end_on_b.upgrade_sequence(),
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let port_id_on_b = chan_end_on_a.counterparty().port_id();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        verify_counterparty_membership(
+            ctx_a,
+            &chan_end_on_a,
+            &msg.proof_chan_end_on_b,
+            msg.proof_height_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+            chan_end_on_b.clone().encode_vec(),
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    abort_upgrade(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        chan_end_on_a.clone(),
+        format!(
+            "upgrade timed out at counterparty height {}",
+            msg.proof_height_on_b
+        ),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message(format!(
+            "success: channel upgrade timeout with channel identifier {} and upgrade sequence {}",
+            msg.chan_id_on_a,
+            chan_end_on_a.upgrade_sequence()
+        ))?;
+
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeTimeoutChannel(UpgradeTimedOut::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a.counterparty().port_id().clone(),
+            chan_id_on_b,
+            chan_end_on_a.upgrade_sequence(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
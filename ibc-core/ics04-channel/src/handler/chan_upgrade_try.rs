@@ -0,0 +1,267 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTry`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTry;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTry;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::upgrade_timeout;
+use super::verify_connection_open;
+
+pub fn chan_upgrade_try_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    module.on_chan_upgrade_try_validate(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.counterparty_upgrade_fields.ordering,
+        &msg.proposed_upgrade_connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_try_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_try_execute(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.counterparty_upgrade_fields.ordering,
+        &msg.proposed_upgrade_connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let mut chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // state changes
+    {
+        // Packets already sent on the channel have to be flushed before the
+        // upgrade can be applied. The counterparty only sees this end
+        // flushing once it acknowledged the upgrade, so the channel end stays
+        // `Flushing` even without in-flight packets.
+        chan_end_on_b.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+        chan_end_on_b.set_state(State::Flushing);
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+
+        let seq_send_path_on_b = SeqSendPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        let next_sequence_send = ctx_b.get_next_sequence_send(&seq_send_path_on_b)?;
+
+        // The counterparty has to complete the upgrade before this timeout,
+        // or it is aborted.
+        let timeout = upgrade_timeout(ctx_b)?;
+
+        let upgrade = Upgrade::new(
+            UpgradeFields::new(
+                msg.counterparty_upgrade_fields.ordering,
+                msg.proposed_upgrade_connection_hops.clone(),
+                version,
+            ),
+            timeout,
+            next_sequence_send,
+        );
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        ctx_b.store_channel_upgrade(&upgrade_path_on_b, upgrade)?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message(format!(
+            "success: channel upgrade try with channel identifier {} and upgrade sequence {}",
+            msg.chan_id_on_b, msg.counterparty_upgrade_sequence
+        ))?;
+
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?
+            .clone();
+
+        let core_event = IbcEvent::UpgradeTryChannel(UpgradeTry::new(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b.counterparty().port_id().clone(),
+            chan_id_on_a,
+            msg.counterparty_upgrade_sequence,
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_b.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeTry) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // Only open channels can be upgraded.
+    chan_end_on_b.verify_state_matches(&State::Open)?;
+
+    // Crossing hellos, where both ends initiate an upgrade, are not
+    // supported: an upgrade initiated on this end has to be cancelled first.
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    if ctx_b.channel_upgrade(&upgrade_path_on_b)?.is_some() {
+        return Err(ChannelError::UpgradeInProgress {
+            port_id: msg.port_id_on_b.clone(),
+            channel_id: msg.chan_id_on_b.clone(),
+        }
+        .into());
+    }
+
+    if msg.counterparty_upgrade_sequence <= chan_end_on_b.upgrade_sequence() {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            local: chan_end_on_b.upgrade_sequence(),
+            counterparty: msg.counterparty_upgrade_sequence,
+        }
+        .into());
+    }
+
+    msg.verify_connection_hops_length()?;
+
+    // The proposed connection has to be open, support the proposed ordering and
+    // lead to the connection the counterparty proposed.
+    {
+        let proposed_conn_end_on_b =
+            verify_connection_open(ctx_b, &msg.proposed_upgrade_connection_hops[0])?;
+
+        proposed_conn_end_on_b.versions()[0]
+            .verify_feature_supported(msg.counterparty_upgrade_fields.ordering.to_string())?;
+
+        let proposed_conn_id_on_a = proposed_conn_end_on_b
+            .counterparty()
+            .connection_id()
+            .ok_or(ChannelError::UndefinedConnectionCounterparty {
+                connection_id: msg.proposed_upgrade_connection_hops[0].clone(),
+            })?;
+
+        let counterparty_conn_id_on_a = &msg.counterparty_upgrade_fields.connection_hops[0];
+
+        if proposed_conn_id_on_a != counterparty_conn_id_on_a {
+            return Err(ChannelError::IncompatibleCounterpartyUpgrade {
+                expected: proposed_conn_id_on_a.clone(),
+                actual: counterparty_conn_id_on_a.clone(),
+            }
+            .into());
+        }
+    }
+
+    chan_end_on_b.verify_connection_hops_length()?;
+
+    let conn_end_on_b = verify_connection_open(ctx_b, &chan_end_on_b.connection_hops()[0])?;
+
+    // Verify proofs
+    {
+        let client_id_on_b = conn_end_on_b.client_id();
+        let client_val_ctx_b = ctx_b.get_client_validation_context();
+        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+        client_state_of_a_on_b
+            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+            .verify_is_active()?;
+
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b =
+            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let port_id_on_a = chan_end_on_b.counterparty().port_id();
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let mut expected_chan_end_on_a = ChannelEnd::new(
+            State::Open,
+            chan_end_on_b.ordering,
+            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+            vec![conn_id_on_a.clone()],
+            chan_end_on_b.version.clone(),
+        )?;
+        expected_chan_end_on_a.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+        let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the channel state against the expected channel end.
+        client_state_of_a_on_b
+            .verify_membership(
+                prefix_on_a,
+                &msg.proof_chan_end_on_a,
+                consensus_state_of_a_on_b.root(),
+                Path::ChannelEnd(chan_end_path_on_a),
+                expected_chan_end_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        // The counterparty stores its upgrade without a timeout nor a next
+        // send sequence until the upgrade is agreed upon.
+        let expected_upgrade_on_a = Upgrade::new(
+            msg.counterparty_upgrade_fields.clone(),
+            UpgradeTimeout::none(),
+            Sequence::from(0),
+        );
+        let upgrade_path_on_a = ChannelUpgradePath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the upgrade proposed by the counterparty.
+        client_state_of_a_on_b
+            .verify_membership(
+                prefix_on_a,
+                &msg.proof_upgrade_on_a,
+                consensus_state_of_a_on_b.root(),
+                Path::ChannelUpgrade(upgrade_path_on_a),
+                expected_upgrade_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
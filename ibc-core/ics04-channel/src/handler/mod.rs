@@ -6,10 +6,19 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
+mod prune_acknowledgements;
 mod recv_packet;
 mod send_packet;
 mod timeout;
 mod timeout_on_close;
+mod upgrade;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +27,42 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_open::*;
+pub use chan_upgrade_timeout::*;
+pub use chan_upgrade_try::*;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::ValidationContext;
+pub use prune_acknowledgements::*;
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+
+/// Returns the end of the given connection, failing with
+/// [`ChannelError::ConnectionNotOpen`] if the connection is not open.
+pub(crate) fn verify_connection_open<Ctx>(
+    ctx: &Ctx,
+    connection_id: &ConnectionId,
+) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = ctx.connection_end(connection_id)?;
+
+    if !conn_end.is_open() {
+        return Err(ChannelError::ConnectionNotOpen {
+            connection_id: connection_id.clone(),
+            state: *conn_end.state(),
+        }
+        .into());
+    }
+
+    Ok(conn_end)
+}
@@ -0,0 +1,127 @@
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Checks that the counterparty has relayed the acknowledgements of the given
+/// channel end below `before_sequence`, less the ones kept by the host's
+/// [`ack_retention_window`](ValidationContext::ack_retention_window).
+///
+/// `relayed` must pair every such acknowledgement still stored with a proof
+/// that the counterparty no longer holds the packet's commitment at
+/// `proof_height_on_a`. The sending chain deletes a packet commitment once it
+/// has processed the acknowledgement of the packet, so the absence of the
+/// commitment proves that the acknowledgement stored on this chain is no
+/// longer needed. Packet receipts are left untouched, so replayed packets are
+/// still rejected.
+pub fn prune_acknowledgements_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    chan_end_path_on_b: &ChannelEndPath,
+    before_sequence: Sequence,
+    relayed: &[(Sequence, CommitmentProofBytes)],
+    proof_height_on_a: Height,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    let prune_before = prune_before(ctx_b, before_sequence)?;
+
+    let chan_end_on_b = ctx_b.channel_end(chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_connection_hops_length()?;
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    let client_id_on_b = conn_end_on_b.client_id();
+    let client_val_ctx_b = ctx_b.get_client_validation_context();
+    let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+    client_state_of_a_on_b
+        .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+        .verify_is_active()?;
+    client_state_of_a_on_b.validate_proof_height(proof_height_on_a)?;
+
+    let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+        client_id_on_b.clone(),
+        proof_height_on_a.revision_number(),
+        proof_height_on_a.revision_height(),
+    );
+    let consensus_state_of_a_on_b =
+        client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+    let prefix_on_a = conn_end_on_b.counterparty().prefix();
+    let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+    let chan_id_on_a = chan_end_on_b
+        .counterparty()
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    let proofs: BTreeMap<&Sequence, &CommitmentProofBytes> = relayed
+        .iter()
+        .map(|(sequence, proof)| (sequence, proof))
+        .collect();
+
+    for sequence in (1..prune_before.value()).map(Sequence::from) {
+        let ack_path_on_b = AckPath::new(&chan_end_path_on_b.0, &chan_end_path_on_b.1, sequence);
+
+        if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_err() {
+            continue;
+        }
+
+        let proof_unreceived_on_a = proofs
+            .get(&sequence)
+            .ok_or(PacketError::MissingRelayProof { sequence })?;
+        let commitment_path_on_a = CommitmentPath::new(port_id_on_a, chan_id_on_a, sequence);
+
+        client_state_of_a_on_b
+            .verify_non_membership(
+                prefix_on_a,
+                proof_unreceived_on_a,
+                consensus_state_of_a_on_b.root(),
+                Path::Commitment(commitment_path_on_a),
+            )
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the acknowledgements of the given channel end below
+/// `before_sequence`, less the ones kept by the host's retention window, once
+/// [`prune_acknowledgements_validate`] has checked that the counterparty
+/// relayed them. Returns the number of acknowledgements removed.
+pub fn prune_acknowledgements_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    chan_end_path_on_b: &ChannelEndPath,
+    before_sequence: Sequence,
+) -> Result<usize, ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let prune_before = prune_before(ctx_b, before_sequence)?;
+
+    ctx_b.prune_acknowledgements(chan_end_path_on_b, prune_before)
+}
+
+/// Returns the sequence below which acknowledgements are pruned, keeping the
+/// ones within the host's retention window below `before_sequence`.
+fn prune_before<Ctx>(ctx: &Ctx, before_sequence: Sequence) -> Result<Sequence, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let window = ctx
+        .ack_retention_window()
+        .ok_or(ChannelError::AckPruningNotSupportedByHost)?;
+
+    Ok(before_sequence.value().saturating_sub(window).into())
+}
@@ -1,34 +1,54 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
 use ibc_core_channel_types::msgs::MsgRecvPacket;
 use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client::context::prelude::*;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
 use ibc_core_connection::delay::verify_conn_delay_passed;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqRecvPath,
+    AckPath, ChannelCounterpartyUpgradePath, ChannelEndPath, ClientConsensusStatePath,
+    CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
+use super::verify_connection_open;
+
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
 {
     // Note: this contains the validation for `write_acknowledgement` as well.
-    validate(ctx_b, &msg)
+    validate(ctx_b, &msg, None)
 
     // nothing to validate with the module, since `onRecvPacket` cannot fail.
     // If any error occurs, then an "error acknowledgement" must be returned.
 }
 
+/// Same as [`recv_packet_validate`], except that the packet commitment is
+/// verified against the given commitment root of the counterparty instead of
+/// the root of the consensus state stored at `msg.proof_height_on_a`.
+///
+/// This lets a host verify several packets proven at the same height while
+/// fetching and decoding the consensus state only once. The caller is
+/// responsible for passing the root of the consensus state at
+/// `msg.proof_height_on_a` of the client backing the channel.
+pub fn recv_packet_validate_with_root<ValCtx>(
+    ctx_b: &ValCtx,
+    msg: MsgRecvPacket,
+    root_on_a: &CommitmentRoot,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg, Some(root_on_a))
+}
+
 pub fn recv_packet_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -64,7 +84,10 @@ where
             }
         };
 
+        // The acknowledgement written on the first receipt stays in place;
+        // the module isn't invoked again.
         if packet_already_received {
+            ctx_b.log_message("no-op: packet already received".to_string())?;
             return Ok(());
         }
     }
@@ -100,7 +123,7 @@ where
         // `writeAcknowledgement` handler state changes
         ctx_b.store_packet_acknowledgement(
             &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
+            acknowledgement.commitment_with(ctx_b.commitment_hasher()),
         )?;
     }
 
@@ -137,7 +160,11 @@ where
     Ok(())
 }
 
-fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
+fn validate<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgRecvPacket,
+    root_on_a: Option<&CommitmentRoot>,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
@@ -147,7 +174,45 @@ where
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+    // Packets sent before an upgrade are still received while the channel
+    // ends are flushed.
+    if !matches!(
+        chan_end_on_b.state,
+        ChannelState::Open | ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be Open, Flushing or FlushComplete".to_string(),
+            actual: chan_end_on_b.state.to_string(),
+        }
+        .into());
+    }
+
+    // Once the counterparty agreed to an upgrade, the packets it sends
+    // afterwards wait for the upgraded channel.
+    if chan_end_on_b.state != ChannelState::Open {
+        let counterparty_upgrade_path_on_b =
+            ChannelCounterpartyUpgradePath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+        if let Some(counterparty_upgrade) =
+            ctx_b.counterparty_channel_upgrade(&counterparty_upgrade_path_on_b)?
+        {
+            if msg.packet.seq_on_a >= counterparty_upgrade.next_sequence_send {
+                return Err(PacketError::InvalidPacketSequence {
+                    given_sequence: msg.packet.seq_on_a,
+                    next_sequence: counterparty_upgrade.next_sequence_send,
+                }
+                .into());
+            }
+        }
+    }
+
+    if ctx_b.is_channel_paused(&chan_end_path_on_b)? {
+        return Err(ChannelError::ChannelPaused {
+            port_id: msg.packet.port_id_on_b.clone(),
+            channel_id: msg.packet.chan_id_on_b.clone(),
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_a.clone(),
@@ -157,9 +222,22 @@ where
     chan_end_on_b.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
-
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_b = verify_connection_open(ctx_b, conn_id_on_b)?;
+
+    // Both channel ends were opened with an ordering negotiated by the
+    // connection handshake, so the local one must still be among them.
+    let order_supported = conn_end_on_b.versions().iter().any(|version| {
+        version
+            .verify_feature_supported(chan_end_on_b.ordering.to_string())
+            .is_ok()
+    });
+    if !order_supported {
+        return Err(ChannelError::OrderMismatch {
+            order: chan_end_on_b.ordering,
+            connection_id: conn_id_on_b.clone(),
+        }
+        .into());
+    }
 
     let latest_height = ctx_b.host_height()?;
     if msg.packet.timeout_height_on_b.has_expired(latest_height) {
@@ -187,20 +265,23 @@ where
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let consensus_state_of_a_on_b;
+        let root_on_a = match root_on_a {
+            Some(root_on_a) => root_on_a,
+            None => {
+                let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+                    client_id_on_b.clone(),
+                    msg.proof_height_on_a.revision_number(),
+                    msg.proof_height_on_a.revision_height(),
+                );
+
+                consensus_state_of_a_on_b =
+                    client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+                consensus_state_of_a_on_b.root()
+            }
+        };
 
-        let expected_commitment_on_a = compute_packet_commitment(
-            &msg.packet.data,
-            &msg.packet.timeout_height_on_b,
-            &msg.packet.timeout_timestamp_on_b,
-        );
+        let expected_commitment_on_a = msg.packet.commitment_with(ctx_b.commitment_hasher());
         let commitment_path_on_a = CommitmentPath::new(
             &msg.packet.port_id_on_a,
             &msg.packet.chan_id_on_a,
@@ -214,7 +295,7 @@ where
             .verify_membership(
                 conn_end_on_b.counterparty().prefix(),
                 &msg.proof_commitment_on_a,
-                consensus_state_of_a_on_b.root(),
+                root_on_a,
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
@@ -230,10 +311,12 @@ where
             let seq_recv_path_on_b =
                 SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
             let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+            // Earlier sequences were already received, which makes the
+            // recvPacket a no-op, but later ones would skip packets
             if msg.packet.seq_on_a > next_seq_recv {
-                return Err(PacketError::InvalidPacketSequence {
-                    given_sequence: msg.packet.seq_on_a,
-                    next_sequence: next_seq_recv,
+                return Err(ChannelError::UnexpectedPacketSequence {
+                    expected: next_seq_recv,
+                    got: msg.packet.seq_on_a,
                 }
                 .into());
             }
@@ -246,20 +329,24 @@ where
         }
         Order::Unordered => {
             let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_a,
-                &msg.packet.chan_id_on_a,
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
                 msg.packet.seq_on_a,
             );
             let packet_rec = ctx_b.get_packet_receipt(&receipt_path_on_b);
             match packet_rec {
+                // Case where the packet was already received, which makes
+                // the recvPacket a no-op
                 Ok(_receipt) => {}
                 Err(ContextError::PacketError(PacketError::PacketReceiptNotFound { sequence }))
-                    if sequence == msg.packet.seq_on_a => {}
+                    if sequence == msg.packet.seq_on_a =>
+                {
+                    // Case where the recvPacket is successful and an
+                    // acknowledgement will be written (not a no-op)
+                    validate_write_acknowledgement(ctx_b, msg)?;
+                }
                 Err(e) => return Err(e),
             }
-            // Case where the recvPacket is successful and an
-            // acknowledgement will be written (not a no-op)
-            validate_write_acknowledgement(ctx_b, msg)?;
         }
         Order::None => {
             return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
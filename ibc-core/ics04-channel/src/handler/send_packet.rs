@@ -1,6 +1,5 @@
-use ibc_core_channel_types::channel::Counterparty;
-use ibc_core_channel_types::commitment::compute_packet_commitment;
-use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::channel::{Counterparty, State};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::SendPacket;
 use ibc_core_channel_types::packet::Packet;
 use ibc_core_client::context::prelude::*;
@@ -37,10 +36,27 @@ pub fn send_packet_validate(
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
+    if ctx_a.is_channel_paused(&chan_end_path_on_a)? {
+        return Err(ChannelError::ChannelPaused {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
+        }
+        .into());
+    }
+
     // Checks the channel end not be `Closed`.
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
+    // Packets cannot be sent while the channel is flushed for an upgrade.
+    if matches!(chan_end_on_a.state, State::Flushing | State::FlushComplete) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state cannot be Flushing or FlushComplete".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
         Some(packet.chan_id_on_b.clone()),
@@ -115,11 +131,7 @@ pub fn send_packet_execute(
 
     ctx_a.store_packet_commitment(
         &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
-        compute_packet_commitment(
-            &packet.data,
-            &packet.timeout_height_on_b,
-            &packet.timeout_timestamp_on_b,
-        ),
+        packet.commitment_with(ctx_a.commitment_hasher()),
     )?;
 
     // emit events and logs
@@ -1,8 +1,8 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
+use ibc_core_channel_types::timeout::TimeoutHeight;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
@@ -15,6 +15,7 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
 use super::timeout_on_close;
+use super::upgrade::{abort_upgrade, update_flush_state};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -85,12 +86,30 @@ where
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
+            // The upgrade of a closed channel cannot complete.
+            if chan_end_on_a.state == State::Flushing {
+                abort_upgrade(
+                    ctx_a,
+                    &packet.port_id_on_a,
+                    &packet.chan_id_on_a,
+                    chan_end_on_a.clone(),
+                    "packet timed out on an ordered channel".to_string(),
+                )?;
+            }
+
             let mut chan_end_on_a = chan_end_on_a;
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
         } else {
+            update_flush_state(
+                ctx_a,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                chan_end_on_a.clone(),
+            )?;
+
             chan_end_on_a
         }
     };
@@ -137,7 +156,15 @@ where
         &msg.packet.chan_id_on_a,
     ))?;
 
-    chan_end_on_a.verify_state_matches(&State::Open)?;
+    // Packets sent before an upgrade still time out while the channel end is
+    // flushed.
+    if !matches!(chan_end_on_a.state, State::Open | State::Flushing) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be Open or Flushing".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_b.clone(),
@@ -163,11 +190,7 @@ where
         return Ok(());
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
-        &msg.packet.data,
-        &msg.packet.timeout_height_on_b,
-        &msg.packet.timeout_timestamp_on_b,
-    );
+    let expected_commitment_on_a = msg.packet.commitment_with(ctx_a.commitment_hasher());
     if commitment_on_a != expected_commitment_on_a {
         return Err(PacketError::IncorrectPacketCommitment {
             sequence: msg.packet.seq_on_a,
@@ -198,6 +221,15 @@ where
         let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
 
         if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
+            // packets without a timeout height can only time out by timestamp
+            if msg.packet.timeout_height_on_b == TimeoutHeight::Never {
+                return Err(PacketError::PacketTimeoutTimestampNotReached {
+                    timeout_timestamp: msg.packet.timeout_timestamp_on_b,
+                    chain_timestamp: timestamp_of_b,
+                }
+                .into());
+            }
+
             return Err(PacketError::PacketTimeoutNotReached {
                 timeout_height: msg.packet.timeout_height_on_b,
                 chain_height: msg.proof_height_on_b,
@@ -226,7 +258,7 @@ where
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    msg.packet.seq_on_a.to_vec(),
+                    msg.next_seq_recv_on_b.to_vec(),
                 )
             }
             Order::Unordered => {
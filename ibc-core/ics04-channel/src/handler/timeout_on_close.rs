@@ -1,5 +1,4 @@
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::prelude::*;
@@ -44,11 +43,7 @@ where
         return Ok(());
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
-        &packet.data,
-        &packet.timeout_height_on_b,
-        &packet.timeout_timestamp_on_b,
-    );
+    let expected_commitment_on_a = packet.commitment_with(ctx_a.commitment_hasher());
     if commitment_on_a != expected_commitment_on_a {
         return Err(PacketError::IncorrectPacketCommitment {
             sequence: packet.seq_on_a,
@@ -94,13 +89,15 @@ where
             packet.port_id_on_a.clone(),
             Some(packet.chan_id_on_a.clone()),
         );
-        let expected_chan_end_on_b = ChannelEnd::new(
+        let mut expected_chan_end_on_b = ChannelEnd::new(
             State::Closed,
             *chan_end_on_a.ordering(),
             expected_counterparty,
             expected_conn_hops_on_b,
             chan_end_on_a.version().clone(),
         )?;
+        // an upgraded channel end keeps its upgrade sequence once closed
+        expected_chan_end_on_b.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
 
         let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
 
@@ -136,7 +133,7 @@ where
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    packet.seq_on_a.to_vec(),
+                    msg.next_seq_recv_on_b.to_vec(),
                 )
             }
             Order::Unordered => {
@@ -0,0 +1,378 @@
+//! Helpers shared by the handlers of the channel upgrade handshake and the
+//! packet handlers that flush channels being upgraded.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeOpen;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_host::types::path::{
+    ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use super::verify_connection_open;
+
+/// Returns the upgrade in progress on the given channel end, failing with
+/// [`ChannelError::UpgradeNotFound`] if there is none.
+pub(crate) fn get_upgrade<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<Upgrade, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.channel_upgrade(&ChannelUpgradePath::new(port_id, chan_id))?
+        .ok_or_else(|| {
+            ChannelError::UpgradeNotFound {
+                port_id: port_id.clone(),
+                channel_id: chan_id.clone(),
+            }
+            .into()
+        })
+}
+
+/// Returns the identifier the counterparty uses for the given connection.
+pub(crate) fn counterparty_connection_id<Ctx>(
+    ctx: &Ctx,
+    connection_id: &ConnectionId,
+) -> Result<ConnectionId, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.connection_end(connection_id)?
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| {
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: connection_id.clone(),
+            }
+            .into()
+        })
+}
+
+/// Returns the channel end the counterparty of the given channel end is
+/// expected to store, in the given state and with the given parameters.
+pub(crate) fn expected_counterparty_chan_end<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    state: State,
+    fields: &UpgradeFields,
+    upgrade_sequence: u64,
+) -> Result<ChannelEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let counterparty_conn_id = counterparty_connection_id(ctx, &fields.connection_hops[0])?;
+
+    let mut expected_chan_end = ChannelEnd::new(
+        state,
+        fields.ordering,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![counterparty_conn_id],
+        fields.version.clone(),
+    )?;
+    expected_chan_end.set_upgrade_sequence(upgrade_sequence);
+
+    Ok(expected_chan_end)
+}
+
+/// Returns the current parameters of the given channel end.
+pub(crate) fn current_fields(chan_end: &ChannelEnd) -> UpgradeFields {
+    UpgradeFields::new(
+        chan_end.ordering,
+        chan_end.connection_hops.clone(),
+        chan_end.version.clone(),
+    )
+}
+
+/// Verifies that the counterparty of the given channel end stores `value`
+/// under `path`, as proven by `proof` at `proof_height`.
+pub(crate) fn verify_counterparty_membership<Ctx>(
+    ctx: &Ctx,
+    chan_end: &ChannelEnd,
+    proof: &CommitmentProofBytes,
+    proof_height: Height,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    chan_end.verify_connection_hops_length()?;
+
+    let conn_end = verify_connection_open(ctx, &chan_end.connection_hops()[0])?;
+
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(ctx.get_client_validation_context(), client_id)?
+        .verify_is_active()?;
+
+    client_state.validate_proof_height(proof_height)?;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        proof_height.revision_number(),
+        proof_height.revision_height(),
+    );
+    let consensus_state = client_val_ctx.consensus_state(&client_cons_state_path)?;
+
+    client_state
+        .verify_membership(
+            conn_end.counterparty().prefix(),
+            proof,
+            consensus_state.root(),
+            path,
+            value,
+        )
+        .map_err(ChannelError::VerifyChannelFailed)?;
+
+    Ok(())
+}
+
+/// Returns the timestamp of the counterparty of the given channel end at
+/// `height`, as recorded by the client of the channel's connection.
+pub(crate) fn counterparty_timestamp<Ctx>(
+    ctx: &Ctx,
+    chan_end: &ChannelEnd,
+    height: Height,
+) -> Result<Timestamp, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = ctx.connection_end(&chan_end.connection_hops()[0])?;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        conn_end.client_id().clone(),
+        height.revision_number(),
+        height.revision_height(),
+    );
+    let consensus_state = ctx
+        .get_client_validation_context()
+        .consensus_state(&client_cons_state_path)?;
+
+    Ok(consensus_state.timestamp())
+}
+
+/// Returns the timeout of an upgrade the host agrees to now, by which the
+/// counterparty has to complete the upgrade.
+pub(crate) fn upgrade_timeout<Ctx>(ctx: &Ctx) -> Result<UpgradeTimeout, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let timestamp = (ctx.host_timestamp()? + ctx.upgrade_timeout_period())
+        .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+
+    Ok(UpgradeTimeout {
+        height: TimeoutHeight::Never,
+        timestamp,
+    })
+}
+
+/// Checks that the upgrade the counterparty agreed to has not timed out on
+/// the host.
+pub(crate) fn verify_upgrade_not_timed_out<Ctx>(
+    ctx: &Ctx,
+    counterparty_upgrade: &Upgrade,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let timeout = &counterparty_upgrade.timeout;
+
+    if timeout.has_expired(ctx.host_height()?, &ctx.host_timestamp()?) {
+        return Err(ChannelError::UpgradeTimedOut {
+            timeout_height: timeout.height,
+            timeout_timestamp: timeout.timestamp,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Aborts the upgrade in progress on the given channel end.
+///
+/// The channel end is restored to `Open` with its current parameters, the
+/// upgrades are deleted and an error receipt is written so that the
+/// counterparty can cancel the upgrade on its end as well, with `message`
+/// as the reason of the abort.
+pub(crate) fn abort_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    mut chan_end: ChannelEnd,
+    message: String,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    chan_end.set_state(State::Open);
+    ctx.store_channel(&ChannelEndPath::new(port_id, chan_id), chan_end.clone())?;
+
+    ctx.delete_channel_upgrade(&ChannelUpgradePath::new(port_id, chan_id))?;
+    ctx.delete_counterparty_channel_upgrade(&ChannelCounterpartyUpgradePath::new(
+        port_id, chan_id,
+    ))?;
+
+    ctx.log_message(format!(
+        "channel upgrade aborted on channel identifier {chan_id} with upgrade sequence {}: {message}",
+        chan_end.upgrade_sequence()
+    ))?;
+
+    let error_receipt = ErrorReceipt::new(chan_end.upgrade_sequence(), message);
+    ctx.store_channel_upgrade_error(
+        &ChannelUpgradeErrorPath::new(port_id, chan_id),
+        error_receipt,
+    )?;
+
+    Ok(())
+}
+
+/// Moves a channel end being flushed to `FlushComplete` once its last
+/// in-flight packet was acknowledged or timed out, or aborts the upgrade if
+/// the counterparty upgrade timed out in the meantime.
+///
+/// Does nothing until the counterparty upgrade is known, i.e. before the
+/// upgrade was acknowledged or confirmed on the given channel end.
+pub(crate) fn update_flush_state<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    mut chan_end: ChannelEnd,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    if chan_end.state != State::Flushing {
+        return Ok(());
+    }
+
+    let counterparty_upgrade_path = ChannelCounterpartyUpgradePath::new(port_id, chan_id);
+    let Some(counterparty_upgrade) =
+        ctx.counterparty_channel_upgrade(&counterparty_upgrade_path)?
+    else {
+        return Ok(());
+    };
+
+    if let Err(e) = verify_upgrade_not_timed_out(ctx, &counterparty_upgrade) {
+        return abort_upgrade(ctx, port_id, chan_id, chan_end, e.to_string());
+    }
+
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    if !ctx.has_inflight_packets(&chan_end_path)? {
+        chan_end.set_state(State::FlushComplete);
+        ctx.store_channel(&chan_end_path, chan_end)?;
+    }
+
+    Ok(())
+}
+
+/// Completes the upgrade of the given channel end once both ends flushed
+/// their in-flight packets: the upgraded parameters are applied and the
+/// channel end is reopened.
+pub(crate) fn open_upgraded_channel<Ctx>(
+    ctx: &mut Ctx,
+    module: &mut dyn Module,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    mut chan_end: ChannelEnd,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let upgrade = get_upgrade(ctx, port_id, chan_id)?;
+
+    let counterparty_upgrade_path = ChannelCounterpartyUpgradePath::new(port_id, chan_id);
+    let counterparty_upgrade = ctx
+        .counterparty_channel_upgrade(&counterparty_upgrade_path)?
+        .ok_or(ChannelError::MissingUpgrade)?;
+
+    // All packets sent before the upgrade have been flushed, so the packet
+    // sequences resume from where both ends stood when agreeing to it.
+    let seq_recv_path = SeqRecvPath::new(port_id, chan_id);
+    match (chan_end.ordering, upgrade.fields.ordering) {
+        (_, Order::Ordered) => {
+            ctx.store_next_sequence_recv(&seq_recv_path, counterparty_upgrade.next_sequence_send)?;
+            ctx.store_next_sequence_ack(
+                &SeqAckPath::new(port_id, chan_id),
+                upgrade.next_sequence_send,
+            )?;
+        }
+        (Order::Ordered, _) => {
+            let next_seq_recv = ctx.get_next_sequence_recv(&seq_recv_path)?;
+
+            if counterparty_upgrade.next_sequence_send > next_seq_recv {
+                ctx.store_next_sequence_recv(
+                    &seq_recv_path,
+                    counterparty_upgrade.next_sequence_send,
+                )?;
+            }
+        }
+        _ => {}
+    }
+
+    chan_end.ordering = upgrade.fields.ordering;
+    chan_end.connection_hops = upgrade.fields.connection_hops;
+    chan_end.version = upgrade.fields.version;
+    chan_end.set_state(State::Open);
+    ctx.store_channel(&ChannelEndPath::new(port_id, chan_id), chan_end.clone())?;
+
+    ctx.delete_channel_upgrade(&ChannelUpgradePath::new(port_id, chan_id))?;
+    ctx.delete_counterparty_channel_upgrade(&counterparty_upgrade_path)?;
+
+    let extras = module.on_chan_upgrade_open_execute(
+        port_id,
+        chan_id,
+        chan_end.ordering,
+        &chan_end.connection_hops,
+        &chan_end.version,
+    );
+
+    ctx.log_message(format!(
+        "success: channel upgrade open with channel identifier {chan_id} and upgrade sequence {}",
+        chan_end.upgrade_sequence()
+    ))?;
+
+    let chan_id_on_counterparty = chan_end
+        .counterparty()
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?
+        .clone();
+
+    let core_event = IbcEvent::UpgradeOpenChannel(UpgradeOpen::new(
+        port_id.clone(),
+        chan_id.clone(),
+        chan_end.counterparty().port_id().clone(),
+        chan_id_on_counterparty,
+        chan_end.upgrade_sequence(),
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(core_event)?;
+
+    for module_event in extras.events {
+        ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx.log_message(log_message)?;
+    }
+
+    Ok(())
+}
@@ -6,6 +6,9 @@ use derive_more::Into;
 use ibc_primitives::prelude::*;
 
 use super::error::PacketError;
+use crate::commitment::{
+    compute_ack_commitment_with, AcknowledgementCommitment, CommitmentHasher, Sha256Hasher,
+};
 
 /// A generic Acknowledgement type that modules may interpret as they like.
 ///
@@ -32,6 +35,18 @@ impl Acknowledgement {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_slice()
     }
+
+    /// Returns the SHA-256 commitment of this acknowledgement, as stored by
+    /// the receiving chain under its [`AckPath`](ibc_core_host_types::path::AckPath).
+    pub fn commitment(&self) -> AcknowledgementCommitment {
+        self.commitment_with(&Sha256Hasher)
+    }
+
+    /// Returns the commitment of this acknowledgement computed with the given
+    /// hasher.
+    pub fn commitment_with(&self, hasher: &dyn CommitmentHasher) -> AcknowledgementCommitment {
+        compute_ack_commitment_with(hasher, self)
+    }
 }
 
 impl AsRef<[u8]> for Acknowledgement {
@@ -53,10 +53,6 @@ impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
     type Error = ChannelError;
 
     fn try_from(value: RawIdentifiedChannel) -> Result<Self, Self::Error> {
-        if value.upgrade_sequence != 0 {
-            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
-        }
-
         let raw_channel_end = RawChannel {
             state: value.state,
             ordering: value.ordering,
@@ -89,7 +85,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
             version: value.channel_end.version.to_string(),
             port_id: value.port_id.to_string(),
             channel_id: value.channel_id.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.channel_end.upgrade_sequence,
         }
     }
 }
@@ -116,6 +112,9 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The sequence of the latest upgrade attempted on this channel end, or
+    /// `0` if it has never been upgraded.
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
@@ -153,7 +152,11 @@ impl TryFrom<RawChannel> for ChannelEnd {
 
         let version = value.version.into();
 
-        ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
+        let mut channel_end =
+            ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)?;
+        channel_end.set_upgrade_sequence(value.upgrade_sequence);
+
+        Ok(channel_end)
     }
 }
 
@@ -169,7 +172,7 @@ impl From<ChannelEnd> for RawChannel {
                 .map(|v| v.as_str().to_string())
                 .collect(),
             version: value.version.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.upgrade_sequence,
         }
     }
 }
@@ -193,6 +196,7 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
         }
     }
 
@@ -223,6 +227,10 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    pub fn set_upgrade_sequence(&mut self, upgrade_sequence: u64) {
+        self.upgrade_sequence = upgrade_sequence;
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state == State::Open
@@ -248,6 +256,10 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -525,6 +537,8 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    Flushing = 5isize,
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -536,6 +550,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -547,8 +563,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "Must be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -1,5 +1,7 @@
 //! Types and utilities related to packet commitments.
 
+use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 
@@ -78,7 +80,58 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
     }
 }
 
-/// Compute the commitment for a packet.
+/// Hash function used to compute packet and acknowledgement commitments.
+///
+/// Both ends of a channel must use the same hasher, and it must match the one
+/// the counterparty light client expects when verifying commitment proofs.
+pub trait CommitmentHasher: Debug {
+    fn hash(&self, data: &[u8]) -> Vec<u8>;
+
+    /// Returns the hash algorithm implemented by this hasher, as reported to
+    /// relayers and counterparties.
+    fn algorithm(&self) -> HashAlgorithm;
+}
+
+/// Identifies the hash algorithm a host uses to compute packet and
+/// acknowledgement commitments, so that relayers and counterparties can
+/// verify them.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// SHA-256, as used by ibc-go.
+    #[default]
+    Sha256,
+    /// Any other algorithm, identified by name.
+    Other(String),
+}
+
+impl Display for HashAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Sha256 => write!(f, "sha256"),
+            Self::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// The SHA-256 [`CommitmentHasher`], as used by ibc-go.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha256Hasher;
+
+impl CommitmentHasher for Sha256Hasher {
+    fn hash(&self, data: &[u8]) -> Vec<u8> {
+        use sha2::Digest;
+
+        sha2::Sha256::digest(data).to_vec()
+    }
+
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Sha256
+    }
+}
+
+/// Compute the commitment for a packet using SHA-256.
 ///
 /// Note that the absence of `timeout_height` is treated as
 /// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
@@ -89,34 +142,54 @@ pub fn compute_packet_commitment(
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
 ) -> PacketCommitment {
-    let mut hash_input = [0; 8 * 3 + 32];
+    compute_packet_commitment_with(
+        &Sha256Hasher,
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    )
+}
+
+/// Compute the commitment for a packet using the given hasher.
+///
+/// See [`compute_packet_commitment`] for the layout of the hashed input.
+pub fn compute_packet_commitment_with(
+    hasher: &dyn CommitmentHasher,
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    let data_hash = hasher.hash(packet_data);
+
+    let mut hash_input = Vec::with_capacity(8 * 3 + data_hash.len());
 
-    hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
-    hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
-    hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
+    hash_input.extend_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
+    hash_input.extend_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
+    hash_input.extend_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
+    hash_input.extend_from_slice(&data_hash);
 
-    hash(&hash_input).to_vec().into()
+    hasher.hash(&hash_input).into()
 }
 
-/// Compute the commitment for an acknowledgement.
+/// Compute the commitment for an acknowledgement using SHA-256.
 pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    compute_ack_commitment_with(&Sha256Hasher, ack)
 }
 
-/// Helper function to hash a byte slice using SHA256.
-///
-/// Note that computing commitments with anything other than SHA256 will
-/// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
-
-    sha2::Sha256::digest(data).into()
+/// Compute the commitment for an acknowledgement using the given hasher.
+pub fn compute_ack_commitment_with(
+    hasher: &dyn CommitmentHasher,
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    hasher.hash(ack.as_ref()).into()
 }
 
 #[cfg(test)]
 mod test {
+    use ibc_core_host_types::identifiers::{ChannelId, PortId};
+
     use super::*;
+    use crate::packet::Packet;
 
     #[test]
     fn test_compute_packet_commitment() {
@@ -131,6 +204,21 @@ mod test {
             &Timestamp::from_nanoseconds(0x42).unwrap(),
         );
         assert_eq!(&expected[..], actual.as_ref());
+
+        // only the data and timeouts of a packet are committed to
+        let packet = Packet {
+            seq_on_a: 7.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"packet data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::At(
+                ibc_core_client_types::Height::new(42, 24).unwrap(),
+            ),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0x42).unwrap(),
+        };
+        assert_eq!(&expected[..], packet.commitment().as_ref());
     }
 
     #[test]
@@ -142,6 +230,42 @@ mod test {
         ];
         let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
         let actual = compute_ack_commitment(&ack);
-        assert_eq!(&expected[..], actual.as_ref())
+        assert_eq!(&expected[..], actual.as_ref());
+        assert_eq!(&expected[..], ack.commitment().as_ref());
+    }
+
+    /// Returns its input unchanged, so that commitments are predictable.
+    #[derive(Debug)]
+    struct IdentityHasher;
+
+    impl CommitmentHasher for IdentityHasher {
+        fn hash(&self, data: &[u8]) -> Vec<u8> {
+            data.to_vec()
+        }
+
+        fn algorithm(&self) -> HashAlgorithm {
+            HashAlgorithm::Other("identity".to_string())
+        }
+    }
+
+    #[test]
+    fn test_compute_commitments_with_custom_hasher() {
+        let actual = compute_packet_commitment_with(
+            &IdentityHasher,
+            b"data",
+            &TimeoutHeight::At(ibc_core_client_types::Height::new(1, 2).unwrap()),
+            &Timestamp::from_nanoseconds(3).unwrap(),
+        );
+
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&3u64.to_be_bytes());
+        expected.extend_from_slice(&1u64.to_be_bytes());
+        expected.extend_from_slice(&2u64.to_be_bytes());
+        expected.extend_from_slice(b"data");
+        assert_eq!(actual.into_vec(), expected);
+
+        let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
+        let actual = compute_ack_commitment_with(&IdentityHasher, &ack);
+        assert_eq!(actual.into_vec(), vec![0, 1, 2, 3]);
     }
 }
@@ -2,13 +2,13 @@
 
 use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
-use ibc_core_connection_types::error as connection_error;
+use ibc_core_connection_types::{error as connection_error, State as ConnectionState};
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{ParseTimestampError, Timestamp};
+use ibc_primitives::{ParseTimestampError, Signer, Timestamp};
 
-use super::channel::Counterparty;
+use super::channel::{Counterparty, Order};
 use super::timeout::TimeoutHeight;
 use crate::channel::State;
 use crate::Version;
@@ -23,18 +23,95 @@ pub enum ChannelError {
     InvalidState { expected: String, actual: String },
     /// invalid channel order type: expected `{expected}`, actual `{actual}`
     InvalidOrderType { expected: String, actual: String },
+    /// channel order `{order}` is not among the orderings negotiated for connection `{connection_id}`
+    OrderMismatch {
+        order: Order,
+        connection_id: ConnectionId,
+    },
     /// invalid connection hops length: expected `{expected}`; actual `{actual}`
     InvalidConnectionHopsLength { expected: u64, actual: u64 },
+    /// connection `{connection_id}` is not open: actual state `{state}`
+    ConnectionNotOpen {
+        connection_id: ConnectionId,
+        state: ConnectionState,
+    },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// invalid proof: missing height
     MissingHeight,
+    /// no consensus state found for client `{client_id}` at proof height `{proof_height}`; it may have been pruned
+    MissingConsensusStateAtProofHeight {
+        client_id: ClientId,
+        proof_height: Height,
+    },
     /// packet data bytes must be valid UTF-8 (this restriction will be lifted in the future)
     NonUtf8PacketData,
     /// missing counterparty
     MissingCounterparty,
     /// unsupported channel upgrade sequence
     UnsupportedChannelUpgradeSequence,
+    /// missing upgrade fields
+    MissingUpgradeFields,
+    /// missing upgrade
+    MissingUpgrade,
+    /// invalid upgrade timeout
+    InvalidUpgradeTimeout,
+    /// the module bound to port `{port_id}` does not support channel upgrades
+    UpgradeNotSupported { port_id: PortId },
+    /// the proposed upgrade fields are identical to those of the channel end
+    UpgradeFieldsUnchanged,
+    /// counterparty upgrade sequence `{counterparty}` is not greater than the local upgrade sequence `{local}`
+    InvalidUpgradeSequence { local: u64, counterparty: u64 },
+    /// counterparty upgrade connection hop `{actual}` doesn't match the counterparty `{expected}` of the proposed connection
+    IncompatibleCounterpartyUpgrade {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// no upgrade found for the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// an upgrade is already in progress on the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeInProgress {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// the host does not support channel upgrades
+    UpgradesNotSupportedByHost,
+    /// the host does not support pausing channels
+    FlowControlNotSupportedByHost,
+    /// the host does not support pruning packet acknowledgements
+    AckPruningNotSupportedByHost,
+    /// signer `{signer}` is not authorized to initiate or cancel channel upgrades
+    UnauthorizedUpgradeSigner { signer: Signer },
+    /// the counterparty upgrade is incompatible with the proposed upgrade: `{description}`
+    IncompatibleUpgrade { description: String },
+    /// counterparty upgrade sequence `{counterparty}` is lower than the local upgrade sequence `{local}`
+    StaleCounterpartyUpgradeSequence { local: u64, counterparty: u64 },
+    /// the upgrade timed out at height `{timeout_height}` or timestamp `{timeout_timestamp}`
+    UpgradeTimedOut {
+        timeout_height: TimeoutHeight,
+        timeout_timestamp: Timestamp,
+    },
+    /// the upgrade timeout has not been reached on the counterparty at height `{chain_height}` and timestamp `{chain_timestamp}`
+    UpgradeTimeoutNotReached {
+        chain_height: Height,
+        chain_timestamp: Timestamp,
+    },
+    /// the counterparty has already completed the upgrade
+    CounterpartyUpgradeCompleted,
+    /// missing error receipt
+    MissingErrorReceipt,
+    /// error receipt sequence `{receipt}` cannot cancel the upgrade with sequence `{local}`
+    InvalidErrorReceiptSequence { local: u64, receipt: u64 },
+    /// packet flow on the channel end (`{port_id}`, `{channel_id}`) is paused
+    ChannelPaused {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// unexpected packet sequence on ordered channel: expected `{expected}`, got `{got}`; packets must be relayed in order
+    UnexpectedPacketSequence { expected: Sequence, got: Sequence },
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
     /// missing channel end
@@ -114,6 +191,11 @@ pub enum PacketError {
         timeout_timestamp: Timestamp,
         chain_timestamp: Timestamp,
     },
+    /// Packet timeout timestamp `{timeout_timestamp}` has not been reached by the counterparty timestamp `{chain_timestamp}` at the proof height
+    PacketTimeoutTimestampNotReached {
+        timeout_timestamp: Timestamp,
+        chain_timestamp: Timestamp,
+    },
     /// Packet acknowledgement exists for the packet with the sequence `{sequence}`
     AcknowledgementExists { sequence: Sequence },
     /// Acknowledgment cannot be empty
@@ -122,6 +204,8 @@ pub enum PacketError {
     EmptyAcknowledgementStatus,
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
+    /// missing proof that the acknowledgement of the packet `{sequence}` was relayed
+    MissingRelayProof { sequence: Sequence },
     /// invalid proof: missing height
     MissingHeight,
     /// there is no packet in this message
@@ -10,7 +10,7 @@ use tendermint::abci;
 
 use self::channel_attributes::{
     ChannelIdAttribute, ConnectionIdAttribute, CounterpartyChannelIdAttribute,
-    CounterpartyPortIdAttribute, PortIdAttribute, VersionAttribute,
+    CounterpartyPortIdAttribute, PortIdAttribute, UpgradeSequenceAttribute, VersionAttribute,
     COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
 use self::packet_attributes::{
@@ -34,6 +34,13 @@ const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
 const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
 const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+const CHANNEL_UPGRADE_CANCEL_EVENT: &str = "channel_upgrade_cancelled";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
 
 /// Packet event types
 const SEND_PACKET_EVENT: &str = "send_packet";
@@ -494,6 +501,524 @@ impl From<CloseConfirm> for abci::Event {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeInit {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_a: UpgradeSequenceAttribute,
+}
+
+impl UpgradeInit {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence_on_a: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr_on_a: upgrade_sequence_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_a(&self) -> u64 {
+        self.upgrade_sequence_attr_on_a.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_INIT_EVENT
+    }
+}
+
+impl From<UpgradeInit> for abci::Event {
+    fn from(o: UpgradeInit) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_INIT_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTry {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_b: UpgradeSequenceAttribute,
+}
+
+impl UpgradeTry {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_sequence_on_b: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_sequence_attr_on_b: upgrade_sequence_on_b.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_b(&self) -> u64 {
+        self.upgrade_sequence_attr_on_b.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TRY_EVENT
+    }
+}
+
+impl From<UpgradeTry> for abci::Event {
+    fn from(o: UpgradeTry) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TRY_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.upgrade_sequence_attr_on_b.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeAck {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_a: UpgradeSequenceAttribute,
+}
+
+impl UpgradeAck {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence_on_a: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr_on_a: upgrade_sequence_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_a(&self) -> u64 {
+        self.upgrade_sequence_attr_on_a.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ACK_EVENT
+    }
+}
+
+impl From<UpgradeAck> for abci::Event {
+    fn from(o: UpgradeAck) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ACK_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_b: UpgradeSequenceAttribute,
+}
+
+impl UpgradeConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_sequence_on_b: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_sequence_attr_on_b: upgrade_sequence_on_b.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_b(&self) -> u64 {
+        self.upgrade_sequence_attr_on_b.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CONFIRM_EVENT
+    }
+}
+
+impl From<UpgradeConfirm> for abci::Event {
+    fn from(o: UpgradeConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.upgrade_sequence_attr_on_b.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeOpen {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_a: UpgradeSequenceAttribute,
+}
+
+impl UpgradeOpen {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence_on_a: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr_on_a: upgrade_sequence_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_a(&self) -> u64 {
+        self.upgrade_sequence_attr_on_a.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_OPEN_EVENT
+    }
+}
+
+impl From<UpgradeOpen> for abci::Event {
+    fn from(o: UpgradeOpen) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_OPEN_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeCancel {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_a: UpgradeSequenceAttribute,
+}
+
+impl UpgradeCancel {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence_on_a: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr_on_a: upgrade_sequence_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_a(&self) -> u64 {
+        self.upgrade_sequence_attr_on_a.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CANCEL_EVENT
+    }
+}
+
+impl From<UpgradeCancel> for abci::Event {
+    fn from(o: UpgradeCancel) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CANCEL_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimedOut {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr_on_a: UpgradeSequenceAttribute,
+}
+
+impl UpgradeTimedOut {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence_on_a: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr_on_a: upgrade_sequence_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence_on_a(&self) -> u64 {
+        self.upgrade_sequence_attr_on_a.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
+    }
+}
+
+impl From<UpgradeTimedOut> for abci::Event {
+    fn from(o: UpgradeTimedOut) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.upgrade_sequence_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
 /// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
 /// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
 /// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
@@ -1264,4 +1789,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn ibc_to_abci_write_acknowledgement_event() {
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        let acknowledgement = Acknowledgement::try_from(b"ok".to_vec()).unwrap();
+
+        let event: AbciEvent =
+            WriteAcknowledgement::new(packet, acknowledgement, ConnectionId::zero())
+                .try_into()
+                .unwrap();
+
+        // Same attribute keys as ibc-go's `write_acknowledgement` event, so
+        // that relayers can rebuild the acknowledgement from the event alone.
+        let expected = [
+            ("packet_data", "data"),
+            ("packet_data_hex", "64617461"),
+            ("packet_timeout_height", "0-0"),
+            ("packet_timeout_timestamp", "0"),
+            ("packet_sequence", "1"),
+            ("packet_src_port", "transfer"),
+            ("packet_src_channel", "channel-0"),
+            ("packet_dst_port", "transfer"),
+            ("packet_dst_channel", "channel-1"),
+            ("packet_ack", "ok"),
+            ("packet_ack_hex", "6f6b"),
+            ("packet_connection", "connection-0"),
+        ];
+
+        assert_eq!(event.kind, WRITE_ACK_EVENT);
+        assert_eq!(event.attributes.len(), expected.len());
+        for (attribute, (key, value)) in event.attributes.iter().zip(expected) {
+            assert_eq!(attribute.key_str().unwrap(), key);
+            assert_eq!(attribute.value_str().unwrap(), value);
+        }
+    }
 }
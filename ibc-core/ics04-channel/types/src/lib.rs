@@ -23,6 +23,7 @@ pub mod events;
 pub mod msgs;
 pub mod packet;
 pub mod timeout;
+pub mod upgrade;
 
 pub mod acknowledgement;
 pub mod commitment;
@@ -0,0 +1,81 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel;
+use ibc_proto::Protobuf;
+
+use crate::error::ChannelError;
+use crate::upgrade::ErrorReceipt;
+
+pub const CHAN_UPGRADE_CANCEL_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeCancel";
+
+///
+/// Message definition for cancelling a channel upgrade (`ChanUpgradeCancel` datagram).
+/// Per our convention, this message is sent to chain A.
+///
+/// The error receipt written by chain B, its proof and the proof height are
+/// only optional when the upgrade authority of chain A cancels the upgrade.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeCancel {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub error_receipt_on_b: Option<ErrorReceipt>,
+    pub proof_error_receipt_on_b: Option<CommitmentProofBytes>,
+    pub proof_height_on_b: Option<Height>,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {}
+
+impl TryFrom<RawMsgChannelUpgradeCancel> for MsgChannelUpgradeCancel {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeCancel) -> Result<Self, Self::Error> {
+        let proof_error_receipt_on_b = if raw_msg.proof_error_receipt.is_empty() {
+            None
+        } else {
+            Some(
+                raw_msg
+                    .proof_error_receipt
+                    .try_into()
+                    .map_err(|_| ChannelError::InvalidProof)?,
+            )
+        };
+
+        Ok(MsgChannelUpgradeCancel {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            error_receipt_on_b: raw_msg.error_receipt.map(TryInto::try_into).transpose()?,
+            proof_error_receipt_on_b,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeCancel> for RawMsgChannelUpgradeCancel {
+    fn from(domain_msg: MsgChannelUpgradeCancel) -> Self {
+        RawMsgChannelUpgradeCancel {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            error_receipt: domain_msg.error_receipt_on_b.map(Into::into),
+            proof_error_receipt: domain_msg
+                .proof_error_receipt_on_b
+                .map(Into::into)
+                .unwrap_or_default(),
+            proof_height: domain_msg.proof_height_on_b.map(Into::into),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
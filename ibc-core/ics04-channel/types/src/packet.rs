@@ -5,8 +5,12 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry::Expired;
 use ibc_primitives::Timestamp;
 use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
+use ibc_proto::Protobuf;
 
 use super::timeout::TimeoutHeight;
+use crate::commitment::{
+    compute_packet_commitment_with, CommitmentHasher, PacketCommitment, Sha256Hasher,
+};
 use crate::error::PacketError;
 
 /// Enumeration of proof carrying ICS4 message, helper for relayer.
@@ -76,7 +80,10 @@ pub struct Packet {
     pub chan_id_on_b: ChannelId,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+        serde(
+            serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper",
+            deserialize_with = "ibc_core_commitment_types::serializer::de_hex_upper"
+        )
     )]
     pub data: Vec<u8>,
     pub timeout_height_on_b: TimeoutHeight,
@@ -145,6 +152,24 @@ impl Packet {
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Returns the SHA-256 commitment of this packet, as stored by the sending
+    /// chain under its [`CommitmentPath`](ibc_core_host_types::path::CommitmentPath).
+    ///
+    /// See [`compute_packet_commitment`](crate::commitment::compute_packet_commitment) for the layout of the hashed input.
+    pub fn commitment(&self) -> PacketCommitment {
+        self.commitment_with(&Sha256Hasher)
+    }
+
+    /// Returns the commitment of this packet computed with the given hasher.
+    pub fn commitment_with(&self, hasher: &dyn CommitmentHasher) -> PacketCommitment {
+        compute_packet_commitment_with(
+            hasher,
+            &self.data,
+            &self.timeout_height_on_b,
+            &self.timeout_timestamp_on_b,
+        )
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -164,6 +189,8 @@ impl core::fmt::Display for Packet {
     }
 }
 
+impl Protobuf<RawPacket> for Packet {}
+
 impl TryFrom<RawPacket> for Packet {
     type Error = PacketError;
 
@@ -249,7 +276,10 @@ pub struct PacketState {
     pub seq: Sequence,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+        serde(
+            serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper",
+            deserialize_with = "ibc_core_commitment_types::serializer::de_hex_upper"
+        )
     )]
     pub data: Vec<u8>,
 }
@@ -278,6 +308,8 @@ impl core::fmt::Display for PacketState {
     }
 }
 
+impl Protobuf<RawPacketState> for PacketState {}
+
 impl TryFrom<RawPacketState> for PacketState {
     type Error = PacketError;
 
@@ -309,3 +341,43 @@ impl From<PacketState> for RawPacketState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::ToProto;
+
+    use super::*;
+
+    fn dummy_packet(seq: u64) -> Packet {
+        Packet {
+            seq_on_a: seq.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"packet data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::At(Height::new(42, 24).unwrap()),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0x42).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_encode_packets_into_one_buffer() {
+        let packets = [dummy_packet(1), dummy_packet(2)];
+        let encoded_len: usize = packets.iter().map(|p| p.clone().encoded_len()).sum();
+
+        let mut buf = Vec::new();
+        for packet in packets.clone() {
+            packet.encode_to_buf(&mut buf);
+        }
+
+        assert_eq!(buf.len(), encoded_len);
+        assert_eq!(
+            buf,
+            packets
+                .into_iter()
+                .flat_map(Protobuf::<RawPacket>::encode_vec)
+                .collect::<Vec<_>>()
+        );
+    }
+}
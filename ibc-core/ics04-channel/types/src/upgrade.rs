@@ -0,0 +1,306 @@
+//! Defines the types used during the channel upgrade handshake, as described
+//! in ICS-04.
+
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Expiry, Timestamp};
+use ibc_proto::ibc::core::channel::v1::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, Order};
+use crate::error::ChannelError;
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The parameters of a channel end that can be renegotiated through an
+/// upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Upgrade ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        verify_connection_hops_length(&self.connection_hops, 1)?;
+
+        if self.version.is_empty() {
+            return Err(ChannelError::VersionNotSupported {
+                expected: Version::empty(),
+                actual: self.version.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let connection_hops = value
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fields = UpgradeFields::new(
+            Order::from_i32(value.ordering)?,
+            connection_hops,
+            value.version.into(),
+        );
+        fields.validate_basic()?;
+
+        Ok(fields)
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The deadline on the counterparty chain by which an upgrade must complete.
+///
+/// An unset timeout is represented by [`TimeoutHeight::Never`] together with
+/// an unset [`Timestamp`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    /// Returns an unset timeout.
+    pub fn none() -> Self {
+        Self {
+            height: TimeoutHeight::Never,
+            timestamp: Timestamp::none(),
+        }
+    }
+
+    /// Returns `true` if either the height or the timestamp is set.
+    pub fn is_set(&self) -> bool {
+        self.height.is_set() || self.timestamp.is_set()
+    }
+
+    /// Returns `true` if the timeout has passed on a chain at the given
+    /// height and timestamp.
+    pub fn has_expired(&self, chain_height: Height, chain_timestamp: &Timestamp) -> bool {
+        let height_expired = self.height.has_expired(chain_height);
+
+        let timestamp_expired = self.timestamp.is_set()
+            && chain_timestamp.check_expiry(&self.timestamp) == Expiry::Expired;
+
+        height_expired || timestamp_expired
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(value: RawTimeout) -> Result<Self, Self::Error> {
+        let height = value
+            .height
+            .try_into()
+            .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+
+        Ok(UpgradeTimeout { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        RawTimeout {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade proposed for a channel end, stored under the
+/// [`ChannelUpgradePath`](ibc_core_host_types::path::ChannelUpgradePath) of
+/// that channel end for the duration of the upgrade handshake.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Upgrade {
+    pub fn new(
+        fields: UpgradeFields,
+        timeout: UpgradeTimeout,
+        next_sequence_send: Sequence,
+    ) -> Self {
+        Self {
+            fields,
+            timeout,
+            next_sequence_send,
+        }
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .ok_or(ChannelError::MissingUpgradeFields)?
+            .try_into()?;
+
+        let timeout = match value.timeout {
+            Some(raw_timeout) => raw_timeout.try_into()?,
+            None => UpgradeTimeout::none(),
+        };
+
+        Ok(Upgrade::new(
+            fields,
+            timeout,
+            Sequence::from(value.next_sequence_send),
+        ))
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.value(),
+        }
+    }
+}
+
+/// The record of an aborted upgrade, written by a channel end so that its
+/// counterparty can cancel the upgrade as well.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    /// The upgrade sequence of the aborted upgrade.
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(sequence: u64, message: String) -> Self {
+        Self { sequence, message }
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = ChannelError;
+
+    fn try_from(value: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(ErrorReceipt::new(value.sequence, value.message))
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: value.sequence,
+            message: value.message,
+        }
+    }
+}
@@ -18,7 +18,10 @@ use crate::error::CommitmentError;
 pub struct CommitmentRoot {
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "crate::serializer::ser_hex_upper")
+        serde(
+            serialize_with = "crate::serializer::ser_hex_upper",
+            deserialize_with = "crate::serializer::de_hex_upper"
+        )
     )]
     bytes: Vec<u8>,
 }
@@ -75,7 +78,10 @@ impl From<Vec<u8>> for CommitmentRoot {
 pub struct CommitmentProofBytes {
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "crate::serializer::ser_hex_upper")
+        serde(
+            serialize_with = "crate::serializer::ser_hex_upper",
+            deserialize_with = "crate::serializer::de_hex_upper"
+        )
     )]
     bytes: Vec<u8>,
 }
@@ -1,4 +1,5 @@
 use ibc_primitives::prelude::*;
+use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 use subtle_encoding::{Encoding, Hex};
 
@@ -12,3 +13,15 @@ where
         .map_err(|e| serde::ser::Error::custom(format!("failed to serialize hex: {}", e)))?;
     hex.serialize(serializer)
 }
+
+/// Counterpart of [`ser_hex_upper`], so that the serialized bytes can be read
+/// back. Lowercase hex is accepted too.
+pub fn de_hex_upper<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?;
+    Hex::upper_case()
+        .decode(hex.to_uppercase())
+        .map_err(|e| serde::de::Error::custom(format!("failed to deserialize hex: {}", e)))
+}
@@ -1,18 +1,25 @@
 use core::time::Duration;
 
 use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::commitment::{
+    AcknowledgementCommitment, CommitmentHasher, PacketCommitment, Sha256Hasher,
+};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
 use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_handler_types::msgs::MsgSummary;
+use ibc_core_host_types::identifiers::{ChainId, ClientId, ConnectionId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
+    AckPath, ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradeErrorPath,
+    ChannelUpgradePath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc_primitives::prelude::*;
@@ -40,10 +47,17 @@ pub trait ValidationContext {
     fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
 
     /// Returns the `ConsensusState` of the host (local) chain at a specific height.
+    ///
+    /// The connection handshake uses it to check that the counterparty stored
+    /// a correct consensus state of the host. Hosts that don't keep their
+    /// historical consensus states can rely on the default implementation,
+    /// which fails with [`ClientError::MissingLocalConsensusState`].
     fn host_consensus_state(
         &self,
         height: &Height,
-    ) -> Result<Self::HostConsensusState, ContextError>;
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        Err(ClientError::MissingLocalConsensusState { height: *height }.into())
+    }
 
     /// Returns a natural number, counting how many clients have been created
     /// thus far. The value of this counter should increase only via method
@@ -95,6 +109,71 @@ pub trait ValidationContext {
     /// Returns the `ChannelEnd` for the given `port_id` and `chan_id`.
     fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
 
+    /// Returns the upgrade proposed for the channel end at the given store
+    /// path, or `None` if no upgrade is in progress.
+    ///
+    /// Channel upgrades are opt-in for hosts. The default implementation,
+    /// meant for hosts that don't store upgrades, returns `None`; see
+    /// [`ExecutionContext::store_channel_upgrade`].
+    fn channel_upgrade(
+        &self,
+        _channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the upgrade the counterparty agreed to for the channel end at
+    /// the given store path, or `None` if there is none. Defaults to `None`.
+    fn counterparty_channel_upgrade(
+        &self,
+        _counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns whether packets sent on the channel end at the given store path
+    /// are still awaiting an acknowledgement or a timeout, i.e. whether the
+    /// channel end still stores packet commitments.
+    ///
+    /// Only called for channels being upgraded, which can't complete the
+    /// upgrade before all in-flight packets are flushed. The default
+    /// implementation, meant for hosts that don't support channel upgrades,
+    /// fails with [`ChannelError::UpgradesNotSupportedByHost`].
+    fn has_inflight_packets(
+        &self,
+        _channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Err(ChannelError::UpgradesNotSupportedByHost.into())
+    }
+
+    /// Validates that `signer` may initiate channel upgrades, and cancel them
+    /// without the proof of a counterparty error receipt. This is typically
+    /// the governance authority of the host.
+    ///
+    /// Defaults to rejecting every signer.
+    fn validate_upgrade_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        Err(ChannelError::UnauthorizedUpgradeSigner {
+            signer: signer.clone(),
+        }
+        .into())
+    }
+
+    /// Returns how long after a channel end agrees to an upgrade the
+    /// counterparty has to complete it, measured against the host timestamp.
+    ///
+    /// Defaults to 10 minutes.
+    fn upgrade_timeout_period(&self) -> Duration {
+        Duration::from_secs(600)
+    }
+
+    /// Returns whether packet flow on the channel end at the given store path
+    /// has been paused by the host operator.
+    ///
+    /// Defaults to `false`, for hosts that do not support pausing channels.
+    fn is_channel_paused(&self, _channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+
     /// Returns the sequence number for the next packet to be sent for the given store path
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
@@ -138,6 +217,37 @@ pub trait ValidationContext {
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the hasher used to compute packet and acknowledgement
+    /// commitments. Defaults to SHA-256.
+    fn commitment_hasher(&self) -> &dyn CommitmentHasher {
+        &Sha256Hasher
+    }
+
+    /// Returns the maximum number of IBC messages a single transaction may
+    /// carry, or `None` if the number is unbounded.
+    fn max_messages_per_tx(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the number of sequences whose packet acknowledgements are kept
+    /// below the one up to which the counterparty proved it relayed them, or
+    /// `None` if the host does not prune acknowledgements.
+    ///
+    /// Pruning is opt-in: with the default, the `prune_acknowledgements`
+    /// entrypoint fails with [`ChannelError::AckPruningNotSupportedByHost`].
+    fn ack_retention_window(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the identifier of the host chain, or `None` if the host doesn't
+    /// expose it.
+    ///
+    /// Used to reject clients tracking the host chain itself, unless the
+    /// `loopback` feature of `ibc-core-client` is enabled.
+    fn host_chain_id(&self) -> Option<ChainId> {
+        None
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -153,6 +263,23 @@ pub trait ExecutionContext: ValidationContext {
     /// Increases the counter which keeps track of how many clients have been created.
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Removes the consensus states of the given client that have expired as
+    /// of `now`, i.e. that are older than the client's trusting period, along
+    /// with their update metadata. Returns the number of consensus states
+    /// removed.
+    ///
+    /// Hosts may call this at any point to keep the consensus state store from
+    /// growing indefinitely; clients may also prune on their own update path.
+    /// The default implementation, meant for hosts relying on the latter only,
+    /// removes nothing.
+    fn prune_expired_consensus_states(
+        &mut self,
+        _client_id: &ClientId,
+        _now: Timestamp,
+    ) -> Result<usize, ContextError> {
+        Ok(0)
+    }
+
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -201,6 +328,33 @@ pub trait ExecutionContext: ValidationContext {
     /// Deletes the packet acknowledgement at the given store path
     fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError>;
 
+    /// Removes the packet acknowledgements of the given channel end whose
+    /// sequence is lower than `before_sequence`. Returns the number of
+    /// acknowledgements removed.
+    ///
+    /// Called once the counterparty has proven it relayed them, see
+    /// `prune_acknowledgements_validate`. The default looks up every sequence
+    /// below `before_sequence`, so hosts whose store can iterate over or
+    /// delete a range of keys should override it.
+    fn prune_acknowledgements(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        before_sequence: Sequence,
+    ) -> Result<usize, ContextError> {
+        let mut pruned = 0;
+
+        for sequence in 1..before_sequence.value() {
+            let ack_path = AckPath::new(&channel_end_path.0, &channel_end_path.1, sequence.into());
+
+            if self.get_packet_acknowledgement(&ack_path).is_ok() {
+                self.delete_packet_acknowledgement(&ack_path)?;
+                pruned += 1;
+            }
+        }
+
+        Ok(pruned)
+    }
+
     /// Stores the given channel_end at a path associated with the port_id and channel_id.
     fn store_channel(
         &mut self,
@@ -208,6 +362,85 @@ pub trait ExecutionContext: ValidationContext {
         channel_end: ChannelEnd,
     ) -> Result<(), ContextError>;
 
+    /// Pauses or resumes packet flow on the channel end at the given store
+    /// path, e.g. as an emergency stop. Packets can be neither sent nor
+    /// received on a paused channel, which otherwise remains open.
+    ///
+    /// Hosts that support pausing channels must implement this method along
+    /// with [`is_channel_paused`](ValidationContext::is_channel_paused). The
+    /// default implementation fails with
+    /// [`ChannelError::FlowControlNotSupportedByHost`], so that a pause is
+    /// never silently dropped.
+    fn set_channel_flow_status(
+        &mut self,
+        _channel_end_path: &ChannelEndPath,
+        _paused: bool,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::FlowControlNotSupportedByHost.into())
+    }
+
+    /// Stores the upgrade proposed for a channel end at the given store path.
+    ///
+    /// Hosts opt into channel upgrades by implementing this method along with
+    /// the other upgrade store methods of both contexts. The default
+    /// implementation fails with [`ChannelError::UpgradesNotSupportedByHost`].
+    fn store_channel_upgrade(
+        &mut self,
+        _channel_upgrade_path: &ChannelUpgradePath,
+        _upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradesNotSupportedByHost.into())
+    }
+
+    /// Deletes the upgrade proposed for a channel end at the given store path.
+    ///
+    /// Defaults to a no-op, as by default no upgrade is ever stored.
+    fn delete_channel_upgrade(
+        &mut self,
+        _channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Stores the upgrade the counterparty agreed to for a channel end at the
+    /// given store path.
+    ///
+    /// The default implementation fails with
+    /// [`ChannelError::UpgradesNotSupportedByHost`].
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        _counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+        _upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradesNotSupportedByHost.into())
+    }
+
+    /// Deletes the upgrade the counterparty agreed to for a channel end at the
+    /// given store path.
+    ///
+    /// Defaults to a no-op, as by default no upgrade is ever stored.
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        _counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Stores the error receipt of the last aborted upgrade of a channel end
+    /// at the given store path, replacing the previous one. The store path
+    /// has to be provable, as the counterparty proves the receipt to cancel
+    /// the upgrade on its end.
+    ///
+    /// The default implementation fails with
+    /// [`ChannelError::UpgradesNotSupportedByHost`].
+    fn store_channel_upgrade_error(
+        &mut self,
+        _upgrade_error_path: &ChannelUpgradeErrorPath,
+        _error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UpgradesNotSupportedByHost.into())
+    }
+
     /// Stores the given `nextSequenceSend` number at the given store path
     fn store_next_sequence_send(
         &mut self,
@@ -236,6 +469,65 @@ pub trait ExecutionContext: ValidationContext {
     /// Emit the given IBC event
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
+    /// Returns the number of IBC events emitted so far, if the host keeps
+    /// track of it.
+    ///
+    /// Used to report how many events each handler emitted in its tracing
+    /// span. Defaults to `None`, in which case the count is not reported.
+    fn ibc_event_count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the IBC events emitted since the `start`-th one, i.e. those
+    /// beyond the count reported by [`ExecutionContext::ibc_event_count`] at
+    /// the time, if the host keeps them.
+    ///
+    /// Defaults to `None`. Hosts implementing
+    /// [`ExecutionContext::on_execute_complete`] must also implement this
+    /// method and [`ExecutionContext::ibc_event_count`], or the hook is never
+    /// called.
+    fn ibc_events_since(&self, _start: usize) -> Option<Vec<IbcEvent>> {
+        None
+    }
+
+    /// Called by the `execute` entrypoint once a message has been executed
+    /// successfully, with the events it emitted.
+    ///
+    /// Hosts can use this to feed an indexer. It is only called if the
+    /// emitted events are available, i.e. if both
+    /// [`ExecutionContext::ibc_event_count`] and
+    /// [`ExecutionContext::ibc_events_since`] return them, so that it never
+    /// reports a message as having emitted no events when they are merely not
+    /// tracked. Defaults to a no-op.
+    fn on_execute_complete(&mut self, _msg_summary: MsgSummary, _events: &[IbcEvent]) {}
+
+    /// Called by the `dispatch_batch` entrypoint before the first message of a
+    /// batch is dispatched, marking the state that
+    /// [`ExecutionContext::revert_batch`] returns to.
+    ///
+    /// Defaults to a no-op.
+    fn begin_batch(&mut self) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called by the `dispatch_batch` entrypoint when a message of the batch
+    /// fails, to discard the state changes of the messages of the batch
+    /// executed before it.
+    ///
+    /// Defaults to a no-op, in which case those changes remain and the host
+    /// is responsible for discarding them, e.g. along with the rest of the
+    /// transaction.
+    fn revert_batch(&mut self) {}
+
+    /// Returns whether [`ExecutionContext::revert_batch`] discards the state
+    /// changes made since [`ExecutionContext::begin_batch`], which the dry
+    /// run of a message relies on to leave the state untouched.
+    ///
+    /// Defaults to `false`, matching the no-op defaults of both.
+    fn supports_revert_batch(&self) -> bool {
+        false
+    }
+
     /// Log the given message.
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
 }
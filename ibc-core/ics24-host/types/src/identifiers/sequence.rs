@@ -44,9 +44,24 @@ impl Sequence {
         self.0 == 0
     }
 
-    /// Increments the sequence number by one.
+    /// Increments the sequence number by one, saturating at `u64::MAX`.
+    ///
+    /// Use [`Sequence::checked_increment`] to detect that the sequence
+    /// numbers are exhausted instead.
     pub fn increment(&self) -> Sequence {
-        Sequence(self.0 + 1)
+        Sequence(self.0.saturating_add(1))
+    }
+
+    /// Increments the sequence number by one, returning `None` if it is
+    /// already `u64::MAX`.
+    pub fn checked_increment(&self) -> Option<Sequence> {
+        self.0.checked_add(1).map(Sequence)
+    }
+
+    /// Returns an iterator over the sequences from `start` to `end`, both
+    /// inclusive. The range is empty if `start` is greater than `end`.
+    pub fn range(start: Sequence, end: Sequence) -> impl DoubleEndedIterator<Item = Sequence> {
+        (start.0..=end.0).map(Sequence)
     }
 
     /// Encodes the sequence number into a byte array in big endian.
@@ -72,3 +87,40 @@ impl core::fmt::Display for Sequence {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_increment() {
+        assert_eq!(Sequence::from(7).increment(), Sequence::from(8));
+        assert_eq!(
+            Sequence::from(u64::MAX).increment(),
+            Sequence::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_checked_increment() {
+        assert_eq!(
+            Sequence::from(7).checked_increment(),
+            Some(Sequence::from(8))
+        );
+        assert_eq!(Sequence::from(u64::MAX).checked_increment(), None);
+    }
+
+    #[test]
+    fn test_range() {
+        let range = |start: u64, end: u64| {
+            Sequence::range(start.into(), end.into())
+                .map(u64::from)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(range(2, 5), vec![2, 3, 4, 5]);
+        assert_eq!(range(5, 5), vec![5]);
+        assert!(range(5, 2).is_empty());
+        assert_eq!(range(u64::MAX - 1, u64::MAX), vec![u64::MAX - 1, u64::MAX]);
+    }
+}
@@ -29,6 +29,11 @@ pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+pub const CHANNEL_UPGRADE_PREFIX: &str = "channelUpgrades";
+pub const UPGRADE_PREFIX: &str = "upgrades";
+pub const UPGRADE_ERROR_PREFIX: &str = "upgradeError";
+pub const COUNTERPARTY_UPGRADE_PREFIX: &str = "counterpartyUpgrade";
+pub const FEES_IN_ESCROW_PREFIX: &str = "feesInEscrow";
 
 pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
@@ -63,6 +68,10 @@ pub enum Path {
     Ack(AckPath),
     Receipt(ReceiptPath),
     UpgradeClient(UpgradeClientPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
+    ChannelCounterpartyUpgrade(ChannelCounterpartyUpgradePath),
+    FeesInEscrow(FeesInEscrowPath),
 }
 
 #[cfg_attr(
@@ -526,6 +535,93 @@ impl SeqAckPath {
     }
 }
 
+/// The path under which the upgrade proposed for a channel end is stored
+/// during the channel upgrade handshake.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// The path under which a channel end records its last aborted upgrade, for
+/// the counterparty to prove when cancelling the upgrade on its end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_ERROR_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// The path under which a channel end keeps the upgrade its counterparty
+/// agreed to, until both ends have flushed their in-flight packets.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{COUNTERPARTY_UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelCounterpartyUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelCounterpartyUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelCounterpartyUpgradePath {
+        ChannelCounterpartyUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// Path under which the commitment of a sent packet is stored.
+///
+/// Paths are ordered by port, channel and then sequence number, so the
+/// commitments of a channel form a contiguous range, ordered by sequence, that
+/// can be scanned with e.g. [`BTreeMap::range`]. This is not the lexicographic
+/// order of the store keys, in which `sequences/10` sorts before `sequences/2`.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -565,6 +661,49 @@ impl CommitmentPath {
     }
 }
 
+/// Path under which the relayer fees paid for a packet are escrowed until the
+/// packet is acknowledged or timed out, as per ICS-29.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{FEES_IN_ESCROW_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
+pub struct FeesInEscrowPath {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl FeesInEscrowPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> FeesInEscrowPath {
+        FeesInEscrowPath {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence,
+        }
+    }
+
+    /// Returns the store prefix under which all the escrowed packet fees are
+    /// stored: "feesInEscrow"
+    pub fn prefix() -> String {
+        FEES_IN_ESCROW_PREFIX.to_string()
+    }
+}
+
+/// Path under which the acknowledgement commitment of a received packet is
+/// stored. Ordered like [`CommitmentPath`].
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -604,6 +743,8 @@ impl AckPath {
     }
 }
 
+/// Path under which the receipt of a received packet is stored on unordered
+/// channels. Ordered like [`CommitmentPath`].
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -724,6 +865,11 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
+            .or_else(|| parse_fees_in_escrow(&components))
+            // only accept canonical store keys, e.g. no zero-padded numbers,
+            // so that every parsed path displays back to the same key
+            .filter(|path| path.to_string() == s)
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
             })
@@ -750,7 +896,7 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         return None;
     }
 
-    let client_id = ClientId::from_str(components[1]).ok()?;
+    let client_id = ClientId::from_str(components.get(1)?).ok()?;
 
     if components.len() == 3 {
         match components[2] {
@@ -1071,6 +1217,73 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
     }
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != CHANNEL_UPGRADE_PREFIX {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    match components[1] {
+        UPGRADE_PREFIX => Some(ChannelUpgradePath(port_id, channel_id).into()),
+        UPGRADE_ERROR_PREFIX => Some(ChannelUpgradeErrorPath(port_id, channel_id).into()),
+        COUNTERPARTY_UPGRADE_PREFIX => {
+            Some(ChannelCounterpartyUpgradePath(port_id, channel_id).into())
+        }
+        _ => None,
+    }
+}
+
+fn parse_fees_in_escrow(components: &[&str]) -> Option<Path> {
+    if components.len() != 7 {
+        return None;
+    }
+
+    let first = *components.first()?;
+
+    if first != FEES_IN_ESCROW_PREFIX {
+        return None;
+    }
+
+    let port = parse_ports(&components[1..=2]);
+    let channel = parse_channels(&components[3..=4]);
+    let sequence = parse_sequences(&components[5..]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    let Some(SubPath::Sequences(sequence)) = sequence else {
+        return None;
+    };
+
+    Some(
+        FeesInEscrowPath {
+            port_id,
+            channel_id,
+            sequence,
+        }
+        .into(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1176,6 +1389,29 @@ mod tests {
         "upgradedIBCState/0/upgradedConsState",
         Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0))
     )]
+    #[case(
+        "channelUpgrades/upgrades/ports/transfer/channels/channel-0",
+        Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/upgradeError/ports/transfer/channels/channel-0",
+        Path::ChannelUpgradeError(ChannelUpgradeErrorPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/counterpartyUpgrade/ports/transfer/channels/channel-0",
+        Path::ChannelCounterpartyUpgrade(ChannelCounterpartyUpgradePath(
+            PortId::transfer(),
+            ChannelId::zero()
+        ))
+    )]
+    #[case(
+        "feesInEscrow/ports/transfer/channels/channel-0/sequences/0",
+        Path::FeesInEscrow(FeesInEscrowPath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            sequence: Sequence::from(0),
+        })
+    )]
     fn test_successful_parsing(#[case] path_str: &str, #[case] path: Path) {
         // can be parsed into Path
         assert_eq!(Path::from_str(path_str).expect("no error"), path);
@@ -1183,10 +1419,39 @@ mod tests {
         assert_eq!(path_str, path.to_string());
     }
 
+    #[test]
+    fn test_packet_paths_range_by_sequence() {
+        let channel_0 = ChannelId::zero();
+        let channel_1 = ChannelId::new(1);
+
+        let paths: BTreeMap<_, ()> = [(&channel_0, 2u64), (&channel_1, 1), (&channel_0, 10)]
+            .into_iter()
+            .chain((3..=5).map(|seq| (&channel_0, seq)))
+            .map(|(chan_id, seq)| {
+                let path = CommitmentPath::new(&PortId::transfer(), chan_id, seq.into());
+                (path, ())
+            })
+            .collect();
+
+        let start = CommitmentPath::new(&PortId::transfer(), &channel_0, 2.into());
+        let end = CommitmentPath::new(&PortId::transfer(), &channel_0, 10.into());
+        let sequences: Vec<u64> = paths
+            .range(start..=end)
+            .map(|(path, _)| path.sequence.value())
+            .collect();
+
+        assert_eq!(sequences, vec![2, 3, 4, 5, 10]);
+    }
+
     #[rstest::rstest]
     #[case("clients/clientType")]
     #[case("channels/channel-0")]
     #[case("sequences/0")]
+    #[case("clients")]
+    #[case("clients/07-tendermint-0/consensusStates/015-31")]
+    #[case("clients/07-tendermint-0/consensusStates/+15-31/processedTime")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/00")]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/+1")]
     fn test_failure_parsing(#[case] path_str: &str) {
         // cannot be parsed into Path
         assert!(Path::from_str(path_str).is_err());
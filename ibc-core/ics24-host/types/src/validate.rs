@@ -1,3 +1,5 @@
+//! Defines the ICS-24 identifier validation functions and length bounds.
+
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError as Error;
@@ -5,6 +7,23 @@ use crate::identifiers::{ChannelId, ConnectionId};
 
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
+/// Minimum length of a client identifier, as specified in ICS-24.
+pub const CLIENT_ID_MIN_LENGTH: u64 = 9;
+/// Maximum length of a client identifier, as specified in ICS-24.
+pub const CLIENT_ID_MAX_LENGTH: u64 = 64;
+/// Minimum length of a connection identifier, as specified in ICS-24.
+pub const CONNECTION_ID_MIN_LENGTH: u64 = 10;
+/// Maximum length of a connection identifier, as specified in ICS-24.
+pub const CONNECTION_ID_MAX_LENGTH: u64 = 64;
+/// Minimum length of a port identifier, as specified in ICS-24.
+pub const PORT_ID_MIN_LENGTH: u64 = 2;
+/// Maximum length of a port identifier, as specified in ICS-24.
+pub const PORT_ID_MAX_LENGTH: u64 = 128;
+/// Minimum length of a channel identifier, as specified in ICS-24.
+pub const CHANNEL_ID_MIN_LENGTH: u64 = 8;
+/// Maximum length of a channel identifier, as specified in ICS-24.
+pub const CHANNEL_ID_MAX_LENGTH: u64 = 64;
+
 /// Checks if the identifier only contains valid characters as specified in the
 /// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
 /// spec.
@@ -42,6 +61,16 @@ pub fn validate_identifier_length(id: &str, min: u64, max: u64) -> Result<(), Er
     }
 }
 
+/// Checks if the identifier only contains valid characters and is between
+/// `min` and `max` characters long.
+///
+/// This is the validation applied to port identifiers, with explicit bounds so
+/// hosts can apply it to their own app-specific identifiers.
+pub fn validate_identifier(id: &str, min: u64, max: u64) -> Result<(), Error> {
+    validate_identifier_chars(id)?;
+    validate_identifier_length(id, min, max)
+}
+
 /// Checks if a prefix forms a valid identifier with the given min/max identifier's length.
 /// The prefix must be between `min_id_length - 2`, considering `u64::MIN` (1 char) and "-"
 /// and `max_id_length - 21` characters, considering `u64::MAX` (20 chars) and "-".
@@ -85,7 +114,7 @@ pub fn validate_named_u64_index(id: &str, name: &str) -> Result<(), Error> {
 /// Default validator function for the Client types.
 pub fn validate_client_type(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_prefix_length(id, 9, 64)
+    validate_prefix_length(id, CLIENT_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Client identifiers.
@@ -93,8 +122,7 @@ pub fn validate_client_type(id: &str) -> Result<(), Error> {
 /// A valid client identifier must be between 9-64 characters as specified in
 /// the ICS-24 spec.
 pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 9, 64)
+    validate_identifier(id, CLIENT_ID_MIN_LENGTH, CLIENT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Connection identifiers.
@@ -102,8 +130,7 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// A valid connection identifier must be between 10-64 characters as specified
 /// in the ICS-24 spec.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 10, 64)?;
+    validate_identifier(id, CONNECTION_ID_MIN_LENGTH, CONNECTION_ID_MAX_LENGTH)?;
     validate_named_u64_index(id, ConnectionId::prefix())?;
     Ok(())
 }
@@ -113,8 +140,7 @@ pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
 /// A valid port identifier must be between 2-128 characters as specified in the
 /// ICS-24 spec.
 pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 2, 128)
+    validate_identifier(id, PORT_ID_MIN_LENGTH, PORT_ID_MAX_LENGTH)
 }
 
 /// Default validator function for Channel identifiers.
@@ -122,8 +148,7 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
 /// A valid channel identifier must be between 8-64 characters as specified in
 /// the ICS-24 spec.
 pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 8, 64)?;
+    validate_identifier(id, CHANNEL_ID_MIN_LENGTH, CHANNEL_ID_MAX_LENGTH)?;
     validate_named_u64_index(id, ChannelId::prefix())?;
     Ok(())
 }
@@ -266,6 +291,21 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[rstest]
+    #[case::ics24_port("transfer", PORT_ID_MIN_LENGTH, PORT_ID_MAX_LENGTH, true)]
+    #[case::below_custom_min("transfer", 10, 128, false)]
+    #[case::above_custom_max("transfer", 2, 7, false)]
+    #[case::invalid_char("trans/fer", 2, 128, false)]
+    fn test_identifier_validation(
+        #[case] id: &str,
+        #[case] min: u64,
+        #[case] max: u64,
+        #[case] success: bool,
+    ) {
+        let result = validate_identifier(id, min, max);
+        assert_eq!(result.is_ok(), success, "{result:?}");
+    }
+
     #[test]
     fn parse_healthy_client_type() {
         let id = validate_client_type("07-tendermint");
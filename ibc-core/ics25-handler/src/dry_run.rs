@@ -0,0 +1,948 @@
+//! Defines the dry-run execution of IBC messages, which reports the state
+//! changes a message would make without keeping them.
+
+use core::time::Duration;
+
+use ibc_core_channel::types::acknowledgement::Acknowledgement;
+use ibc_core_channel::types::channel::{ChannelEnd, Counterparty, Order};
+use ibc_core_channel::types::commitment::{
+    AcknowledgementCommitment, CommitmentHasher, PacketCommitment,
+};
+use ibc_core_channel::types::error::{ChannelError, PacketError};
+use ibc_core_channel::types::packet::{Packet, Receipt};
+use ibc_core_channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_channel::types::Version as ChannelVersion;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::{ContextError, StoreError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::msgs::{MsgEnvelope, MsgSummary};
+use ibc_core_host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
+use ibc_core_host::types::path::{
+    AckPath, ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradeErrorPath,
+    ChannelUpgradePath, ClientConnectionPath, CommitmentPath, ConnectionPath,
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
+    SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_core_router::router::Router;
+use ibc_core_router::types::module::{ModuleExtras, ModuleId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::{Signer, Timestamp};
+
+use crate::entrypoint::{execute, validate};
+
+/// A value written to the IBC store, as recorded in a [`StateDiff`].
+#[derive(Clone, Debug)]
+pub enum StateValue {
+    Connection(ConnectionEnd),
+    /// A connection id added to the connections of a client
+    ClientConnection(ConnectionId),
+    Channel(ChannelEnd),
+    ChannelUpgrade(Upgrade),
+    ChannelUpgradeError(ErrorReceipt),
+    PacketCommitment(PacketCommitment),
+    Receipt(Receipt),
+    Acknowledgement(AcknowledgementCommitment),
+    Sequence(Sequence),
+    /// The new value of one of the identifier counters
+    Counter(u64),
+}
+
+/// The state changes made by a message, as reported by [`execute_dry_run`].
+#[derive(Clone, Debug, Default)]
+pub struct StateDiff {
+    /// The last value written at each store path, or `None` if the value at
+    /// the path was deleted.
+    pub writes: BTreeMap<Path, Option<StateValue>>,
+    /// The last packet flow status set on each channel end, `true` if paused.
+    pub flow_status: BTreeMap<ChannelEndPath, bool>,
+    /// The events emitted, in order.
+    pub events: Vec<IbcEvent>,
+    /// The messages logged, in order.
+    pub logs: Vec<String>,
+}
+
+/// Validates and executes the given message as [`dispatch`](crate::entrypoint::dispatch)
+/// does, and reports the state changes it made as a [`StateDiff`] instead of
+/// keeping them.
+///
+/// The writes are discarded through [`ExecutionContext::begin_batch`] and
+/// [`ExecutionContext::revert_batch`]. This also covers the writes that
+/// escape the recording: those of the client contexts and the pruning of
+/// expired consensus states. Hosts must therefore opt in through
+/// [`ExecutionContext::supports_revert_batch`], and the dry run fails with
+/// [`StoreError::RevertUnsupported`] otherwise. Events and logs are only
+/// recorded, never forwarded to the host.
+///
+/// Modules keep their own state, which a reverted batch cannot restore, so
+/// the dry run only runs their validation callbacks and never their
+/// execution callbacks. The state of the modules is left untouched, and the
+/// reported changes are those of the core handlers alone. In particular, the
+/// acknowledgement written for a received packet is a placeholder, as only
+/// the receiving module can produce the actual one.
+pub fn execute_dry_run<Ctx>(
+    ctx: &mut Ctx,
+    router: &impl Router,
+    msg: MsgEnvelope,
+) -> Result<StateDiff, ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    if !ctx.supports_revert_batch() {
+        return Err(StoreError::RevertUnsupported.into());
+    }
+
+    validate(ctx, router, msg.clone())?;
+
+    ctx.begin_batch()?;
+
+    let mut recorder = RecordingContext::new(ctx);
+    let res = execute(&mut recorder, &mut DryRunRouter::new(router), msg);
+    let diff = recorder.into_diff();
+
+    ctx.revert_batch();
+
+    res.map(|()| diff)
+}
+
+/// Wraps an [`ExecutionContext`], recording the store writes made through it
+/// into a [`StateDiff`].
+///
+/// Writes are forwarded to the wrapped context, so that later reads observe
+/// them. Events and logs are recorded instead of being forwarded, and the
+/// host is not notified of executed messages.
+pub struct RecordingContext<'a, Ctx> {
+    ctx: &'a mut Ctx,
+    diff: StateDiff,
+}
+
+impl<'a, Ctx> RecordingContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    pub fn new(ctx: &'a mut Ctx) -> Self {
+        Self {
+            ctx,
+            diff: StateDiff::default(),
+        }
+    }
+
+    /// Returns the state changes recorded so far.
+    pub fn into_diff(self) -> StateDiff {
+        self.diff
+    }
+
+    fn record(&mut self, path: impl Into<Path>, value: Option<StateValue>) {
+        self.diff.writes.insert(path.into(), value);
+    }
+}
+
+impl<Ctx> ValidationContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.client_counter()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.ctx.connection_end(conn_id)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.connection_counter()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.ctx.channel_end(channel_end_path)
+    }
+
+    fn channel_upgrade(
+        &self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        self.ctx.channel_upgrade(channel_upgrade_path)
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        self.ctx
+            .counterparty_channel_upgrade(counterparty_upgrade_path)
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        self.ctx.has_inflight_packets(channel_end_path)
+    }
+
+    fn validate_upgrade_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_upgrade_authority(signer)
+    }
+
+    fn upgrade_timeout_period(&self) -> Duration {
+        self.ctx.upgrade_timeout_period()
+    }
+
+    fn is_channel_paused(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.ctx.is_channel_paused(channel_end_path)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.ctx.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.ctx.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.ctx.get_packet_acknowledgement(ack_path)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.channel_counter()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.ctx.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn commitment_hasher(&self) -> &dyn CommitmentHasher {
+        self.ctx.commitment_hasher()
+    }
+
+    fn max_messages_per_tx(&self) -> Option<usize> {
+        self.ctx.max_messages_per_tx()
+    }
+
+    fn ack_retention_window(&self) -> Option<u64> {
+        self.ctx.ack_retention_window()
+    }
+
+    fn host_chain_id(&self) -> Option<ChainId> {
+        self.ctx.host_chain_id()
+    }
+}
+
+impl<Ctx> ExecutionContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_client_counter()?;
+        let counter = self.ctx.client_counter()?;
+        self.record(NextClientSequencePath, Some(StateValue::Counter(counter)));
+        Ok(())
+    }
+
+    fn prune_expired_consensus_states(
+        &mut self,
+        client_id: &ClientId,
+        now: Timestamp,
+    ) -> Result<usize, ContextError> {
+        self.ctx.prune_expired_consensus_states(client_id, now)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_connection(connection_path, connection_end.clone())?;
+        self.record(
+            connection_path.clone(),
+            Some(StateValue::Connection(connection_end)),
+        );
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_connection_to_client(client_connection_path, conn_id.clone())?;
+        self.record(
+            client_connection_path.clone(),
+            Some(StateValue::ClientConnection(conn_id)),
+        );
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_connection_counter()?;
+        let counter = self.ctx.connection_counter()?;
+        self.record(
+            NextConnectionSequencePath,
+            Some(StateValue::Counter(counter)),
+        );
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_packet_commitment(commitment_path, commitment.clone())?;
+        self.record(
+            commitment_path.clone(),
+            Some(StateValue::PacketCommitment(commitment)),
+        );
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.ctx.delete_packet_commitment(commitment_path)?;
+        self.record(commitment_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_packet_receipt(receipt_path, receipt.clone())?;
+        self.record(receipt_path.clone(), Some(StateValue::Receipt(receipt)));
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_packet_acknowledgement(ack_path, ack_commitment.clone())?;
+        self.record(
+            ack_path.clone(),
+            Some(StateValue::Acknowledgement(ack_commitment)),
+        );
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.ctx.delete_packet_acknowledgement(ack_path)?;
+        self.record(ack_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_channel(channel_end_path, channel_end.clone())?;
+        self.record(
+            channel_end_path.clone(),
+            Some(StateValue::Channel(channel_end)),
+        );
+        Ok(())
+    }
+
+    fn set_channel_flow_status(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        paused: bool,
+    ) -> Result<(), ContextError> {
+        self.ctx.set_channel_flow_status(channel_end_path, paused)?;
+        self.diff
+            .flow_status
+            .insert(channel_end_path.clone(), paused);
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        channel_upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_channel_upgrade(channel_upgrade_path, upgrade.clone())?;
+        self.record(
+            channel_upgrade_path.clone(),
+            Some(StateValue::ChannelUpgrade(upgrade)),
+        );
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ctx.delete_channel_upgrade(channel_upgrade_path)?;
+        self.record(channel_upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_counterparty_channel_upgrade(counterparty_upgrade_path, upgrade.clone())?;
+        self.record(
+            counterparty_upgrade_path.clone(),
+            Some(StateValue::ChannelUpgrade(upgrade)),
+        );
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .delete_counterparty_channel_upgrade(counterparty_upgrade_path)?;
+        self.record(counterparty_upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_channel_upgrade_error(upgrade_error_path, error_receipt.clone())?;
+        self.record(
+            upgrade_error_path.clone(),
+            Some(StateValue::ChannelUpgradeError(error_receipt)),
+        );
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_send(seq_send_path, seq)?;
+        self.record(seq_send_path.clone(), Some(StateValue::Sequence(seq)));
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_recv(seq_recv_path, seq)?;
+        self.record(seq_recv_path.clone(), Some(StateValue::Sequence(seq)));
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_ack(seq_ack_path, seq)?;
+        self.record(seq_ack_path.clone(), Some(StateValue::Sequence(seq)));
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_channel_counter()?;
+        let counter = self.ctx.channel_counter()?;
+        self.record(NextChannelSequencePath, Some(StateValue::Counter(counter)));
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.diff.events.push(event);
+        Ok(())
+    }
+
+    fn ibc_event_count(&self) -> Option<usize> {
+        Some(self.diff.events.len())
+    }
+
+    fn ibc_events_since(&self, start: usize) -> Option<Vec<IbcEvent>> {
+        self.diff.events.get(start..).map(<[IbcEvent]>::to_vec)
+    }
+
+    fn on_execute_complete(&mut self, _msg_summary: MsgSummary, _events: &[IbcEvent]) {}
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.diff.logs.push(message);
+        Ok(())
+    }
+}
+
+/// The acknowledgement a dry run writes for a received packet, in place of
+/// the one the receiving module would produce.
+const DRY_RUN_ACKNOWLEDGEMENT: &[u8] = b"dry-run";
+
+/// Routes messages to the modules of a wrapped router, without ever running
+/// their execution callbacks.
+struct DryRunRouter<'r, R> {
+    router: &'r R,
+    modules: BTreeMap<ModuleId, DryRunModule<'r>>,
+}
+
+impl<'r, R> DryRunRouter<'r, R>
+where
+    R: Router,
+{
+    fn new(router: &'r R) -> Self {
+        let modules = router
+            .module_ids()
+            .filter_map(|module_id| {
+                router
+                    .get_route(module_id)
+                    .map(|module| (module_id.clone(), DryRunModule(module)))
+            })
+            .collect();
+
+        Self { router, modules }
+    }
+}
+
+impl<R> Router for DryRunRouter<'_, R>
+where
+    R: Router,
+{
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules
+            .get(module_id)
+            .map(|module| module as &dyn Module)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        self.modules
+            .get_mut(module_id)
+            .map(|module| module as &mut dyn Module)
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.router.lookup_module(port_id)
+    }
+
+    fn module_ids(&self) -> Box<dyn Iterator<Item = &ModuleId> + '_> {
+        self.router.module_ids()
+    }
+
+    fn bound_ports(&self) -> Box<dyn Iterator<Item = &PortId> + '_> {
+        self.router.bound_ports()
+    }
+}
+
+/// Wraps a module, forwarding its validation callbacks and turning its
+/// execution callbacks into no-ops.
+///
+/// The execution callbacks choosing a version answer with the version the
+/// matching validation callback chooses.
+#[derive(Debug)]
+struct DryRunModule<'r>(&'r dyn Module);
+
+impl Module for DryRunModule<'_> {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &ChannelVersion,
+    ) -> Result<ChannelVersion, ChannelError> {
+        self.0.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &ChannelVersion,
+    ) -> Result<(ModuleExtras, ChannelVersion), ChannelError> {
+        let version = self.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+
+        Ok((ModuleExtras::empty(), version))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &ChannelVersion,
+    ) -> Result<ChannelVersion, ChannelError> {
+        self.0.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &ChannelVersion,
+    ) -> Result<(ModuleExtras, ChannelVersion), ChannelError> {
+        let version = self.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?;
+
+        Ok((ModuleExtras::empty(), version))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &ChannelVersion,
+    ) -> Result<(), ChannelError> {
+        self.0
+            .on_chan_open_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &ChannelVersion,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.0.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.0.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.0.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &ChannelVersion,
+    ) -> Result<ChannelVersion, ChannelError> {
+        self.0.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &ChannelVersion,
+    ) -> Result<(ModuleExtras, ChannelVersion), ChannelError> {
+        let version = self.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )?;
+
+        Ok((ModuleExtras::empty(), version))
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &ChannelVersion,
+    ) -> Result<ChannelVersion, ChannelError> {
+        self.0.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &ChannelVersion,
+    ) -> Result<(ModuleExtras, ChannelVersion), ChannelError> {
+        let version = self.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )?;
+
+        Ok((ModuleExtras::empty(), version))
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &ChannelVersion,
+    ) -> Result<(), ChannelError> {
+        self.0
+            .on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &ChannelVersion,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &ChannelVersion,
+    ) -> ModuleExtras {
+        ModuleExtras::empty()
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Acknowledgement) {
+        let acknowledgement = Acknowledgement::try_from(DRY_RUN_ACKNOWLEDGEMENT.to_vec())
+            .expect("the placeholder acknowledgement is not empty");
+
+        (ModuleExtras::empty(), acknowledgement)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.0
+            .on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.0.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn is_bound_to_port(&self, port_id: &PortId) -> bool {
+        self.0.is_bound_to_port(port_id)
+    }
+}
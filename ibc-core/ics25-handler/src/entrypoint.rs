@@ -1,28 +1,53 @@
+use core::ops::Deref;
+
 use ibc_core_channel::handler::{
     acknowledgement_packet_execute, acknowledgement_packet_validate, chan_close_confirm_execute,
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_ack_execute,
+    chan_upgrade_ack_validate, chan_upgrade_cancel_execute, chan_upgrade_cancel_validate,
+    chan_upgrade_confirm_execute, chan_upgrade_confirm_validate, chan_upgrade_init_execute,
+    chan_upgrade_init_validate, chan_upgrade_open_execute, chan_upgrade_open_validate,
+    chan_upgrade_timeout_execute, chan_upgrade_timeout_validate, chan_upgrade_try_execute,
+    chan_upgrade_try_validate, prune_acknowledgements_execute, prune_acknowledgements_validate,
+    recv_packet_execute, recv_packet_validate, recv_packet_validate_with_root,
     timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
-    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
+    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, MsgRecvPacket, PacketMsg,
+    ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL,
+    CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL,
+    CHAN_OPEN_TRY_TYPE_URL, CHAN_UPGRADE_ACK_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL,
+    CHAN_UPGRADE_CONFIRM_TYPE_URL, CHAN_UPGRADE_INIT_TYPE_URL, CHAN_UPGRADE_OPEN_TYPE_URL,
+    CHAN_UPGRADE_TIMEOUT_TYPE_URL, CHAN_UPGRADE_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
+    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
-use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_channel::types::packet::Packet;
+use ibc_core_client::context::prelude::*;
 use ibc_core_client::handler::{create_client, update_client, upgrade_client};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{CommitmentProofBytes, CommitmentRoot};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_handler_types::msgs::{MsgEnvelope, MsgSummary};
+use ibc_core_host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
 use ibc_core_router::router::Router;
 use ibc_core_router::types::error::RouterError;
+use ibc_core_router::types::module::ModuleId;
+use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Signer;
+use tracing::field::Empty;
+use tracing::Span;
 
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch<Ctx>(
@@ -40,6 +65,180 @@ where
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which dispatches all the messages of a transaction in order,
+/// as described in [`validate`].
+///
+/// Fails without processing any message if the transaction carries more
+/// messages than [`ValidationContext::max_messages_per_tx`] allows.
+///
+/// Processing stops at the first message that fails, and the store writes
+/// of the messages before it are discarded through
+/// [`ExecutionContext::revert_batch`], if the host implements it. Messages
+/// cannot be validated all up front instead, since a message may depend on
+/// the state written by an earlier one, e.g. a channel opened on a
+/// connection created in the same transaction.
+///
+/// The batch is not atomic, though: only the writes to the host store are
+/// reverted. State kept by the router or its modules, such as application
+/// balances, is left as the earlier messages changed it. So is state the
+/// host keeps outside of its store, such as emitted events, logs and
+/// [`ExecutionContext::on_execute_complete`] notifications, or the frozen
+/// heights and host consensus states the testkit's mock store keeps in
+/// memory.
+pub fn dispatch_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    if let Some(max) = ctx.max_messages_per_tx() {
+        if msgs.len() > max {
+            return Err(ContextError::TooManyMessages {
+                count: msgs.len(),
+                max,
+            });
+        }
+    }
+
+    ctx.begin_batch()?;
+
+    msgs.into_iter()
+        .try_for_each(|msg| dispatch(ctx, router, msg))
+        .map_err(|e| {
+            ctx.revert_batch();
+            e
+        })
+}
+
+/// Entrypoint which validates and executes a batch of received packets, all
+/// proven against the counterparty state at the same `proof_height_on_a`.
+///
+/// Each packet is paired with the proof of its commitment. The commitment
+/// root of the counterparty is looked up once per client and reused for
+/// every packet of the batch relayed over that client, instead of being
+/// decoded again for each packet.
+///
+/// Packets are processed in order, as if each one was dispatched in its own
+/// [`MsgRecvPacket`]. Processing stops at the first packet that fails, whose
+/// index in the batch is returned along with the error; the packets before
+/// it remain received.
+///
+/// Fails without processing any packet if the batch carries more packets
+/// than [`ValidationContext::max_messages_per_tx`] allows, in which case the
+/// index returned is that of the first packet over the limit.
+pub fn recv_packets_execute<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    packets: Vec<(Packet, CommitmentProofBytes)>,
+    proof_height_on_a: Height,
+    signer: Signer,
+) -> Result<(), (usize, ContextError)>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    if let Some(max) = ctx.max_messages_per_tx() {
+        if packets.len() > max {
+            return Err((
+                max,
+                ContextError::TooManyMessages {
+                    count: packets.len(),
+                    max,
+                },
+            ));
+        }
+    }
+
+    let mut roots_on_a = BTreeMap::new();
+
+    packets
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(index, (packet, proof_commitment_on_a))| {
+            let msg = MsgRecvPacket {
+                packet,
+                proof_commitment_on_a,
+                proof_height_on_a,
+                signer: signer.clone(),
+            };
+
+            recv_packet_in_batch(ctx, router, &mut roots_on_a, msg).map_err(|e| (index, e))
+        })
+}
+
+fn recv_packet_in_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    roots_on_a: &mut BTreeMap<ClientId, CommitmentRoot>,
+    msg: MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let chan_end_path_on_b =
+        ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+    let chan_end_on_b = ctx.channel_end(&chan_end_path_on_b)?;
+    let conn_end_on_b = ctx.connection_end(&chan_end_on_b.connection_hops()[0])?;
+    let client_id_on_b = conn_end_on_b.client_id();
+
+    if !roots_on_a.contains_key(client_id_on_b) {
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let root_on_a = ctx
+            .get_client_validation_context()
+            .consensus_state(&client_cons_state_path_on_b)?
+            .root()
+            .clone();
+
+        roots_on_a.insert(client_id_on_b.clone(), root_on_a);
+    }
+
+    recv_packet_validate_with_root(ctx, msg.clone(), &roots_on_a[client_id_on_b])?;
+
+    execute(ctx, router, MsgEnvelope::from(PacketMsg::Recv(msg)))
+}
+
+/// Entrypoint which prunes the packet acknowledgements of a channel end below
+/// `before_sequence`, once the counterparty has proven it relayed them.
+///
+/// `relayed` pairs the sequence of each acknowledgement to prune with a proof
+/// that the counterparty no longer holds the packet commitment at
+/// `proof_height_on_a`, as checked by [`prune_acknowledgements_validate`]. The
+/// acknowledgements within the host's
+/// [`ack_retention_window`](ValidationContext::ack_retention_window) are kept.
+/// Returns the number of acknowledgements removed.
+///
+/// Nothing is pruned unless every acknowledgement to prune is proven relayed.
+pub fn prune_acknowledgements<Ctx>(
+    ctx: &mut Ctx,
+    chan_end_path_on_b: &ChannelEndPath,
+    before_sequence: Sequence,
+    relayed: &[(Sequence, CommitmentProofBytes)],
+    proof_height_on_a: Height,
+) -> Result<usize, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    prune_acknowledgements_validate(
+        ctx,
+        chan_end_path_on_b,
+        before_sequence,
+        relayed,
+        proof_height_on_a,
+    )?;
+
+    prune_acknowledgements_execute(ctx, chan_end_path_on_b, before_sequence)
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -77,56 +276,114 @@ where
             ConnectionMsg::OpenConfirm(msg) => conn_open_confirm::validate(ctx, &msg),
         },
         MsgEnvelope::Channel(msg) => {
-            let port_id = channel_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
-                .ok_or(RouterError::UnknownPort {
-                    port_id: port_id.clone(),
-                })?;
-            let module = router
-                .get_route(&module_id)
-                .ok_or(RouterError::ModuleNotFound)?;
-
-            match msg {
-                ChannelMsg::OpenInit(msg) => chan_open_init_validate(ctx, module, msg),
-                ChannelMsg::OpenTry(msg) => chan_open_try_validate(ctx, module, msg),
-                ChannelMsg::OpenAck(msg) => chan_open_ack_validate(ctx, module, msg),
-                ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
-                ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
-                ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
-            }
+            let span = channel_msg_span(&msg, "validate");
+            let _guard = span.enter();
+
+            let res = validate_channel_msg(ctx, router, msg);
+
+            record_outcome(&span, &res);
+
+            res
         }
         MsgEnvelope::Packet(msg) => {
-            let port_id = packet_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
-                .ok_or(RouterError::UnknownPort {
-                    port_id: port_id.clone(),
-                })?;
-            let module = router
-                .get_route(&module_id)
-                .ok_or(RouterError::ModuleNotFound)?;
-
-            match msg {
-                PacketMsg::Recv(msg) => recv_packet_validate(ctx, msg),
-                PacketMsg::Ack(msg) => acknowledgement_packet_validate(ctx, module, msg),
-                PacketMsg::Timeout(msg) => {
-                    timeout_packet_validate(ctx, module, TimeoutMsgType::Timeout(msg))
-                }
-                PacketMsg::TimeoutOnClose(msg) => {
-                    timeout_packet_validate(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
-                }
-            }
+            let span = packet_msg_span(&msg, "validate");
+            let _guard = span.enter();
+
+            let res = validate_packet_msg(ctx, router, msg);
+
+            record_outcome(&span, &res);
+
+            res
+        }
+    }
+}
+
+fn validate_channel_msg<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    msg: ChannelMsg,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let port_id = channel_msg_to_port_id(&msg);
+    let module = lookup_bound_module(router, port_id, |router, module_id| {
+        router.get_route(module_id)
+    })?;
+
+    match msg {
+        ChannelMsg::OpenInit(msg) => chan_open_init_validate(ctx, module, msg),
+        ChannelMsg::OpenTry(msg) => chan_open_try_validate(ctx, module, msg),
+        ChannelMsg::OpenAck(msg) => chan_open_ack_validate(ctx, module, msg),
+        ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
+        ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
+        ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+        ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_validate(ctx, module, msg),
+        ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_validate(ctx, module, msg),
+        ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_validate(ctx, module, msg),
+        ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_validate(ctx, msg),
+        ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_validate(ctx, msg),
+        ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, msg),
+        ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, msg),
+    }
+}
+
+fn validate_packet_msg<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    msg: PacketMsg,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let port_id = packet_msg_to_port_id(&msg);
+    let module = lookup_bound_module(router, port_id, |router, module_id| {
+        router.get_route(module_id)
+    })?;
+
+    match msg {
+        PacketMsg::Recv(msg) => recv_packet_validate(ctx, msg),
+        PacketMsg::Ack(msg) => acknowledgement_packet_validate(ctx, module, msg),
+        PacketMsg::Timeout(msg) => {
+            timeout_packet_validate(ctx, module, TimeoutMsgType::Timeout(msg))
+        }
+        PacketMsg::TimeoutOnClose(msg) => {
+            timeout_packet_validate(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
         }
     }
 }
 
 /// Entrypoint which only performs message execution
+///
+/// Once the message has been executed successfully, the host is notified
+/// through [`ExecutionContext::on_execute_complete`], provided it keeps track
+/// of the emitted events.
 pub fn execute<Ctx>(
     ctx: &mut Ctx,
     router: &mut impl Router,
     msg: MsgEnvelope,
 ) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let msg_summary = MsgSummary::from(&msg);
+    let events_before = ctx.ibc_event_count();
+
+    execute_msg(ctx, router, msg)?;
+
+    if let Some(events) = events_before.and_then(|start| ctx.ibc_events_since(start)) {
+        ctx.on_execute_complete(msg_summary, &events);
+    }
+
+    Ok(())
+}
+
+fn execute_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
     <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
@@ -154,46 +411,213 @@ where
             ConnectionMsg::OpenConfirm(msg) => conn_open_confirm::execute(ctx, &msg),
         },
         MsgEnvelope::Channel(msg) => {
-            let port_id = channel_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
-                .ok_or(RouterError::UnknownPort {
-                    port_id: port_id.clone(),
-                })?;
-            let module = router
-                .get_route_mut(&module_id)
-                .ok_or(RouterError::ModuleNotFound)?;
-
-            match msg {
-                ChannelMsg::OpenInit(msg) => chan_open_init_execute(ctx, module, msg),
-                ChannelMsg::OpenTry(msg) => chan_open_try_execute(ctx, module, msg),
-                ChannelMsg::OpenAck(msg) => chan_open_ack_execute(ctx, module, msg),
-                ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
-                ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
-                ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+            let span = channel_msg_span(&msg, "execute");
+            let _guard = span.enter();
+            let events_before = ctx.ibc_event_count();
+
+            // the opening handshake assigns the next channel identifier to
+            // the new channel end
+            let assigned_chan_id = match msg {
+                ChannelMsg::OpenInit(_) | ChannelMsg::OpenTry(_) => {
+                    ctx.channel_counter().ok().map(ChannelId::new)
+                }
+                _ => None,
+            };
+
+            let res = execute_channel_msg(ctx, router, msg);
+
+            record_outcome(&span, &res);
+            record_emitted_events(&span, events_before, ctx.ibc_event_count());
+            if let (Ok(()), Some(chan_id)) = (&res, assigned_chan_id) {
+                span.record("channel_id", tracing::field::display(chan_id));
             }
+
+            res
         }
         MsgEnvelope::Packet(msg) => {
-            let port_id = packet_msg_to_port_id(&msg);
-            let module_id = router
-                .lookup_module(port_id)
-                .ok_or(RouterError::UnknownPort {
-                    port_id: port_id.clone(),
-                })?;
-            let module = router
-                .get_route_mut(&module_id)
-                .ok_or(RouterError::ModuleNotFound)?;
-
-            match msg {
-                PacketMsg::Recv(msg) => recv_packet_execute(ctx, module, msg),
-                PacketMsg::Ack(msg) => acknowledgement_packet_execute(ctx, module, msg),
-                PacketMsg::Timeout(msg) => {
-                    timeout_packet_execute(ctx, module, TimeoutMsgType::Timeout(msg))
-                }
-                PacketMsg::TimeoutOnClose(msg) => {
-                    timeout_packet_execute(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
-                }
-            }
+            let span = packet_msg_span(&msg, "execute");
+            let _guard = span.enter();
+            let events_before = ctx.ibc_event_count();
+
+            let res = execute_packet_msg(ctx, router, msg);
+
+            record_outcome(&span, &res);
+            record_emitted_events(&span, events_before, ctx.ibc_event_count());
+
+            res
+        }
+    }
+}
+
+fn execute_channel_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: ChannelMsg,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let port_id = channel_msg_to_port_id(&msg);
+    let module = lookup_bound_module(router, port_id, |router, module_id| {
+        router.get_route_mut(module_id)
+    })?;
+
+    match msg {
+        ChannelMsg::OpenInit(msg) => chan_open_init_execute(ctx, module, msg),
+        ChannelMsg::OpenTry(msg) => chan_open_try_execute(ctx, module, msg),
+        ChannelMsg::OpenAck(msg) => chan_open_ack_execute(ctx, module, msg),
+        ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
+        ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
+        ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+        ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_execute(ctx, module, msg),
+        ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_execute(ctx, module, msg),
+        ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_execute(ctx, module, msg),
+        ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_execute(ctx, module, msg),
+        ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_execute(ctx, module, msg),
+        ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, msg),
+        ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, msg),
+    }
+}
+
+fn execute_packet_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: PacketMsg,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let port_id = packet_msg_to_port_id(&msg);
+    let module = lookup_bound_module(router, port_id, |router, module_id| {
+        router.get_route_mut(module_id)
+    })?;
+
+    match msg {
+        PacketMsg::Recv(msg) => recv_packet_execute(ctx, module, msg),
+        PacketMsg::Ack(msg) => acknowledgement_packet_execute(ctx, module, msg),
+        PacketMsg::Timeout(msg) => {
+            timeout_packet_execute(ctx, module, TimeoutMsgType::Timeout(msg))
         }
+        PacketMsg::TimeoutOnClose(msg) => {
+            timeout_packet_execute(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
+        }
+    }
+}
+
+/// Looks up the module the router binds the given port to, fetching it with
+/// `get_route`, i.e. either [`Router::get_route`] or [`Router::get_route_mut`].
+///
+/// Fails if the port is unknown, if its module is not registered, or if the
+/// module reports not being bound to the port.
+fn lookup_bound_module<R, M>(
+    router: R,
+    port_id: &PortId,
+    get_route: impl FnOnce(R, &ModuleId) -> Option<M>,
+) -> Result<M, RouterError>
+where
+    R: Deref,
+    R::Target: Router,
+    M: Deref,
+    M::Target: Module,
+{
+    let module_id = router
+        .lookup_module(port_id)
+        .ok_or(RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        })?;
+    let module = get_route(router, &module_id).ok_or_else(|| RouterError::ModuleNotFound {
+        port_id: port_id.clone(),
+    })?;
+    if !module.is_bound_to_port(port_id) {
+        return Err(RouterError::PortNotBound {
+            port_id: port_id.clone(),
+        });
+    }
+
+    Ok(module)
+}
+
+/// Opens the span under which a channel message is handled during the given
+/// `stage` (either `validate` or `execute`).
+fn channel_msg_span(msg: &ChannelMsg, stage: &'static str) -> Span {
+    let (msg_type, channel_id): (_, Option<&ChannelId>) = match msg {
+        ChannelMsg::OpenInit(_) => (CHAN_OPEN_INIT_TYPE_URL, None),
+        ChannelMsg::OpenTry(_) => (CHAN_OPEN_TRY_TYPE_URL, None),
+        ChannelMsg::OpenAck(msg) => (CHAN_OPEN_ACK_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::OpenConfirm(msg) => (CHAN_OPEN_CONFIRM_TYPE_URL, Some(&msg.chan_id_on_b)),
+        ChannelMsg::CloseInit(msg) => (CHAN_CLOSE_INIT_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::CloseConfirm(msg) => (CHAN_CLOSE_CONFIRM_TYPE_URL, Some(&msg.chan_id_on_b)),
+        ChannelMsg::UpgradeInit(msg) => (CHAN_UPGRADE_INIT_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::UpgradeTry(msg) => (CHAN_UPGRADE_TRY_TYPE_URL, Some(&msg.chan_id_on_b)),
+        ChannelMsg::UpgradeAck(msg) => (CHAN_UPGRADE_ACK_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::UpgradeConfirm(msg) => (CHAN_UPGRADE_CONFIRM_TYPE_URL, Some(&msg.chan_id_on_b)),
+        ChannelMsg::UpgradeOpen(msg) => (CHAN_UPGRADE_OPEN_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::UpgradeCancel(msg) => (CHAN_UPGRADE_CANCEL_TYPE_URL, Some(&msg.chan_id_on_a)),
+        ChannelMsg::UpgradeTimeout(msg) => (CHAN_UPGRADE_TIMEOUT_TYPE_URL, Some(&msg.chan_id_on_a)),
+    };
+
+    handler_span(stage, msg_type, channel_msg_to_port_id(msg), channel_id)
+}
+
+/// Opens the span under which a packet message is handled during the given
+/// `stage` (either `validate` or `execute`).
+fn packet_msg_span(msg: &PacketMsg, stage: &'static str) -> Span {
+    let (msg_type, channel_id) = match msg {
+        PacketMsg::Recv(msg) => (RECV_PACKET_TYPE_URL, &msg.packet.chan_id_on_b),
+        PacketMsg::Ack(msg) => (ACKNOWLEDGEMENT_TYPE_URL, &msg.packet.chan_id_on_a),
+        PacketMsg::Timeout(msg) => (TIMEOUT_TYPE_URL, &msg.packet.chan_id_on_a),
+        PacketMsg::TimeoutOnClose(msg) => (TIMEOUT_ON_CLOSE_TYPE_URL, &msg.packet.chan_id_on_a),
+    };
+
+    handler_span(
+        stage,
+        msg_type,
+        packet_msg_to_port_id(msg),
+        Some(channel_id),
+    )
+}
+
+fn handler_span(
+    stage: &'static str,
+    msg_type: &'static str,
+    port_id: &PortId,
+    channel_id: Option<&ChannelId>,
+) -> Span {
+    let span = tracing::info_span!(
+        "ibc_handler",
+        stage,
+        msg_type,
+        port_id = %port_id,
+        channel_id = Empty,
+        outcome = Empty,
+        events = Empty,
+    );
+
+    // channels are only assigned an identifier once their opening
+    // handshake step has been executed
+    if let Some(channel_id) = channel_id {
+        span.record("channel_id", tracing::field::display(channel_id));
+    }
+
+    span
+}
+
+fn record_outcome<T>(span: &Span, res: &Result<T, ContextError>) {
+    match res {
+        Ok(_) => {
+            span.record("outcome", "success");
+        }
+        Err(e) => {
+            span.record("outcome", "failure");
+            tracing::debug!(error = %e, "IBC handler failed");
+        }
+    }
+}
+
+/// Records how many IBC events the handler emitted, provided the host keeps
+/// count of them through [`ExecutionContext::ibc_event_count`].
+fn record_emitted_events(span: &Span, before: Option<usize>, after: Option<usize>) {
+    if let (Some(before), Some(after)) = (before, after) {
+        span.record("events", after.saturating_sub(before));
     }
 }
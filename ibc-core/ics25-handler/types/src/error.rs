@@ -7,6 +7,7 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::SignerError;
 
 /// Top-level error
 #[derive(Debug, Display, From)]
@@ -21,6 +22,28 @@ pub enum ContextError {
     PacketError(PacketError),
     /// ICS26 Routing error: {0}
     RouterError(RouterError),
+    /// Signer error: {0}
+    SignerError(SignerError),
+    /// Store error: {0}
+    StoreError(StoreError),
+    /// too many messages in transaction: got `{count}`, max `{max}`
+    #[from(ignore)]
+    TooManyMessages { count: usize, max: usize },
+}
+
+/// Errors raised by the host store backing the contexts.
+///
+/// Lets hosts tell a missing value apart from a failure of the store itself,
+/// e.g. so that a query server can answer `not_found` for the former and
+/// `internal` for the latter.
+#[derive(Debug, Display)]
+pub enum StoreError {
+    /// no value found at `{path}`
+    NotFound { path: String },
+    /// store backend error: `{description}`
+    Backend { description: String },
+    /// the store cannot revert a batch of writes
+    RevertUnsupported,
 }
 
 impl From<ContextError> for ClientError {
@@ -43,6 +66,12 @@ impl std::error::Error for ContextError {
             Self::ChannelError(e) => Some(e),
             Self::PacketError(e) => Some(e),
             Self::RouterError(e) => Some(e),
+            Self::SignerError(e) => Some(e),
+            Self::StoreError(e) => Some(e),
+            Self::TooManyMessages { .. } => None,
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for StoreError {}
@@ -80,6 +80,13 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancel),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimedOut),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -111,6 +118,13 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
@@ -143,6 +157,13 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeInitChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTryChannel(event) => event.event_type(),
+            IbcEvent::UpgradeAckChannel(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeOpenChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
@@ -153,6 +174,41 @@ impl IbcEvent {
             IbcEvent::Message(_) => MESSAGE_EVENT,
         }
     }
+
+    /// Returns a canonical byte encoding of the event, meant for hosts that
+    /// hash emitted events into their blocks.
+    ///
+    /// The encoding is derived from the ABCI event, with its attributes sorted
+    /// by key and value, so that it only depends on the event's content. The
+    /// event type and each attribute key and value are written as their
+    /// length, as a big-endian `u64`, followed by their bytes. The attributes'
+    /// `index` flags are not part of the encoding.
+    ///
+    /// Fails whenever the conversion to an ABCI event fails.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        fn write_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+            buf.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+            buf.extend_from_slice(bytes);
+        }
+
+        let event = abci::Event::try_from(self.clone())?;
+
+        let mut attributes: Vec<(&[u8], &[u8])> = event
+            .attributes
+            .iter()
+            .map(|attr| (attr.key_bytes(), attr.value_bytes()))
+            .collect();
+        attributes.sort_unstable();
+
+        let mut buf = Vec::new();
+        write_bytes(&mut buf, event.kind.as_bytes());
+        for (key, value) in attributes {
+            write_bytes(&mut buf, key);
+            write_bytes(&mut buf, value);
+        }
+
+        Ok(buf)
+    }
 }
 
 /// An event type that is emitted by the Cosmos SDK.
@@ -206,3 +262,142 @@ impl From<ModuleEvent> for IbcEvent {
         IbcEvent::Module(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_client_types::Height;
+    use ibc_core_host_types::identifiers::{ChannelId, ClientType, ConnectionId, PortId};
+    use ibc_core_router_types::event::ModuleEventAttribute;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    /// Converts `event` into an ABCI event and checks its kind and attributes
+    /// against the layout emitted by ibc-go.
+    fn assert_abci_layout(event: IbcEvent, kind: &str, expected: &[(&str, &str)]) {
+        let abci_event = abci::Event::try_from(event).expect("valid event");
+
+        assert_eq!(abci_event.kind, kind);
+        assert_eq!(abci_event.attributes.len(), expected.len(), "{kind}");
+        for (attribute, (key, value)) in abci_event.attributes.iter().zip(expected) {
+            assert_eq!(attribute.key_str().unwrap(), *key, "{kind}");
+            assert_eq!(attribute.value_str().unwrap(), *value, "{kind}");
+        }
+    }
+
+    #[test]
+    fn test_ibc_event_to_abci_matches_ibc_go() {
+        assert_abci_layout(
+            IbcEvent::CreateClient(ClientEvents::CreateClient::new(
+                "07-tendermint-0".parse().unwrap(),
+                ClientType::new("07-tendermint").unwrap(),
+                Height::new(0, 10).unwrap(),
+            )),
+            "create_client",
+            &[
+                ("client_id", "07-tendermint-0"),
+                ("client_type", "07-tendermint"),
+                ("consensus_height", "0-10"),
+            ],
+        );
+
+        assert_abci_layout(
+            IbcEvent::CloseInitChannel(ChannelEvents::CloseInit::new(
+                PortId::transfer(),
+                ChannelId::zero(),
+                PortId::transfer(),
+                ChannelId::new(1),
+                ConnectionId::zero(),
+            )),
+            "channel_close_init",
+            &[
+                ("port_id", "transfer"),
+                ("channel_id", "channel-0"),
+                ("counterparty_port_id", "transfer"),
+                ("counterparty_channel_id", "channel-1"),
+                ("connection_id", "connection-0"),
+            ],
+        );
+
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        assert_abci_layout(
+            IbcEvent::SendPacket(ChannelEvents::SendPacket::new(
+                packet,
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            "send_packet",
+            &[
+                ("packet_data", "data"),
+                ("packet_data_hex", "64617461"),
+                ("packet_timeout_height", "0-0"),
+                ("packet_timeout_timestamp", "0"),
+                ("packet_sequence", "1"),
+                ("packet_src_port", "transfer"),
+                ("packet_src_channel", "channel-0"),
+                ("packet_dst_port", "transfer"),
+                ("packet_dst_channel", "channel-1"),
+                ("packet_channel_ordering", "ORDER_UNORDERED"),
+                ("packet_connection", "connection-0"),
+            ],
+        );
+
+        assert_abci_layout(
+            IbcEvent::Message(MessageEvent::Channel),
+            "message",
+            &[("module", "ibc_channel")],
+        );
+    }
+
+    #[test]
+    fn test_event_to_bytes_is_canonical() {
+        let module_event = |attributes: Vec<ModuleEventAttribute>| {
+            IbcEvent::Module(ModuleEvent {
+                kind: "transfer".to_string(),
+                attributes,
+            })
+        };
+
+        let event = module_event(vec![
+            ("sender", "alice").into(),
+            ("receiver", "bob").into(),
+            ("amount", "100").into(),
+        ]);
+        let same_event = module_event(vec![
+            ("amount", "100").into(),
+            ("sender", "alice").into(),
+            ("receiver", "bob").into(),
+        ]);
+        let other_event = module_event(vec![
+            ("sender", "bob").into(),
+            ("receiver", "alice").into(),
+            ("amount", "100").into(),
+        ]);
+
+        let bytes = event.to_bytes().expect("valid event");
+
+        assert_eq!(bytes, same_event.to_bytes().expect("valid event"));
+        assert_ne!(bytes, other_event.to_bytes().expect("valid event"));
+
+        let message_event = IbcEvent::Message(MessageEvent::Channel);
+        assert_eq!(
+            message_event.to_bytes().expect("valid event"),
+            IbcEvent::Message(MessageEvent::Channel)
+                .to_bytes()
+                .expect("valid event")
+        );
+    }
+}
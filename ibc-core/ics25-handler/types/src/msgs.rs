@@ -1,16 +1,20 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgChannelUpgradeAck,
+    MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm, MsgChannelUpgradeInit,
+    MsgChannelUpgradeOpen, MsgChannelUpgradeTimeout, MsgChannelUpgradeTry, MsgRecvPacket,
+    MsgTimeout, MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL,
+    CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL,
+    CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL,
+    CHAN_UPGRADE_ACK_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_INIT_TYPE_URL, CHAN_UPGRADE_OPEN_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    CHAN_UPGRADE_TRY_TYPE_URL, RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
     ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
-    UPGRADE_CLIENT_TYPE_URL,
+    CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL,
+    UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
@@ -19,14 +23,20 @@ use ibc_core_connection_types::msgs::{
 };
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
+///
+/// With the `serde` feature, this and every message it wraps serialize with
+/// their field names as-is and byte fields (proofs, packet data) as uppercase
+/// hex strings, so that a serialized message deserializes to an equal one.
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub enum MsgEnvelope {
@@ -36,6 +46,114 @@ pub enum MsgEnvelope {
     Packet(PacketMsg),
 }
 
+#[allow(deprecated)]
+impl MsgEnvelope {
+    /// Returns the protobuf type URL of the wrapped message.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(_) => CREATE_CLIENT_TYPE_URL,
+                ClientMsg::UpdateClient(_) => UPDATE_CLIENT_TYPE_URL,
+                ClientMsg::Misbehaviour(_) => SUBMIT_MISBEHAVIOUR_TYPE_URL,
+                ClientMsg::UpgradeClient(_) => UPGRADE_CLIENT_TYPE_URL,
+                ClientMsg::RecoverClient(_) => RECOVER_CLIENT_TYPE_URL,
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(_) => CONN_OPEN_INIT_TYPE_URL,
+                ConnectionMsg::OpenTry(_) => CONN_OPEN_TRY_TYPE_URL,
+                ConnectionMsg::OpenAck(_) => CONN_OPEN_ACK_TYPE_URL,
+                ConnectionMsg::OpenConfirm(_) => CONN_OPEN_CONFIRM_TYPE_URL,
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(_) => CHAN_OPEN_INIT_TYPE_URL,
+                ChannelMsg::OpenTry(_) => CHAN_OPEN_TRY_TYPE_URL,
+                ChannelMsg::OpenAck(_) => CHAN_OPEN_ACK_TYPE_URL,
+                ChannelMsg::OpenConfirm(_) => CHAN_OPEN_CONFIRM_TYPE_URL,
+                ChannelMsg::CloseInit(_) => CHAN_CLOSE_INIT_TYPE_URL,
+                ChannelMsg::CloseConfirm(_) => CHAN_CLOSE_CONFIRM_TYPE_URL,
+                ChannelMsg::UpgradeInit(_) => CHAN_UPGRADE_INIT_TYPE_URL,
+                ChannelMsg::UpgradeTry(_) => CHAN_UPGRADE_TRY_TYPE_URL,
+                ChannelMsg::UpgradeAck(_) => CHAN_UPGRADE_ACK_TYPE_URL,
+                ChannelMsg::UpgradeConfirm(_) => CHAN_UPGRADE_CONFIRM_TYPE_URL,
+                ChannelMsg::UpgradeOpen(_) => CHAN_UPGRADE_OPEN_TYPE_URL,
+                ChannelMsg::UpgradeCancel(_) => CHAN_UPGRADE_CANCEL_TYPE_URL,
+                ChannelMsg::UpgradeTimeout(_) => CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(_) => RECV_PACKET_TYPE_URL,
+                PacketMsg::Ack(_) => ACKNOWLEDGEMENT_TYPE_URL,
+                PacketMsg::Timeout(_) => TIMEOUT_TYPE_URL,
+                PacketMsg::TimeoutOnClose(_) => TIMEOUT_ON_CLOSE_TYPE_URL,
+            },
+        }
+    }
+
+    /// Returns the signer of the wrapped message.
+    pub fn signer(&self) -> &Signer {
+        match self {
+            MsgEnvelope::Client(msg) => match msg {
+                ClientMsg::CreateClient(msg) => &msg.signer,
+                ClientMsg::UpdateClient(msg) => &msg.signer,
+                ClientMsg::Misbehaviour(msg) => &msg.signer,
+                ClientMsg::UpgradeClient(msg) => &msg.signer,
+                ClientMsg::RecoverClient(msg) => &msg.signer,
+            },
+            MsgEnvelope::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(msg) => &msg.signer,
+                ConnectionMsg::OpenTry(msg) => &msg.signer,
+                ConnectionMsg::OpenAck(msg) => &msg.signer,
+                ConnectionMsg::OpenConfirm(msg) => &msg.signer,
+            },
+            MsgEnvelope::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(msg) => &msg.signer,
+                ChannelMsg::OpenTry(msg) => &msg.signer,
+                ChannelMsg::OpenAck(msg) => &msg.signer,
+                ChannelMsg::OpenConfirm(msg) => &msg.signer,
+                ChannelMsg::CloseInit(msg) => &msg.signer,
+                ChannelMsg::CloseConfirm(msg) => &msg.signer,
+                ChannelMsg::UpgradeInit(msg) => &msg.signer,
+                ChannelMsg::UpgradeTry(msg) => &msg.signer,
+                ChannelMsg::UpgradeAck(msg) => &msg.signer,
+                ChannelMsg::UpgradeConfirm(msg) => &msg.signer,
+                ChannelMsg::UpgradeOpen(msg) => &msg.signer,
+                ChannelMsg::UpgradeCancel(msg) => &msg.signer,
+                ChannelMsg::UpgradeTimeout(msg) => &msg.signer,
+            },
+            MsgEnvelope::Packet(msg) => match msg {
+                PacketMsg::Recv(msg) => &msg.signer,
+                PacketMsg::Ack(msg) => &msg.signer,
+                PacketMsg::Timeout(msg) => &msg.signer,
+                PacketMsg::TimeoutOnClose(msg) => &msg.signer,
+            },
+        }
+    }
+}
+
+/// Identifies a message that has been executed, as handed to hosts once its
+/// execution completes.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSummary {
+    /// The protobuf type URL of the message
+    pub type_url: String,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl From<&MsgEnvelope> for MsgSummary {
+    fn from(msg: &MsgEnvelope) -> Self {
+        Self {
+            type_url: msg.type_url().to_string(),
+            signer: msg.signer().clone(),
+        }
+    }
+}
+
 #[allow(deprecated)]
 impl TryFrom<Any> for MsgEnvelope {
     type Error = RouterError;
@@ -167,6 +285,67 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            CHAN_UPGRADE_INIT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeInit::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeInit(domain_msg)))
+            }
+            CHAN_UPGRADE_TRY_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeTry::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTry(domain_msg)))
+            }
+            CHAN_UPGRADE_ACK_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeAck::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeAck(domain_msg)))
+            }
+            CHAN_UPGRADE_CONFIRM_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeConfirm(domain_msg)))
+            }
+            CHAN_UPGRADE_OPEN_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeOpen::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeOpen(domain_msg)))
+            }
+            CHAN_UPGRADE_CANCEL_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeCancel::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeCancel(domain_msg)))
+            }
+            CHAN_UPGRADE_TIMEOUT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTimeout(domain_msg)))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
                 let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
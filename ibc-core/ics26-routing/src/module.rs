@@ -118,6 +118,108 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Called when an upgrade of a channel bound to this module is initiated,
+    /// returning the version to propose to the counterparty.
+    ///
+    /// Modules have to opt into channel upgrades; by default they are rejected.
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    /// Called when the counterparty proposes an upgrade of a channel bound to
+    /// this module, returning the version this module agrees to.
+    ///
+    /// Modules have to opt into channel upgrades; by default they are rejected.
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    /// Called when the counterparty agrees to an upgrade this module
+    /// initiated, with the version the counterparty module agreed to.
+    ///
+    /// Modules have to opt into channel upgrades; by default they are rejected.
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    /// Called once the upgrade of a channel bound to this module completed,
+    /// with the parameters the channel now runs with.
+    ///
+    /// The counterparty may already have switched to the new parameters, so
+    /// this callback cannot fail. Defaults to a no-op.
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> ModuleExtras {
+        ModuleExtras::empty()
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
@@ -158,4 +260,14 @@ pub trait Module: Debug {
         packet: &Packet,
         relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>);
+
+    /// Returns whether this module is bound to the given port.
+    ///
+    /// Callbacks for a port are only dispatched to the module the router
+    /// binds it to, and are rejected if that module reports not being bound to
+    /// the port. Modules that know their ports can implement this to guard
+    /// against a misconfigured router; by default, the router is trusted.
+    fn is_bound_to_port(&self, _port_id: &PortId) -> bool {
+        true
+    }
 }
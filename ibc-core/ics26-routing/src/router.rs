@@ -2,6 +2,7 @@
 
 use ibc_core_host_types::identifiers::PortId;
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
 
@@ -15,4 +16,15 @@ pub trait Router {
 
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns the ids of all the registered modules.
+    fn module_ids(&self) -> Box<dyn Iterator<Item = &ModuleId> + '_>;
+
+    /// Returns all the ports bound to a module.
+    fn bound_ports(&self) -> Box<dyn Iterator<Item = &PortId> + '_>;
+
+    /// Returns true if a module is registered against the specified `ModuleId`
+    fn has_route(&self, module_id: &ModuleId) -> bool {
+        self.get_route(module_id).is_some()
+    }
 }
@@ -11,8 +11,10 @@ pub enum RouterError {
     MalformedMessageBytes { reason: String },
     /// port `{port_id}` is unknown
     UnknownPort { port_id: PortId },
-    /// module not found
-    ModuleNotFound,
+    /// no module found for port `{port_id}`
+    ModuleNotFound { port_id: PortId },
+    /// the module routed to for port `{port_id}` is not bound to it
+    PortNotBound { port_id: PortId },
 }
 
 #[cfg(feature = "std")]
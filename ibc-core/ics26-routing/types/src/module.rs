@@ -71,4 +71,27 @@ impl ModuleExtras {
             log: Vec::new(),
         }
     }
+
+    /// Appends the given event to the extras.
+    pub fn with_event(mut self, event: impl Into<ModuleEvent>) -> Self {
+        self.events.push(event.into());
+        self
+    }
+
+    /// Appends the given log message to the extras.
+    pub fn with_log(mut self, log: impl Into<String>) -> Self {
+        self.log.push(log.into());
+        self
+    }
+
+    /// Combines the extras with `other`, whose events and logs come after
+    /// these ones.
+    ///
+    /// Useful to middleware that wrap an inner module, in order to combine
+    /// their own extras with those of the inner module.
+    pub fn merge(mut self, other: ModuleExtras) -> Self {
+        self.events.extend(other.events);
+        self.log.extend(other.log);
+        self
+    }
 }
@@ -23,6 +23,12 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {client_type(cs)},
         imports,
     );
+    let chain_id_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {chain_id(cs)},
+        imports,
+    );
     let latest_height_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -62,6 +68,7 @@ pub(crate) fn impl_ClientStateCommon(
     let CommitmentProofBytes = imports.commitment_proof_bytes();
     let ClientStateCommon = imports.client_state_common();
     let ClientType = imports.client_type();
+    let ChainId = imports.chain_id();
     let ClientError = imports.client_error();
     let Height = imports.height();
     let Path = imports.path();
@@ -79,6 +86,12 @@ pub(crate) fn impl_ClientStateCommon(
                 }
             }
 
+            fn chain_id(&self) -> Option<#ChainId> {
+                match self {
+                    #(#chain_id_impl),*
+                }
+            }
+
             fn latest_height(&self) -> #Height {
                 match self {
                     #(#latest_height_impl),*
@@ -80,6 +80,11 @@ impl Imports {
         quote! {#Prefix::host::types::identifiers::ClientType}
     }
 
+    pub fn chain_id(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::host::types::identifiers::ChainId}
+    }
+
     pub fn client_error(&self) -> TokenStream {
         let prefix = self.prefix();
         quote! {#prefix::client::types::error::ClientError}
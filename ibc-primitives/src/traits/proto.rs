@@ -22,6 +22,18 @@ where
             value: self.encode_vec(),
         }
     }
+
+    /// Appends the Protobuf encoding of `self` to `buf`, reserving the exact
+    /// space it needs first.
+    ///
+    /// Unlike [`Protobuf::encode_vec`], which allocates a new `Vec` per
+    /// value, this lets callers encoding many values reuse a single buffer.
+    fn encode_to_buf(self, buf: &mut Vec<u8>) {
+        let raw = P::from(self);
+        buf.reserve(raw.encoded_len());
+        // encoding only fails if the buffer is too small, which a `Vec` never is
+        let _ = raw.encode(buf);
+    }
 }
 
 impl<T, P> ToProto<P> for T
@@ -1,4 +1,7 @@
-use derive_more::Display;
+use core::str::FromStr;
+
+use displaydoc::Display;
+use subtle_encoding::bech32;
 
 use crate::prelude::*;
 
@@ -17,17 +20,133 @@ use crate::prelude::*;
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
 pub struct Signer(String);
 
+impl Signer {
+    /// Checks that the signer is a bech32 address with the given
+    /// human-readable part.
+    ///
+    /// The `Signer` type itself accepts any string, so hosts that only accept
+    /// bech32 accounts can call this from their
+    /// `ValidationContext::validate_message_signer` implementation.
+    pub fn validate_bech32(&self, hrp: &str) -> Result<(), SignerError> {
+        if self.0.is_empty() {
+            return Err(SignerError::Empty);
+        }
+
+        let (actual_hrp, _) = bech32::decode(&self.0).map_err(|e| SignerError::InvalidBech32 {
+            signer: self.0.clone(),
+            reason: e.to_string(),
+        })?;
+
+        if actual_hrp != hrp {
+            return Err(SignerError::MismatchedPrefix {
+                expected: hrp.to_string(),
+                actual: actual_hrp,
+            });
+        }
+
+        Ok(())
+    }
+}
+
 impl From<String> for Signer {
     fn from(s: String) -> Self {
         Self(s)
     }
 }
 
+/// Parses a signer, rejecting empty strings and strings containing whitespace
+/// or control characters. No address format is enforced.
+impl FromStr for Signer {
+    type Err = SignerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(SignerError::Empty);
+        }
+
+        if let Some(c) = s.chars().find(|c| c.is_whitespace() || c.is_control()) {
+            return Err(SignerError::Malformed {
+                reason: format!("contains invalid character {c:?}"),
+            });
+        }
+
+        Ok(Self(s.to_string()))
+    }
+}
+
 impl AsRef<str> for Signer {
     fn as_ref(&self) -> &str {
         self.0.as_str()
     }
 }
+
+#[derive(Debug, Display)]
+pub enum SignerError {
+    /// empty signer
+    Empty,
+    /// malformed signer: `{reason}`
+    Malformed { reason: String },
+    /// signer `{signer}` is not a valid bech32 address: `{reason}`
+    InvalidBech32 { signer: String, reason: String },
+    /// mismatched bech32 prefix: expected `{expected}`, actual `{actual}`
+    MismatchedPrefix { expected: String, actual: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BECH32_ACCOUNT: &str = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng";
+
+    #[test]
+    fn test_validate_bech32() {
+        let signer = Signer::from(BECH32_ACCOUNT.to_string());
+
+        assert!(signer.validate_bech32("cosmos").is_ok());
+        assert!(matches!(
+            signer.validate_bech32("osmo"),
+            Err(SignerError::MismatchedPrefix { expected, actual })
+                if expected == "osmo" && actual == "cosmos"
+        ));
+
+        assert!(matches!(
+            Signer::from(String::new()).validate_bech32("cosmos"),
+            Err(SignerError::Empty)
+        ));
+
+        for malformed in [
+            "0CDA3F47EF3C4906693B170EF650EB968C5F4B2C",
+            "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7nh",
+        ] {
+            assert!(matches!(
+                Signer::from(malformed.to_string()).validate_bech32("cosmos"),
+                Err(SignerError::InvalidBech32 { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_signer() {
+        assert_eq!(
+            BECH32_ACCOUNT.parse::<Signer>().unwrap(),
+            Signer::from(BECH32_ACCOUNT.to_string())
+        );
+
+        for empty in ["", "  "] {
+            assert!(matches!(empty.parse::<Signer>(), Err(SignerError::Empty)));
+        }
+
+        for malformed in ["cosmos1 wxeyh7", "cosmos1wxeyh7\n", "cosmos1\0"] {
+            assert!(matches!(
+                malformed.parse::<Signer>(),
+                Err(SignerError::Malformed { .. })
+            ));
+        }
+    }
+}
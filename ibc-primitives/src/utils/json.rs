@@ -0,0 +1,42 @@
+//! JSON encoding utilities.
+
+use crate::prelude::*;
+
+/// Escapes the characters that Go's `encoding/json` escapes by default, i.e.
+/// `<`, `>`, `&`, U+2028 and U+2029, in the given JSON.
+///
+/// These characters can only occur inside JSON strings, so escaping them
+/// leaves the structure intact. Used to reproduce the bytes that Go chains
+/// commit to when encoding packet data as JSON.
+pub fn escape_html(json: &str) -> String {
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html(r#"{"memo":"<a&b>"}"#),
+            r#"{"memo":"\u003ca\u0026b\u003e"}"#
+        );
+        assert_eq!(
+            escape_html("{\"memo\":\"a\u{2028}b\u{2029}\"}"),
+            r#"{"memo":"a\u2028b\u2029"}"#
+        );
+        assert_eq!(escape_html(r#"{"memo":"plain"}"#), r#"{"memo":"plain"}"#);
+    }
+}
@@ -1,4 +1,6 @@
 //! Contains various internally-used utilities.
+pub mod json;
 pub mod pretty;
 
+pub use json::*;
 pub use pretty::*;
@@ -0,0 +1,3 @@
+#[cfg(feature = "std")]
+pub mod nft_transfer;
+pub mod transfer;
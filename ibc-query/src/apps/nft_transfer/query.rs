@@ -0,0 +1,28 @@
+//! Provides utility functions for querying the NFT transfer application.
+
+use ibc::apps::nft_transfer::context::NftTransferValidationContext;
+
+use super::{
+    QueryClassHashRequest, QueryClassHashResponse, QueryTokenEscrowRequest,
+    QueryTokenEscrowResponse,
+};
+
+/// Queries for the hash of the given class trace, as used in the hashed class
+/// IDs of received NFT classes.
+pub fn query_class_hash(request: &QueryClassHashRequest) -> QueryClassHashResponse {
+    QueryClassHashResponse::new(request.trace.class_hash())
+}
+
+/// Queries for the port and channel through which the given NFT is escrowed,
+/// if any.
+pub fn query_token_escrow<T>(
+    transfer_ctx: &T,
+    request: &QueryTokenEscrowRequest,
+) -> QueryTokenEscrowResponse
+where
+    T: NftTransferValidationContext,
+{
+    QueryTokenEscrowResponse::new(
+        transfer_ctx.token_escrow_info(&request.class_id, &request.token_id),
+    )
+}
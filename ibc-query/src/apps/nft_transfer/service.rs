@@ -0,0 +1,55 @@
+//! [`NftTransferQueryService`](NftTransferQueryService) takes a generic `I` to
+//! store the NFT transfer context that implements
+//! [`NftTransferValidationContext`](NftTransferValidationContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use ibc::apps::nft_transfer::context::NftTransferValidationContext;
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_class_hash, query_token_escrow, QueryClassHashRequest, QueryClassHashResponse,
+    QueryTokenEscrowRequest, QueryTokenEscrowResponse,
+};
+
+/// Serves the NFT transfer queries, for which `ibc-proto` defines no RPC, so
+/// that hosts can expose them from their own gRPC services.
+///
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct NftTransferQueryService<I>
+where
+    I: NftTransferValidationContext + Send + Sync + 'static,
+{
+    transfer_context: I,
+}
+
+impl<I> NftTransferQueryService<I>
+where
+    I: NftTransferValidationContext + Send + Sync + 'static,
+{
+    /// The parameter `transfer_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(transfer_context: I) -> Self {
+        Self { transfer_context }
+    }
+
+    /// Serves the hash of a class trace.
+    pub async fn class_hash(
+        &self,
+        request: Request<QueryClassHashRequest>,
+    ) -> Result<Response<QueryClassHashResponse>, Status> {
+        Ok(Response::new(query_class_hash(request.get_ref())))
+    }
+
+    /// Serves the port and channel through which an NFT is escrowed, if any.
+    pub async fn token_escrow(
+        &self,
+        request: Request<QueryTokenEscrowRequest>,
+    ) -> Result<Response<QueryTokenEscrowResponse>, Status> {
+        Ok(Response::new(query_token_escrow(
+            &self.transfer_context,
+            request.get_ref(),
+        )))
+    }
+}
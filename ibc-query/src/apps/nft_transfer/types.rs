@@ -0,0 +1,57 @@
+//! Contains the RPC method request and response domain types for the NFT
+//! transfer application.
+
+use ibc::apps::nft_transfer::types::{PrefixedClassId, TokenId};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+/// Defines the RPC method request type for querying the hash of a class
+/// trace.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassHashRequest {
+    pub trace: PrefixedClassId,
+}
+
+/// Defines the RPC method response type when querying the hash of a class
+/// trace.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClassHashResponse {
+    pub hash: String,
+}
+
+impl QueryClassHashResponse {
+    pub fn new(hash: String) -> Self {
+        Self { hash }
+    }
+}
+
+/// Defines the RPC method request type for querying the escrow status of an
+/// NFT.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTokenEscrowRequest {
+    pub class_id: PrefixedClassId,
+    pub token_id: TokenId,
+}
+
+/// Defines the RPC method response type when querying the escrow status of an
+/// NFT.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTokenEscrowResponse {
+    /// The port and channel through which the NFT is escrowed, or `None` if
+    /// it isn't escrowed.
+    pub escrow: Option<(PortId, ChannelId)>,
+}
+
+impl QueryTokenEscrowResponse {
+    pub fn new(escrow: Option<(PortId, ChannelId)>) -> Self {
+        Self { escrow }
+    }
+}
@@ -0,0 +1,10 @@
+//! Provides utility functions and domain types for querying the state of the
+//! ICS-20 token transfer application.
+
+mod query;
+mod service;
+mod types;
+
+pub use query::*;
+pub use service::*;
+pub use types::*;
@@ -0,0 +1,128 @@
+//! Provides utility functions for querying the token transfer application.
+
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::{PrefixedDenom, IBC_DENOM_PREFIX};
+use ibc::core::primitives::prelude::*;
+
+use super::{
+    QueryDenomSupplyRequest, QueryDenomSupplyResponse, QueryDenomTraceRequest,
+    QueryDenomTraceResponse, QueryDenomTracesRequest, QueryDenomTracesResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+use crate::error::QueryError;
+
+/// Queries for the total supply of the given IBC voucher denom.
+pub fn query_denom_supply<T>(
+    transfer_ctx: &T,
+    request: &QueryDenomSupplyRequest,
+) -> Result<QueryDenomSupplyResponse, QueryError>
+where
+    T: TokenTransferValidationContext,
+{
+    let amount = transfer_ctx
+        .get_denom_supply(&request.denom)?
+        .ok_or_else(|| {
+            QueryError::unimplemented("the host chain does not track voucher supplies")
+        })?;
+
+    Ok(QueryDenomSupplyResponse::new(request.denom.clone(), amount))
+}
+
+/// Queries for the denom trace of the IBC voucher denom with the given hash.
+///
+/// The hash is matched case-insensitively and may be prefixed with `ibc/`, as
+/// in the denom of the voucher itself.
+pub fn query_denom_trace<T>(
+    transfer_ctx: &T,
+    request: &QueryDenomTraceRequest,
+) -> Result<QueryDenomTraceResponse, QueryError>
+where
+    T: TokenTransferValidationContext,
+{
+    let hash = strip_ibc_denom_prefix(&request.hash).unwrap_or(&request.hash);
+
+    Ok(QueryDenomTraceResponse::new(resolve_denom_trace(
+        transfer_ctx,
+        hash,
+    )?))
+}
+
+/// Queries for a page of the denom traces known to the host chain, ordered by
+/// their full trace.
+pub fn query_denom_traces<T>(
+    transfer_ctx: &T,
+    request: &QueryDenomTracesRequest,
+) -> Result<QueryDenomTracesResponse, QueryError>
+where
+    T: TokenTransferValidationContext,
+{
+    let mut denom_traces = transfer_ctx.denom_traces();
+    denom_traces.sort_by_cached_key(ToString::to_string);
+
+    let (denom_traces, pagination) = request
+        .pagination
+        .clone()
+        .unwrap_or_default()
+        .paginate(denom_traces)?;
+
+    Ok(QueryDenomTracesResponse::new(
+        denom_traces,
+        Some(pagination),
+    ))
+}
+
+/// Queries for the total amount of the given denom held in escrow across all
+/// channels.
+///
+/// An `ibc/{hash}` voucher denom is first resolved to its denom trace, which
+/// is what the escrow is tracked by. A denom that has never been escrowed,
+/// including a voucher denom whose hash matches no known denom trace, has a
+/// total of zero.
+pub fn query_total_escrow_for_denom<T>(
+    transfer_ctx: &T,
+    request: &QueryTotalEscrowForDenomRequest,
+) -> Result<QueryTotalEscrowForDenomResponse, QueryError>
+where
+    T: TokenTransferValidationContext,
+{
+    let hash = request
+        .denom
+        .trace_path
+        .is_empty()
+        .then(|| strip_ibc_denom_prefix(request.denom.base_denom.as_str()))
+        .flatten();
+
+    let denom = hash
+        .and_then(|hash| transfer_ctx.denom_trace(&hash.to_uppercase()))
+        .unwrap_or_else(|| request.denom.clone());
+
+    let amount = transfer_ctx.total_escrowed_amount(&denom)?.ok_or_else(|| {
+        QueryError::unimplemented("the host chain does not track escrow balances")
+    })?;
+
+    Ok(QueryTotalEscrowForDenomResponse::new(
+        request.denom.clone(),
+        amount,
+    ))
+}
+
+/// Returns the hash of an `ibc/{hash}` voucher denom, or `None` if the denom
+/// is not a voucher denom.
+fn strip_ibc_denom_prefix(denom: &str) -> Option<&str> {
+    denom
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .and_then(|hash| hash.strip_prefix('/'))
+}
+
+/// Looks up the denom trace with the given hash, matched case-insensitively.
+fn resolve_denom_trace<T>(transfer_ctx: &T, hash: &str) -> Result<PrefixedDenom, QueryError>
+where
+    T: TokenTransferValidationContext,
+{
+    let hash = hash.to_uppercase();
+
+    Ok(transfer_ctx
+        .denom_trace(&hash)
+        .ok_or(TokenTransferError::DenomTraceNotFound { hash })?)
+}
@@ -0,0 +1,110 @@
+//! [`TransferQueryService`](TransferQueryService) takes a generic `I` to store
+//! the token transfer context that implements
+//! [`TokenTransferValidationContext`](TokenTransferValidationContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+//!
+//! Queries the transfer `Query` service of `ibc-proto` has no RPC for, as
+//! ibc-go answers them from other modules, are served by inherent methods of
+//! the service instead, for hosts to expose themselves.
+
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc_proto::ibc::apps::transfer::v1::query_server::Query as TransferQuery;
+use ibc_proto::ibc::apps::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_denom_supply, query_denom_trace, query_denom_traces, query_total_escrow_for_denom,
+    QueryDenomSupplyRequest, QueryDenomSupplyResponse,
+};
+use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    transfer_context: I,
+}
+
+impl<I> TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    /// The parameter `transfer_context` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(transfer_context: I) -> Self {
+        Self { transfer_context }
+    }
+
+    /// Serves the total supply of an IBC voucher denom.
+    pub async fn denom_supply(
+        &self,
+        request: Request<QueryDenomSupplyRequest>,
+    ) -> Result<Response<QueryDenomSupplyResponse>, Status> {
+        let response = query_denom_supply(&self.transfer_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+}
+
+#[tonic::async_trait]
+impl<I> TransferQuery for TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    async fn denom_traces(
+        &self,
+        request: Request<QueryDenomTracesRequest>,
+    ) -> Result<Response<QueryDenomTracesResponse>, Status> {
+        query_denom_traces(&self.transfer_context, &request.into_domain())?.into_response()
+    }
+
+    async fn denom_trace(
+        &self,
+        request: Request<QueryDenomTraceRequest>,
+    ) -> Result<Response<QueryDenomTraceResponse>, Status> {
+        query_denom_trace(&self.transfer_context, &request.into_domain())?.into_response()
+    }
+
+    async fn params(
+        &self,
+        _request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        Err(Status::unimplemented(
+            "Querying Params is not supported yet",
+        ))
+    }
+
+    async fn denom_hash(
+        &self,
+        _request: Request<QueryDenomHashRequest>,
+    ) -> Result<Response<QueryDenomHashResponse>, Status> {
+        Err(Status::unimplemented(
+            "Querying DenomHash is not supported yet",
+        ))
+    }
+
+    async fn escrow_address(
+        &self,
+        _request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        Err(Status::unimplemented(
+            "Querying EscrowAddress is not supported yet",
+        ))
+    }
+
+    async fn total_escrow_for_denom(
+        &self,
+        request: Request<QueryTotalEscrowForDenomRequest>,
+    ) -> Result<Response<QueryTotalEscrowForDenomResponse>, Status> {
+        query_total_escrow_for_denom(&self.transfer_context, &request.try_into_domain()?)?
+            .into_response()
+    }
+}
@@ -0,0 +1,172 @@
+//! Contains the RPC method request and response domain types for the token
+//! transfer application.
+
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::apps::transfer::v1::{
+    QueryDenomTraceRequest as RawQueryDenomTraceRequest,
+    QueryDenomTraceResponse as RawQueryDenomTraceResponse,
+    QueryDenomTracesRequest as RawQueryDenomTracesRequest,
+    QueryDenomTracesResponse as RawQueryDenomTracesResponse,
+    QueryTotalEscrowForDenomRequest as RawQueryTotalEscrowForDenomRequest,
+    QueryTotalEscrowForDenomResponse as RawQueryTotalEscrowForDenomResponse,
+};
+
+use crate::error::QueryError;
+use crate::types::{PageRequest, PageResponse};
+
+/// Defines the RPC method request type for querying the total supply of an IBC
+/// voucher denom.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomSupplyRequest {
+    pub denom: PrefixedDenom,
+}
+
+/// Defines the RPC method response type when querying the total supply of an
+/// IBC voucher denom.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomSupplyResponse {
+    pub amount: PrefixedCoin,
+}
+
+impl QueryDenomSupplyResponse {
+    pub fn new(denom: PrefixedDenom, amount: Amount) -> Self {
+        Self {
+            amount: PrefixedCoin { denom, amount },
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the denom trace of an IBC
+/// voucher denom.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTraceRequest {
+    /// The hash of the denom trace, optionally prefixed with `ibc/`.
+    pub hash: String,
+}
+
+impl From<RawQueryDenomTraceRequest> for QueryDenomTraceRequest {
+    fn from(request: RawQueryDenomTraceRequest) -> Self {
+        Self { hash: request.hash }
+    }
+}
+
+/// Defines the RPC method response type when querying the denom trace of an
+/// IBC voucher denom.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTraceResponse {
+    pub denom_trace: PrefixedDenom,
+}
+
+impl QueryDenomTraceResponse {
+    pub fn new(denom_trace: PrefixedDenom) -> Self {
+        Self { denom_trace }
+    }
+}
+
+impl From<QueryDenomTraceResponse> for RawQueryDenomTraceResponse {
+    fn from(response: QueryDenomTraceResponse) -> Self {
+        Self {
+            denom_trace: Some(response.denom_trace.into()),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying all the denom traces known
+/// to the host chain.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTracesRequest {
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<RawQueryDenomTracesRequest> for QueryDenomTracesRequest {
+    fn from(request: RawQueryDenomTracesRequest) -> Self {
+        Self {
+            pagination: request.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying all the denom traces
+/// known to the host chain.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryDenomTracesResponse {
+    pub denom_traces: Vec<PrefixedDenom>,
+    pub pagination: Option<PageResponse>,
+}
+
+impl QueryDenomTracesResponse {
+    pub fn new(denom_traces: Vec<PrefixedDenom>, pagination: Option<PageResponse>) -> Self {
+        Self {
+            denom_traces,
+            pagination,
+        }
+    }
+}
+
+impl From<QueryDenomTracesResponse> for RawQueryDenomTracesResponse {
+    fn from(response: QueryDenomTracesResponse) -> Self {
+        Self {
+            denom_traces: response.denom_traces.into_iter().map(Into::into).collect(),
+            pagination: response.pagination.map(Into::into),
+        }
+    }
+}
+
+/// Defines the RPC method request type for querying the total amount of a
+/// denom held in escrow across all channels.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTotalEscrowForDenomRequest {
+    pub denom: PrefixedDenom,
+}
+
+impl TryFrom<RawQueryTotalEscrowForDenomRequest> for QueryTotalEscrowForDenomRequest {
+    type Error = QueryError;
+
+    fn try_from(request: RawQueryTotalEscrowForDenomRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom: request.denom.parse().map_err(|e| {
+                QueryError::invalid_argument(format!("invalid denom `{}`: {e}", request.denom))
+            })?,
+        })
+    }
+}
+
+/// Defines the RPC method response type when querying the total amount of a
+/// denom held in escrow across all channels.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryTotalEscrowForDenomResponse {
+    pub amount: PrefixedCoin,
+}
+
+impl QueryTotalEscrowForDenomResponse {
+    pub fn new(denom: PrefixedDenom, amount: Amount) -> Self {
+        Self {
+            amount: PrefixedCoin { denom, amount },
+        }
+    }
+}
+
+impl From<QueryTotalEscrowForDenomResponse> for RawQueryTotalEscrowForDenomResponse {
+    fn from(response: QueryTotalEscrowForDenomResponse) -> Self {
+        Self {
+            amount: Some(response.amount.into()),
+        }
+    }
+}
@@ -1,30 +1,37 @@
 //! Provides utility functions for querying IBC channel states.
 
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::host::types::identifiers::Sequence;
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
     ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
-use ibc::primitives::prelude::format;
+use ibc::primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 
 use super::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
-    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
-    QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
-    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
-    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
-    QueryNextSequenceSendRequest, QueryNextSequenceSendResponse, QueryPacketAcknowledgementRequest,
-    QueryPacketAcknowledgementResponse, QueryPacketAcknowledgementsRequest,
-    QueryPacketAcknowledgementsResponse, QueryPacketCommitmentRequest,
-    QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
-    QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelFlowStatusRequest, QueryChannelFlowStatusResponse, QueryChannelParamsRequest,
+    QueryChannelParamsResponse, QueryChannelRequest, QueryChannelResponse, QueryChannelsRequest,
+    QueryChannelsResponse, QueryCommitmentSequenceBoundsRequest,
+    QueryCommitmentSequenceBoundsResponse, QueryConnectionChannelsRequest,
+    QueryConnectionChannelsResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryNextSequenceSendRequest, QueryNextSequenceSendResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    QueryPortChannelsRequest, QueryPortChannelsResponse, QueryUnreceivedAcksRequest,
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
 };
 use crate::core::client::IdentifiedClientState;
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::utils::{resolve_query_height, QueryHeight};
 
 /// Queries for a specific IBC channel by the given channel and port ids and
 /// returns the channel end with the associated proof.
@@ -33,15 +40,16 @@ pub fn query_channel<I>(
     request: &QueryChannelRequest,
 ) -> Result<QueryChannelResponse, QueryError>
 where
-    I: ValidationContext + ProvableContext,
+    I: QueryContext,
 {
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
+    let (channel_end, proof_height) = match resolve_query_height(ibc_ctx, request.query_height)? {
+        QueryHeight::Latest(height) => (ibc_ctx.channel_end(&channel_end_path)?, height),
+        QueryHeight::Historical(height) => (
+            ibc_ctx.channel_end_at_height(&channel_end_path, height)?,
+            height,
+        ),
     };
 
     let proof = ibc_ctx
@@ -101,6 +109,73 @@ where
     ))
 }
 
+/// Queries for the ids of all channels bound to a given port
+pub fn query_port_channels<I>(
+    ibc_ctx: &I,
+    request: &QueryPortChannelsRequest,
+) -> Result<QueryPortChannelsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_ids = ibc_ctx.channels_by_port(&request.port_id)?;
+
+    Ok(QueryPortChannelsResponse::new(
+        channel_ids,
+        ibc_ctx.host_height()?,
+    ))
+}
+
+/// Queries whether packet flow on a channel has been paused by the host
+/// operator
+pub fn query_channel_flow_status<I>(
+    ibc_ctx: &I,
+    request: &QueryChannelFlowStatusRequest,
+) -> Result<QueryChannelFlowStatusResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+
+    // Fail on unknown channels rather than reporting them as flowing
+    ibc_ctx.channel_end(&channel_end_path)?;
+
+    Ok(QueryChannelFlowStatusResponse::new(
+        ibc_ctx.is_channel_paused(&channel_end_path)?,
+        ibc_ctx.host_height()?,
+    ))
+}
+
+/// Queries for the lowest and highest sequences with an outstanding packet
+/// commitment on a channel, so that relayers can size their batches
+pub fn query_commitment_sequence_bounds<I>(
+    ibc_ctx: &I,
+    request: &QueryCommitmentSequenceBoundsRequest,
+) -> Result<QueryCommitmentSequenceBoundsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+
+    Ok(QueryCommitmentSequenceBoundsResponse::new(
+        ibc_ctx.commitment_sequence_bounds(&channel_end_path)?,
+        ibc_ctx.host_height()?,
+    ))
+}
+
+/// Queries for the parameters of the channel module, such as the hash
+/// algorithm used for packet commitments
+pub fn query_channel_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryChannelParamsRequest,
+) -> Result<QueryChannelParamsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(QueryChannelParamsResponse::new(
+        ibc_ctx.commitment_hasher().algorithm(),
+    ))
+}
+
 /// Queries for the client state associated with a channel by the given channel
 /// and port ids
 pub fn query_channel_client_state<I>(
@@ -218,17 +293,21 @@ pub fn query_packet_commitment<I>(
     request: &QueryPacketCommitmentRequest,
 ) -> Result<QueryPacketCommitmentResponse, QueryError>
 where
-    I: ValidationContext + ProvableContext,
+    I: QueryContext,
 {
     let commitment_path =
         CommitmentPath::new(&request.port_id, &request.channel_id, request.sequence);
 
-    let packet_commitment_data = ibc_ctx.get_packet_commitment(&commitment_path)?;
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let (packet_commitment_data, proof_height) =
+        match resolve_query_height(ibc_ctx, request.query_height)? {
+            QueryHeight::Latest(height) => {
+                (ibc_ctx.get_packet_commitment(&commitment_path)?, height)
+            }
+            QueryHeight::Historical(height) => (
+                ibc_ctx.packet_commitment_at_height(&commitment_path, height)?,
+                height,
+            ),
+        };
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::Commitment(commitment_path.clone()))
@@ -310,17 +389,22 @@ pub fn query_packet_acknowledgement<I>(
     request: &QueryPacketAcknowledgementRequest,
 ) -> Result<QueryPacketAcknowledgementResponse, QueryError>
 where
-    I: ValidationContext + ProvableContext,
+    I: QueryContext,
 {
     let acknowledgement_path =
         AckPath::new(&request.port_id, &request.channel_id, request.sequence);
 
-    let packet_acknowledgement_data = ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?;
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let (packet_acknowledgement_data, proof_height) =
+        match resolve_query_height(ibc_ctx, request.query_height)? {
+            QueryHeight::Latest(height) => (
+                ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?,
+                height,
+            ),
+            QueryHeight::Historical(height) => (
+                ibc_ctx.packet_acknowledgement_at_height(&acknowledgement_path, height)?,
+                height,
+            ),
+        };
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::Ack(acknowledgement_path.clone()))
@@ -382,7 +466,22 @@ where
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let unreceived_packets = ibc_ctx.unreceived_packets(&channel_end_path, sequences)?;
+    let unreceived_packets = match packet_ordering(ibc_ctx, &channel_end_path)? {
+        // Ordered channels don't write packet receipts: every sequence at or
+        // past the next receive sequence is unreceived.
+        PacketOrdering::Ordered => {
+            let seq_recv_path = SeqRecvPath::new(&request.port_id, &request.channel_id);
+            let next_sequence_recv = ibc_ctx.get_next_sequence_recv(&seq_recv_path)?;
+
+            sequences.filter(|seq| seq >= &next_sequence_recv).collect()
+        }
+        // A contiguous run of sequences, as relayers usually send, is resolved
+        // by a single range read of the channel receipts.
+        PacketOrdering::Unordered => match sequence_range(&request.packet_commitment_sequences) {
+            Some((start, end)) => ibc_ctx.unreceived_in_range(&channel_end_path, start, end)?,
+            None => ibc_ctx.unreceived_packets(&channel_end_path, sequences)?,
+        },
+    };
 
     Ok(QueryUnreceivedPacketsResponse::new(
         unreceived_packets,
@@ -402,6 +501,10 @@ where
 
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
+    // Acknowledgements are tracked the same way for both orderings, but the
+    // channel must still have an ordering we know how to handle.
+    packet_ordering(ibc_ctx, &channel_end_path)?;
+
     let unreceived_acks = ibc_ctx.unreceived_acks(&channel_end_path, sequences)?;
 
     Ok(QueryUnreceivedAcksResponse::new(
@@ -476,3 +579,46 @@ where
         proof_height,
     ))
 }
+
+/// Packet ordering of a channel, as understood by the packet queries.
+enum PacketOrdering {
+    Ordered,
+    Unordered,
+}
+
+/// Classifies the ordering of the given channel, so that all packet queries
+/// whose results depend on it dispatch through the same code path. Orderings
+/// that the queries don't know how to handle are rejected rather than being
+/// silently treated as unordered.
+fn packet_ordering<I>(
+    ibc_ctx: &I,
+    channel_end_path: &ChannelEndPath,
+) -> Result<PacketOrdering, QueryError>
+where
+    I: ValidationContext,
+{
+    let channel_end = ibc_ctx.channel_end(channel_end_path)?;
+
+    match channel_end.ordering() {
+        Order::Ordered => Ok(PacketOrdering::Ordered),
+        Order::Unordered => Ok(PacketOrdering::Unordered),
+        Order::None => Err(ChannelError::InvalidOrderType {
+            expected: "Channel ordering must be either ordered or unordered".to_string(),
+            actual: Order::None.to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Returns the bounds of `sequences` if it holds at least two sequences, in
+/// ascending order and without gaps.
+fn sequence_range(sequences: &[Sequence]) -> Option<(Sequence, Sequence)> {
+    if sequences.len() < 2 {
+        return None;
+    }
+
+    sequences
+        .windows(2)
+        .all(|pair| pair[0].value().checked_add(1) == Some(pair[1].value()))
+        .then(|| (sequences[0], sequences[sequences.len() - 1]))
+}
@@ -1,10 +1,12 @@
-//! [`ChannelQueryService`](ChannelQueryService) takes a generic `I` to store `ibc_context` that implements [`QueryContext`](QueryContext).
+//! [`ChannelQueryService`](ChannelQueryService) takes a generic `I` to store `ibc_context` that implements [`AsyncQueryContext`](AsyncQueryContext),
+//! which every [`QueryContext`](crate::core::context::QueryContext) does.
 //! `I` must be a type where writes from one thread are readable from another.
 //! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+//!
+//! Queries the channel `Query` service of `ibc-proto` has no RPC for are served
+//! by inherent methods of the service instead, for hosts to expose themselves.
 
-use ibc::core::host::ConsensusStateRef;
 use ibc::core::primitives::prelude::*;
-use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::query_server::Query as ChannelQuery;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
@@ -24,14 +26,13 @@ use ibc_proto::ibc::core::channel::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_channel, query_channel_client_state, query_channel_consensus_state, query_channels,
-    query_connection_channels, query_next_sequence_receive, query_next_sequence_send,
-    query_packet_acknowledgement, query_packet_acknowledgements, query_packet_commitment,
-    query_packet_commitments, query_packet_receipt, query_unreceived_acks,
-    query_unreceived_packets,
+    QueryChannelFlowStatusRequest, QueryChannelFlowStatusResponse,
+    QueryCommitmentSequenceBoundsRequest, QueryCommitmentSequenceBoundsResponse,
+    QueryPortChannelsRequest, QueryPortChannelsResponse,
 };
-use crate::core::context::QueryContext;
-use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
+use crate::core::context::AsyncQueryContext;
+use crate::core::port::{QueryPortRequest, QueryPortResponse};
+use crate::utils::{async_query_height_from_metadata, IntoDomain, IntoResponse, TryIntoDomain};
 
 // TODO(rano): currently the services don't support pagination, so we return all the results.
 
@@ -39,63 +40,148 @@ use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 pub struct ChannelQueryService<I>
 where
-    I: QueryContext + Send + Sync + 'static,
-    ConsensusStateRef<I>: Into<Any>,
+    I: AsyncQueryContext + 'static,
 {
     ibc_context: I,
 }
 
 impl<I> ChannelQueryService<I>
 where
-    I: QueryContext + Send + Sync + 'static,
-    ConsensusStateRef<I>: Into<Any>,
+    I: AsyncQueryContext + 'static,
 {
     /// The parameter `ibc_context` must be a type where writes from one thread are readable from another.
     /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
     pub fn new(ibc_context: I) -> Self {
         Self { ibc_context }
     }
+
+    /// Serves the id of the module bound to a port.
+    pub async fn port(
+        &self,
+        request: Request<QueryPortRequest>,
+    ) -> Result<Response<QueryPortResponse>, Status> {
+        let response = self.ibc_context.query_port(request.get_ref()).await?;
+
+        Ok(Response::new(response))
+    }
+
+    /// Serves the parameters of the channel module, such as the hash
+    /// algorithm used for packet commitments.
+    ///
+    /// Not to be confused with the
+    /// [`channel_params`](ChannelQuery::channel_params) RPC, which serves the
+    /// channel upgrade parameters.
+    pub async fn commitment_params(
+        &self,
+        request: Request<super::QueryChannelParamsRequest>,
+    ) -> Result<Response<super::QueryChannelParamsResponse>, Status> {
+        let response = self
+            .ibc_context
+            .query_channel_params(request.get_ref())
+            .await?;
+
+        Ok(Response::new(response))
+    }
+
+    /// Serves the ids of all channels bound to a port.
+    pub async fn port_channels(
+        &self,
+        request: Request<QueryPortChannelsRequest>,
+    ) -> Result<Response<QueryPortChannelsResponse>, Status> {
+        let response = self
+            .ibc_context
+            .query_port_channels(request.get_ref())
+            .await?;
+
+        Ok(Response::new(response))
+    }
+
+    /// Serves whether packet flow on a channel has been paused by the host
+    /// operator.
+    pub async fn channel_flow_status(
+        &self,
+        request: Request<QueryChannelFlowStatusRequest>,
+    ) -> Result<Response<QueryChannelFlowStatusResponse>, Status> {
+        let response = self
+            .ibc_context
+            .query_channel_flow_status(request.get_ref())
+            .await?;
+
+        Ok(Response::new(response))
+    }
+
+    /// Serves the lowest and highest sequences of a channel that still have a
+    /// packet commitment.
+    pub async fn commitment_sequence_bounds(
+        &self,
+        request: Request<QueryCommitmentSequenceBoundsRequest>,
+    ) -> Result<Response<QueryCommitmentSequenceBoundsResponse>, Status> {
+        let response = self
+            .ibc_context
+            .query_commitment_sequence_bounds(request.get_ref())
+            .await?;
+
+        Ok(Response::new(response))
+    }
 }
 
 #[tonic::async_trait]
 impl<I> ChannelQuery for ChannelQueryService<I>
 where
-    I: QueryContext + Send + Sync + 'static,
-    ConsensusStateRef<I>: Into<Any>,
+    I: AsyncQueryContext + 'static,
 {
     async fn channel(
         &self,
         request: Request<QueryChannelRequest>,
     ) -> Result<Response<QueryChannelResponse>, Status> {
-        query_channel(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        let query_height = async_query_height_from_metadata(&request, &self.ibc_context).await?;
+
+        let mut request: super::QueryChannelRequest = request.try_into_domain()?;
+        request.query_height = query_height.or(request.query_height);
+
+        self.ibc_context
+            .query_channel(&request)
+            .await?
+            .into_response()
     }
 
     async fn channels(
         &self,
         request: Request<QueryChannelsRequest>,
     ) -> Result<Response<QueryChannelsResponse>, Status> {
-        query_channels(&self.ibc_context, &request.into_domain())?.into_response()
+        self.ibc_context
+            .query_channels(&request.into_domain())
+            .await?
+            .into_response()
     }
 
     async fn connection_channels(
         &self,
         request: Request<QueryConnectionChannelsRequest>,
     ) -> Result<Response<QueryConnectionChannelsResponse>, Status> {
-        query_connection_channels(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_connection_channels(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn channel_client_state(
         &self,
         request: Request<QueryChannelClientStateRequest>,
     ) -> Result<Response<QueryChannelClientStateResponse>, Status> {
-        query_channel_client_state(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_channel_client_state(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn channel_consensus_state(
         &self,
         request: Request<QueryChannelConsensusStateRequest>,
     ) -> Result<Response<QueryChannelConsensusStateResponse>, Status> {
-        query_channel_consensus_state(&self.ibc_context, &request.try_into_domain()?)?
+        self.ibc_context
+            .query_channel_consensus_state(&request.try_into_domain()?)
+            .await?
             .into_response()
     }
 
@@ -103,28 +189,49 @@ where
         &self,
         request: Request<QueryPacketCommitmentRequest>,
     ) -> Result<Response<QueryPacketCommitmentResponse>, Status> {
-        query_packet_commitment(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        let query_height = async_query_height_from_metadata(&request, &self.ibc_context).await?;
+
+        let mut request: super::QueryPacketCommitmentRequest = request.try_into_domain()?;
+        request.query_height = query_height.or(request.query_height);
+
+        self.ibc_context
+            .query_packet_commitment(&request)
+            .await?
+            .into_response()
     }
 
     async fn packet_commitments(
         &self,
         request: Request<QueryPacketCommitmentsRequest>,
     ) -> Result<Response<QueryPacketCommitmentsResponse>, Status> {
-        query_packet_commitments(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_packet_commitments(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn packet_receipt(
         &self,
         request: Request<QueryPacketReceiptRequest>,
     ) -> Result<Response<QueryPacketReceiptResponse>, Status> {
-        query_packet_receipt(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_packet_receipt(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn packet_acknowledgement(
         &self,
         request: Request<QueryPacketAcknowledgementRequest>,
     ) -> Result<Response<QueryPacketAcknowledgementResponse>, Status> {
-        query_packet_acknowledgement(&self.ibc_context, &request.try_into_domain()?)?
+        let query_height = async_query_height_from_metadata(&request, &self.ibc_context).await?;
+
+        let mut request: super::QueryPacketAcknowledgementRequest = request.try_into_domain()?;
+        request.query_height = query_height.or(request.query_height);
+
+        self.ibc_context
+            .query_packet_acknowledgement(&request)
+            .await?
             .into_response()
     }
 
@@ -133,7 +240,9 @@ where
         &self,
         request: Request<QueryPacketAcknowledgementsRequest>,
     ) -> Result<Response<QueryPacketAcknowledgementsResponse>, Status> {
-        query_packet_acknowledgements(&self.ibc_context, &request.try_into_domain()?)?
+        self.ibc_context
+            .query_packet_acknowledgements(&request.try_into_domain()?)
+            .await?
             .into_response()
     }
 
@@ -142,7 +251,10 @@ where
         &self,
         request: Request<QueryUnreceivedPacketsRequest>,
     ) -> Result<Response<QueryUnreceivedPacketsResponse>, Status> {
-        query_unreceived_packets(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_unreceived_packets(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     /// Returns all the unreceived acknowledgements if sequences is omitted.
@@ -150,21 +262,30 @@ where
         &self,
         request: Request<QueryUnreceivedAcksRequest>,
     ) -> Result<Response<QueryUnreceivedAcksResponse>, Status> {
-        query_unreceived_acks(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_unreceived_acks(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn next_sequence_receive(
         &self,
         request: Request<QueryNextSequenceReceiveRequest>,
     ) -> Result<Response<QueryNextSequenceReceiveResponse>, Status> {
-        query_next_sequence_receive(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_next_sequence_receive(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn next_sequence_send(
         &self,
         request: Request<QueryNextSequenceSendRequest>,
     ) -> Result<Response<QueryNextSequenceSendResponse>, Status> {
-        query_next_sequence_send(&self.ibc_context, &request.try_into_domain()?)?.into_response()
+        self.ibc_context
+            .query_next_sequence_send(&request.try_into_domain()?)
+            .await?
+            .into_response()
     }
 
     async fn upgrade_error(
@@ -82,6 +82,42 @@ impl TryFrom<RawQueryConnectionChannelsRequest> for QueryConnectionChannelsReque
     }
 }
 
+/// Defines the RPC method request type for querying all channels bound to a
+/// port identifier
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryPortChannelsRequest {
+    pub port_id: PortId,
+}
+
+/// Defines the RPC method request type for querying whether packet flow on a
+/// channel is paused
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelFlowStatusRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+/// Defines the RPC method request type for querying the bounds of the
+/// sequences with an outstanding packet commitment on a channel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryCommitmentSequenceBoundsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+/// Defines the RPC method request type for querying the parameters of the
+/// channel module
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelParamsRequest;
+
 /// Defines the RPC method request type for querying the client state associated
 /// with a channel
 #[derive(Clone, Debug)]
@@ -2,10 +2,12 @@
 //! and from the corresponding gRPC proto types for the channel module.
 
 use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
-use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    AcknowledgementCommitment, HashAlgorithm, PacketCommitment,
+};
 use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
-use ibc::core::host::types::identifiers::{ClientId, Sequence};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, Sequence};
 use ibc::core::primitives::proto::Any;
 use ibc::primitives::prelude::*;
 use ibc::primitives::proto::Protobuf;
@@ -190,6 +192,92 @@ impl From<QueryConnectionChannelsResponse> for RawQueryConnectionChannelsRespons
     }
 }
 
+/// Defines the RPC method response type when querying the channels bound to a
+/// port.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryPortChannelsResponse {
+    pub channel_ids: Vec<ChannelId>,
+    pub query_height: Height,
+}
+
+impl QueryPortChannelsResponse {
+    pub fn new(channel_ids: Vec<ChannelId>, query_height: Height) -> Self {
+        Self {
+            channel_ids,
+            query_height,
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying whether packet flow on
+/// a channel is paused.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelFlowStatusResponse {
+    pub paused: bool,
+    pub query_height: Height,
+}
+
+impl QueryChannelFlowStatusResponse {
+    pub fn new(paused: bool, query_height: Height) -> Self {
+        Self {
+            paused,
+            query_height,
+        }
+    }
+}
+
+/// Defines the RPC method response type when querying the bounds of the
+/// sequences with an outstanding packet commitment on a channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryCommitmentSequenceBoundsResponse {
+    /// The lowest and highest sequences, or `None` if no commitment is
+    /// outstanding.
+    pub bounds: Option<(Sequence, Sequence)>,
+    pub query_height: Height,
+}
+
+impl QueryCommitmentSequenceBoundsResponse {
+    pub fn new(bounds: Option<(Sequence, Sequence)>, query_height: Height) -> Self {
+        Self {
+            bounds,
+            query_height,
+        }
+    }
+
+    /// Returns the lowest sequence with an outstanding commitment.
+    pub fn min_sequence(&self) -> Option<Sequence> {
+        self.bounds.map(|(min, _)| min)
+    }
+
+    /// Returns the highest sequence with an outstanding commitment.
+    pub fn max_sequence(&self) -> Option<Sequence> {
+        self.bounds.map(|(_, max)| max)
+    }
+}
+
+/// Defines the RPC method response type when querying the parameters of the
+/// channel module.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelParamsResponse {
+    pub packet_commitment_algorithm: HashAlgorithm,
+}
+
+impl QueryChannelParamsResponse {
+    pub fn new(packet_commitment_algorithm: HashAlgorithm) -> Self {
+        Self {
+            packet_commitment_algorithm,
+        }
+    }
+}
+
 /// Defines the RPC method response type when querying a channel client state.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
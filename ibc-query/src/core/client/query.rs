@@ -12,13 +12,14 @@ use ibc::primitives::prelude::format;
 use ibc::primitives::proto::Any;
 
 use super::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateResponse,
-    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
-    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
-    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
-    QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
-    QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
-    QueryUpgradedConsensusStateResponse,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientPeriodsRequest,
+    QueryClientPeriodsResponse, QueryClientStateResponse, QueryClientStatesRequest,
+    QueryClientStatesResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryConsensusStateHeightsRequest, QueryConsensusStateHeightsResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+    QueryConsensusStatesResponse, QueryHostParamsRequest, QueryHostParamsResponse,
+    QueryUpgradedClientStateRequest, QueryUpgradedClientStateResponse,
+    QueryUpgradedConsensusStateRequest, QueryUpgradedConsensusStateResponse,
 };
 use crate::core::client::QueryClientStateRequest;
 use crate::core::context::{ProvableContext, QueryContext};
@@ -162,7 +163,8 @@ where
     ))
 }
 
-/// Queries for the heights of all the consensus states of a given client id.
+/// Queries for the heights of the consensus states of a given client id, in
+/// ascending order, page by page.
 pub fn query_consensus_state_heights<I>(
     ibc_ctx: &I,
     request: &QueryConsensusStateHeightsRequest,
@@ -170,12 +172,18 @@ pub fn query_consensus_state_heights<I>(
 where
     I: QueryContext,
 {
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&request.client_id)?;
+    let mut consensus_state_heights = ibc_ctx.consensus_state_heights(&request.client_id)?;
+    consensus_state_heights.sort();
+
+    let (consensus_state_heights, pagination) = request
+        .pagination
+        .clone()
+        .unwrap_or_default()
+        .paginate(consensus_state_heights)?;
 
     Ok(QueryConsensusStateHeightsResponse::new(
         consensus_state_heights,
-        // no support for pagination yet
-        None,
+        Some(pagination),
     ))
 }
 
@@ -195,6 +203,43 @@ where
     Ok(QueryClientStatusResponse::new(client_status))
 }
 
+/// Queries for the trusting and unbonding periods of a given client.
+pub fn query_client_periods<I>(
+    ibc_ctx: &I,
+    request: &QueryClientPeriodsRequest,
+) -> Result<QueryClientPeriodsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    // fail for unknown clients, whose periods hosts may not look up at all
+    ibc_ctx
+        .get_client_validation_context()
+        .client_state(&request.client_id)?;
+
+    let trusting_period = ibc_ctx.client_trusting_period(&request.client_id)?;
+    let unbonding_period = ibc_ctx.client_unbonding_period(&request.client_id)?;
+
+    Ok(QueryClientPeriodsResponse::new(
+        trusting_period,
+        unbonding_period,
+    ))
+}
+
+/// Queries for the parameters of the host chain, such as the revision number it
+/// currently runs at.
+pub fn query_host_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryHostParamsRequest,
+) -> Result<QueryHostParamsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(QueryHostParamsResponse::new(
+        ibc_ctx.host_revision_number()?,
+        ibc_ctx.host_height()?,
+    ))
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<I, U>(
     ibc_ctx: &I,
@@ -1,6 +1,9 @@
 //! [`ClientQueryService`](ClientQueryService) takes generics `I` and `U` to store `ibc_context` and `upgrade_context` that implement [`QueryContext`](QueryContext) and [`UpgradeValidationContext`](UpgradeValidationContext) respectively.
 //! `I` must be a type where writes from one thread are readable from another.
 //! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+//!
+//! Queries the client `Query` service of `ibc-proto` has no RPC for are served
+//! by inherent methods of the service instead, for hosts to expose themselves.
 
 use ibc::core::host::ConsensusStateRef;
 use ibc::core::primitives::prelude::*;
@@ -21,9 +24,11 @@ use ibc_proto::ibc::core::client::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_client_state, query_client_states, query_client_status, query_consensus_state,
-    query_consensus_state_heights, query_consensus_states, query_upgraded_client_state,
-    query_upgraded_consensus_state,
+    query_client_periods, query_client_state, query_client_states, query_client_status,
+    query_consensus_state, query_consensus_state_heights, query_consensus_states,
+    query_host_params, query_upgraded_client_state, query_upgraded_consensus_state,
+    QueryClientPeriodsRequest, QueryClientPeriodsResponse, QueryHostParamsRequest,
+    QueryHostParamsResponse,
 };
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
@@ -58,6 +63,27 @@ where
             upgrade_context,
         }
     }
+
+    /// Serves the trusting and unbonding periods of a client.
+    pub async fn client_periods(
+        &self,
+        request: Request<QueryClientPeriodsRequest>,
+    ) -> Result<Response<QueryClientPeriodsResponse>, Status> {
+        let response = query_client_periods(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    /// Serves the parameters of the host chain, such as the revision number
+    /// it currently runs at.
+    pub async fn host_params(
+        &self,
+        request: Request<QueryHostParamsRequest>,
+    ) -> Result<Response<QueryHostParamsResponse>, Status> {
+        let response = query_host_params(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
 }
 
 #[tonic::async_trait]
@@ -179,6 +179,15 @@ impl TryFrom<RawQueryClientStatusRequest> for QueryClientStatusRequest {
     }
 }
 
+/// Defines the RPC method request type for querying the trusting and unbonding
+/// periods of a client.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientPeriodsRequest {
+    pub client_id: ClientId,
+}
+
 /// Defines the RPC method request type for querying the parameters of a client.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -193,6 +202,13 @@ impl From<RawQueryClientParamsRequest> for QueryClientParamsRequest {
     }
 }
 
+/// Defines the RPC method request type for querying the parameters of the host
+/// chain, such as the revision number it currently runs at.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryHostParamsRequest;
+
 /// Defines the RPC method request type for querying the upgraded client state.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1,6 +1,8 @@
 //! Contains all the RPC method response domain types and their conversions to
 //! and from the corresponding gRPC proto types for the client module.
 
+use core::time::Duration;
+
 use ibc::core::client::types::{Height, Status};
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::primitives::proto::Any;
@@ -385,6 +387,27 @@ impl From<QueryClientStatusResponse> for RawQueryClientStatusResponse {
     }
 }
 
+/// Defines the RPC method response type when querying the trusting and
+/// unbonding periods of a client.
+///
+/// Both periods are `None` for client types that don't define them.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientPeriodsResponse {
+    pub trusting_period: Option<Duration>,
+    pub unbonding_period: Option<Duration>,
+}
+
+impl QueryClientPeriodsResponse {
+    pub fn new(trusting_period: Option<Duration>, unbonding_period: Option<Duration>) -> Self {
+        Self {
+            trusting_period,
+            unbonding_period,
+        }
+    }
+}
+
 /// Defines the RPC method response type for querying the client parameters.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -431,6 +454,27 @@ impl From<QueryClientParamsResponse> for RawQueryClientParamsResponse {
     }
 }
 
+/// Defines the RPC method response type for querying the parameters of the host
+/// chain.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryHostParamsResponse {
+    /// The revision number the host chain currently runs at.
+    pub revision_number: u64,
+    /// The height of the host chain at which the parameters were read.
+    pub query_height: Height,
+}
+
+impl QueryHostParamsResponse {
+    pub fn new(revision_number: u64, query_height: Height) -> Self {
+        Self {
+            revision_number,
+            query_height,
+        }
+    }
+}
+
 /// Defines the RPC method response type for querying the upgraded client state.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -57,12 +57,15 @@ where
 /// Queries for all the existing connection ends.
 pub fn query_connections<I>(
     ibc_ctx: &I,
-    _request: &QueryConnectionsRequest,
+    request: &QueryConnectionsRequest,
 ) -> Result<QueryConnectionsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.connection_ends()?;
+    let connections = match request.state {
+        Some(state) => ibc_ctx.connection_ends_by_state(state)?,
+        None => ibc_ctx.connection_ends()?,
+    };
 
     Ok(QueryConnectionsResponse::new(
         connections,
@@ -21,7 +21,7 @@ use super::{
     query_connection_consensus_state, query_connection_params, query_connections,
 };
 use crate::core::context::QueryContext;
-use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
+use crate::utils::{connection_state_from_metadata, IntoDomain, IntoResponse, TryIntoDomain};
 
 // TODO(rano): currently the services don't support pagination, so we return all the results.
 
@@ -66,7 +66,12 @@ where
         &self,
         request: Request<QueryConnectionsRequest>,
     ) -> Result<Response<QueryConnectionsResponse>, Status> {
-        query_connections(&self.ibc_context, &request.into_domain())?.into_response()
+        let state = connection_state_from_metadata(&request)?;
+
+        let mut domain_request: super::QueryConnectionsRequest = request.into_domain();
+        domain_request.state = state;
+
+        query_connections(&self.ibc_context, &domain_request)?.into_response()
     }
 
     async fn client_connections(
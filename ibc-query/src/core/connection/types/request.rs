@@ -2,6 +2,7 @@
 //! and from the corresponding gRPC proto types for the connection module.
 
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::State;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
 use ibc::primitives::prelude::*;
 use ibc_proto::ibc::core::connection::v1::{
@@ -37,21 +38,45 @@ impl TryFrom<RawQueryConnectionRequest> for QueryConnectionRequest {
 }
 
 /// Defines the RPC method request type for querying connections.
+///
+/// The gRPC request carries no state, so
+/// [`ConnectionQueryService`](crate::core::connection::ConnectionQueryService)
+/// reads the state filter from the request's
+/// [`CONNECTION_STATE_METADATA_KEY`](crate::utils::CONNECTION_STATE_METADATA_KEY)
+/// metadata.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct QueryConnectionsRequest {
     pub pagination: Option<PageRequest>,
+    /// Only returns the connections in this state, if set.
+    pub state: Option<State>,
 }
 
-impl From<RawQueryConnectionsRequest> for QueryConnectionsRequest {
-    fn from(request: RawQueryConnectionsRequest) -> Self {
+impl QueryConnectionsRequest {
+    pub fn new(pagination: Option<PageRequest>) -> Self {
+        Self {
+            pagination,
+            state: None,
+        }
+    }
+
+    /// Only queries the connections in the given state.
+    pub fn with_state(self, state: State) -> Self {
         Self {
-            pagination: request.pagination.map(Into::into),
+            state: Some(state),
+            ..self
         }
     }
 }
 
+impl From<RawQueryConnectionsRequest> for QueryConnectionsRequest {
+    fn from(request: RawQueryConnectionsRequest) -> Self {
+        Self::new(request.pagination.map(Into::into))
+    }
+}
+
 /// Defines the RPC method request type for querying connections associated with
 /// a client.
 #[derive(Clone, Debug)]
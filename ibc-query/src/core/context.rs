@@ -1,14 +1,42 @@
 //! Required traits for blanket implementations of [`gRPC query services`](crate::core).
 
-use ibc::core::channel::types::channel::IdentifiedChannelEnd;
-use ibc::core::channel::types::packet::PacketState;
+use core::time::Duration;
+
+use ibc::clients::tendermint::types::{
+    ClientState as TmClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::{Packet, PacketState};
+use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::Height;
-use ibc::core::connection::types::IdentifiedConnectionEnd;
+use ibc::core::connection::types::{IdentifiedConnectionEnd, State as ConnectionState};
 use ibc::core::handler::types::error::ContextError;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
-use ibc::core::host::types::path::{ChannelEndPath, Path};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath, CommitmentPath, Path};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
+use ibc::core::router::types::module::ModuleId;
+use ibc_proto::google::protobuf::Any;
+
+use crate::core::channel::{
+    self, QueryChannelClientStateRequest, QueryChannelClientStateResponse,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelFlowStatusRequest, QueryChannelFlowStatusResponse, QueryChannelParamsRequest,
+    QueryChannelParamsResponse, QueryChannelRequest, QueryChannelResponse, QueryChannelsRequest,
+    QueryChannelsResponse, QueryCommitmentSequenceBoundsRequest,
+    QueryCommitmentSequenceBoundsResponse, QueryConnectionChannelsRequest,
+    QueryConnectionChannelsResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryNextSequenceSendRequest, QueryNextSequenceSendResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    QueryPortChannelsRequest, QueryPortChannelsResponse, QueryUnreceivedAcksRequest,
+    QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+};
+use crate::core::port::{self, QueryPortRequest, QueryPortResponse};
+use crate::error::QueryError;
 
 /// Context to be implemented by the host to provide proofs in query responses
 pub trait ProvableContext {
@@ -30,14 +58,69 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         client_id: &ClientId,
     ) -> Result<Vec<(Height, ConsensusStateRef<Self>)>, ContextError>;
 
-    /// Returns the list of all heights at which consensus states for the given client are.
+    /// Returns the list of all heights at which consensus states for the given
+    /// client are, in any order.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns the trusting period of the given client, or `None` if its
+    /// client type doesn't define one.
+    ///
+    /// The default implementation reads it from Tendermint client states, and
+    /// returns `None` for any other client type.
+    fn client_trusting_period(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, ContextError> {
+        Ok(tendermint_client_state(self, client_id)?
+            .map(|client_state| client_state.trusting_period))
+    }
+
+    /// Returns the unbonding period of the given client, or `None` if its
+    /// client type doesn't define one.
+    ///
+    /// The default implementation reads it from Tendermint client states, and
+    /// returns `None` for any other client type.
+    fn client_unbonding_period(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Duration>, ContextError> {
+        Ok(tendermint_client_state(self, client_id)?
+            .map(|client_state| client_state.unbonding_period))
+    }
+
+    /// Returns the revision number the host chain currently runs at, i.e. the
+    /// epoch encoded in its chain identifier.
+    ///
+    /// Relayers use it to build timeout heights that remain valid on the host
+    /// after it upgrades to a new revision.
+    ///
+    /// The default implementation returns the revision number of the
+    /// [`host_height`](ValidationContext::host_height).
+    fn host_revision_number(&self) -> Result<u64, ContextError> {
+        Ok(self.host_height()?.revision_number())
+    }
+
     // Connection queries
 
     /// Returns the list of all connection ends.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
 
+    /// Returns the list of all connection ends in the given state.
+    ///
+    /// The default implementation filters the result of
+    /// [`connection_ends`](QueryContext::connection_ends). Hosts indexing
+    /// connections by state should override it.
+    fn connection_ends_by_state(
+        &self,
+        state: ConnectionState,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        Ok(self
+            .connection_ends()?
+            .into_iter()
+            .filter(|connection_end| connection_end.connection_end.state == state)
+            .collect())
+    }
+
     /// Returns the list of all connection ids of the given client.
     fn client_connection_ends(
         &self,
@@ -49,6 +132,33 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all channel ends.
     fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
 
+    /// Returns the list of all channel ids bound to the given port.
+    ///
+    /// The default implementation filters the result of
+    /// [`channel_ends`](QueryContext::channel_ends). Hosts able to look up
+    /// channels by port prefix should override it.
+    fn channels_by_port(&self, port_id: &PortId) -> Result<Vec<ChannelId>, ContextError> {
+        Ok(self
+            .channel_ends()?
+            .into_iter()
+            .filter(|channel_end| &channel_end.port_id == port_id)
+            .map(|channel_end| channel_end.channel_id)
+            .collect())
+    }
+
+    // Port queries
+
+    /// Returns the list of all ports bound by the host, along with the id of
+    /// the module each one is routed to.
+    ///
+    /// The default implementation, meant for hosts that don't expose their
+    /// routing, returns [`QueryError::Unimplemented`].
+    fn port_bindings(&self) -> Result<Vec<(PortId, ModuleId)>, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying port bindings is not supported by this host",
+        ))
+    }
+
     // Packet queries
 
     /// Returns the list of all packet commitments for the given channel end.
@@ -66,12 +176,33 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     ) -> Result<Vec<PacketState>, ContextError>;
 
     /// Filters the packet sequences for the given channel end that are not received.
+    ///
+    /// Only used for unordered channels, which track packet receipts.
     fn unreceived_packets(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<Sequence>, ContextError>;
 
+    /// Returns the sequences in `start..=end` for which the given channel end
+    /// holds no packet receipt.
+    ///
+    /// Only used for unordered channels, which track packet receipts. The
+    /// default implementation falls back to
+    /// [`unreceived_packets`](QueryContext::unreceived_packets), i.e. one
+    /// lookup per sequence. Hosts able to iterate over the receipts of a
+    /// channel should override it to read them at once instead.
+    fn unreceived_in_range(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        end: Sequence,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let sequences: Vec<Sequence> = Sequence::range(start, end).collect();
+
+        self.unreceived_packets(channel_end_path, sequences.into_iter())
+    }
+
     /// Filters the list of packet sequences for the given channel end whose acknowledgement is not received.
     /// Returns all the unreceived acknowledgements if `sequences` is empty.
     fn unreceived_acks(
@@ -79,4 +210,396 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<Sequence>, ContextError>;
+
+    /// Returns the lowest and highest sequences of the packets sent on the
+    /// given channel end that still have an outstanding commitment, or `None`
+    /// if there is none.
+    ///
+    /// The default implementation scans the result of
+    /// [`packet_commitments`](QueryContext::packet_commitments). Hosts storing
+    /// commitments in sequence order should override it to read both ends
+    /// directly.
+    fn commitment_sequence_bounds(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Option<(Sequence, Sequence)>, ContextError> {
+        let sequences = self
+            .packet_commitments(channel_end_path)?
+            .into_iter()
+            .map(|packet_state| packet_state.seq);
+
+        Ok(sequences.fold(None, |bounds, seq| match bounds {
+            None => Some((seq, seq)),
+            Some((min, max)) => Some((min.min(seq), max.max(seq))),
+        }))
+    }
+
+    /// Returns all the packets sent on the given channel end that still have an
+    /// outstanding commitment, so that relayers can reconstruct and resend them.
+    ///
+    /// Only hosts that keep the full packets around can serve this query. The
+    /// default implementation, meant for hosts storing only the commitment
+    /// hashes, returns [`QueryError::Unimplemented`].
+    fn pending_packets(
+        &self,
+        _channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Packet>, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying pending packets is not supported by this host",
+        ))
+    }
+
+    // Historical queries
+
+    /// Returns the channel end stored at the given path as of the given
+    /// height.
+    ///
+    /// Only called for heights below the latest height of the host. The
+    /// default implementation, meant for hosts that don't keep historical
+    /// state around, returns [`QueryError::Unimplemented`].
+    fn channel_end_at_height(
+        &self,
+        _channel_end_path: &ChannelEndPath,
+        _height: Height,
+    ) -> Result<ChannelEnd, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying channel ends at a given height is not supported by this host",
+        ))
+    }
+
+    /// Returns the packet commitment stored at the given path as of the given
+    /// height.
+    ///
+    /// Only called for heights below the latest height of the host. The
+    /// default implementation, meant for hosts that don't keep historical
+    /// state around, returns [`QueryError::Unimplemented`].
+    fn packet_commitment_at_height(
+        &self,
+        _commitment_path: &CommitmentPath,
+        _height: Height,
+    ) -> Result<PacketCommitment, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying packet commitments at a given height is not supported by this host",
+        ))
+    }
+
+    /// Returns the packet acknowledgement stored at the given path as of the
+    /// given height.
+    ///
+    /// Only called for heights below the latest height of the host. The
+    /// default implementation, meant for hosts that don't keep historical
+    /// state around, returns [`QueryError::Unimplemented`].
+    fn packet_acknowledgement_at_height(
+        &self,
+        _ack_path: &AckPath,
+        _height: Height,
+    ) -> Result<AcknowledgementCommitment, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying packet acknowledgements at a given height is not supported by this host",
+        ))
+    }
+}
+
+/// Asynchronous counterpart of [`QueryContext`] serving the channel queries,
+/// for hosts whose storage has to be awaited.
+///
+/// Each method answers one query of the
+/// [`ChannelQueryService`](crate::core::channel::ChannelQueryService), so that
+/// the service never blocks inside the gRPC runtime. Every [`QueryContext`] is
+/// an `AsyncQueryContext` through a blanket implementation running the
+/// synchronous [`query_*`](crate::core::channel) functions, hence only hosts
+/// backed by asynchronous storage need to implement this trait.
+///
+/// The queries `ibc-proto` has no RPC for are optional and default to
+/// [`QueryError::Unimplemented`].
+#[tonic::async_trait]
+pub trait AsyncQueryContext: Send + Sync {
+    /// Returns the current height of the host chain.
+    async fn query_host_height(&self) -> Result<Height, QueryError>;
+
+    async fn query_channel(
+        &self,
+        request: &QueryChannelRequest,
+    ) -> Result<QueryChannelResponse, QueryError>;
+
+    async fn query_channels(
+        &self,
+        request: &QueryChannelsRequest,
+    ) -> Result<QueryChannelsResponse, QueryError>;
+
+    async fn query_connection_channels(
+        &self,
+        request: &QueryConnectionChannelsRequest,
+    ) -> Result<QueryConnectionChannelsResponse, QueryError>;
+
+    async fn query_channel_client_state(
+        &self,
+        request: &QueryChannelClientStateRequest,
+    ) -> Result<QueryChannelClientStateResponse, QueryError>;
+
+    async fn query_channel_consensus_state(
+        &self,
+        request: &QueryChannelConsensusStateRequest,
+    ) -> Result<QueryChannelConsensusStateResponse, QueryError>;
+
+    async fn query_packet_commitment(
+        &self,
+        request: &QueryPacketCommitmentRequest,
+    ) -> Result<QueryPacketCommitmentResponse, QueryError>;
+
+    async fn query_packet_commitments(
+        &self,
+        request: &QueryPacketCommitmentsRequest,
+    ) -> Result<QueryPacketCommitmentsResponse, QueryError>;
+
+    async fn query_packet_receipt(
+        &self,
+        request: &QueryPacketReceiptRequest,
+    ) -> Result<QueryPacketReceiptResponse, QueryError>;
+
+    async fn query_packet_acknowledgement(
+        &self,
+        request: &QueryPacketAcknowledgementRequest,
+    ) -> Result<QueryPacketAcknowledgementResponse, QueryError>;
+
+    async fn query_packet_acknowledgements(
+        &self,
+        request: &QueryPacketAcknowledgementsRequest,
+    ) -> Result<QueryPacketAcknowledgementsResponse, QueryError>;
+
+    async fn query_unreceived_packets(
+        &self,
+        request: &QueryUnreceivedPacketsRequest,
+    ) -> Result<QueryUnreceivedPacketsResponse, QueryError>;
+
+    async fn query_unreceived_acks(
+        &self,
+        request: &QueryUnreceivedAcksRequest,
+    ) -> Result<QueryUnreceivedAcksResponse, QueryError>;
+
+    async fn query_next_sequence_send(
+        &self,
+        request: &QueryNextSequenceSendRequest,
+    ) -> Result<QueryNextSequenceSendResponse, QueryError>;
+
+    async fn query_next_sequence_receive(
+        &self,
+        request: &QueryNextSequenceReceiveRequest,
+    ) -> Result<QueryNextSequenceReceiveResponse, QueryError>;
+
+    /// Answers the query for the module bound to a port.
+    async fn query_port(
+        &self,
+        _request: &QueryPortRequest,
+    ) -> Result<QueryPortResponse, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying ports is not supported by this host",
+        ))
+    }
+
+    /// Answers the query for the parameters of the channel module.
+    async fn query_channel_params(
+        &self,
+        _request: &QueryChannelParamsRequest,
+    ) -> Result<QueryChannelParamsResponse, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying channel params is not supported by this host",
+        ))
+    }
+
+    /// Answers the query for the channels bound to a port.
+    async fn query_port_channels(
+        &self,
+        _request: &QueryPortChannelsRequest,
+    ) -> Result<QueryPortChannelsResponse, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying port channels is not supported by this host",
+        ))
+    }
+
+    /// Answers the query for whether packet flow on a channel is paused.
+    async fn query_channel_flow_status(
+        &self,
+        _request: &QueryChannelFlowStatusRequest,
+    ) -> Result<QueryChannelFlowStatusResponse, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying channel flow status is not supported by this host",
+        ))
+    }
+
+    /// Answers the query for the sequence bounds of the outstanding packet
+    /// commitments of a channel.
+    async fn query_commitment_sequence_bounds(
+        &self,
+        _request: &QueryCommitmentSequenceBoundsRequest,
+    ) -> Result<QueryCommitmentSequenceBoundsResponse, QueryError> {
+        Err(QueryError::unimplemented(
+            "Querying commitment sequence bounds is not supported by this host",
+        ))
+    }
+}
+
+#[tonic::async_trait]
+impl<I> AsyncQueryContext for I
+where
+    I: QueryContext + Send + Sync,
+    ConsensusStateRef<I>: Into<Any>,
+{
+    async fn query_host_height(&self) -> Result<Height, QueryError> {
+        Ok(self.host_height()?)
+    }
+
+    async fn query_channel(
+        &self,
+        request: &QueryChannelRequest,
+    ) -> Result<QueryChannelResponse, QueryError> {
+        channel::query_channel(self, request)
+    }
+
+    async fn query_channels(
+        &self,
+        request: &QueryChannelsRequest,
+    ) -> Result<QueryChannelsResponse, QueryError> {
+        channel::query_channels(self, request)
+    }
+
+    async fn query_connection_channels(
+        &self,
+        request: &QueryConnectionChannelsRequest,
+    ) -> Result<QueryConnectionChannelsResponse, QueryError> {
+        channel::query_connection_channels(self, request)
+    }
+
+    async fn query_channel_client_state(
+        &self,
+        request: &QueryChannelClientStateRequest,
+    ) -> Result<QueryChannelClientStateResponse, QueryError> {
+        channel::query_channel_client_state(self, request)
+    }
+
+    async fn query_channel_consensus_state(
+        &self,
+        request: &QueryChannelConsensusStateRequest,
+    ) -> Result<QueryChannelConsensusStateResponse, QueryError> {
+        channel::query_channel_consensus_state(self, request)
+    }
+
+    async fn query_packet_commitment(
+        &self,
+        request: &QueryPacketCommitmentRequest,
+    ) -> Result<QueryPacketCommitmentResponse, QueryError> {
+        channel::query_packet_commitment(self, request)
+    }
+
+    async fn query_packet_commitments(
+        &self,
+        request: &QueryPacketCommitmentsRequest,
+    ) -> Result<QueryPacketCommitmentsResponse, QueryError> {
+        channel::query_packet_commitments(self, request)
+    }
+
+    async fn query_packet_receipt(
+        &self,
+        request: &QueryPacketReceiptRequest,
+    ) -> Result<QueryPacketReceiptResponse, QueryError> {
+        channel::query_packet_receipt(self, request)
+    }
+
+    async fn query_packet_acknowledgement(
+        &self,
+        request: &QueryPacketAcknowledgementRequest,
+    ) -> Result<QueryPacketAcknowledgementResponse, QueryError> {
+        channel::query_packet_acknowledgement(self, request)
+    }
+
+    async fn query_packet_acknowledgements(
+        &self,
+        request: &QueryPacketAcknowledgementsRequest,
+    ) -> Result<QueryPacketAcknowledgementsResponse, QueryError> {
+        channel::query_packet_acknowledgements(self, request)
+    }
+
+    async fn query_unreceived_packets(
+        &self,
+        request: &QueryUnreceivedPacketsRequest,
+    ) -> Result<QueryUnreceivedPacketsResponse, QueryError> {
+        channel::query_unreceived_packets(self, request)
+    }
+
+    async fn query_unreceived_acks(
+        &self,
+        request: &QueryUnreceivedAcksRequest,
+    ) -> Result<QueryUnreceivedAcksResponse, QueryError> {
+        channel::query_unreceived_acks(self, request)
+    }
+
+    async fn query_next_sequence_send(
+        &self,
+        request: &QueryNextSequenceSendRequest,
+    ) -> Result<QueryNextSequenceSendResponse, QueryError> {
+        channel::query_next_sequence_send(self, request)
+    }
+
+    async fn query_next_sequence_receive(
+        &self,
+        request: &QueryNextSequenceReceiveRequest,
+    ) -> Result<QueryNextSequenceReceiveResponse, QueryError> {
+        channel::query_next_sequence_receive(self, request)
+    }
+
+    async fn query_port(
+        &self,
+        request: &QueryPortRequest,
+    ) -> Result<QueryPortResponse, QueryError> {
+        port::query_port(self, request)
+    }
+
+    async fn query_channel_params(
+        &self,
+        request: &QueryChannelParamsRequest,
+    ) -> Result<QueryChannelParamsResponse, QueryError> {
+        channel::query_channel_params(self, request)
+    }
+
+    async fn query_port_channels(
+        &self,
+        request: &QueryPortChannelsRequest,
+    ) -> Result<QueryPortChannelsResponse, QueryError> {
+        channel::query_port_channels(self, request)
+    }
+
+    async fn query_channel_flow_status(
+        &self,
+        request: &QueryChannelFlowStatusRequest,
+    ) -> Result<QueryChannelFlowStatusResponse, QueryError> {
+        channel::query_channel_flow_status(self, request)
+    }
+
+    async fn query_commitment_sequence_bounds(
+        &self,
+        request: &QueryCommitmentSequenceBoundsRequest,
+    ) -> Result<QueryCommitmentSequenceBoundsResponse, QueryError> {
+        channel::query_commitment_sequence_bounds(self, request)
+    }
+}
+
+/// Returns the state of the given client if it is a Tendermint client, or
+/// `None` if it is of any other client type.
+fn tendermint_client_state<I>(
+    ibc_ctx: &I,
+    client_id: &ClientId,
+) -> Result<Option<TmClientState>, ContextError>
+where
+    I: ValidationContext + ?Sized,
+{
+    let client_state: Any = ibc_ctx
+        .get_client_validation_context()
+        .client_state(client_id)?
+        .into();
+
+    if client_state.type_url != TENDERMINT_CLIENT_STATE_TYPE_URL {
+        return Ok(None);
+    }
+
+    Ok(Some(TmClientState::try_from(client_state)?))
 }
@@ -0,0 +1,8 @@
+//! Provides utility functions and domain types for querying the port
+//! bindings of the host.
+
+mod query;
+mod types;
+
+pub use query::*;
+pub use types::*;
@@ -0,0 +1,29 @@
+//! Provides utility functions for querying IBC port bindings.
+
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::router::types::error::RouterError;
+
+use super::{QueryPortRequest, QueryPortResponse};
+use crate::core::context::QueryContext;
+use crate::error::QueryError;
+
+/// Queries for the id of the module bound to the given port.
+pub fn query_port<I>(
+    ibc_ctx: &I,
+    request: &QueryPortRequest,
+) -> Result<QueryPortResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let (_, module_id) = ibc_ctx
+        .port_bindings()?
+        .into_iter()
+        .find(|(port_id, _)| port_id == &request.port_id)
+        .ok_or_else(|| {
+            ContextError::from(RouterError::UnknownPort {
+                port_id: request.port_id.clone(),
+            })
+        })?;
+
+    Ok(QueryPortResponse::new(module_id.to_string()))
+}
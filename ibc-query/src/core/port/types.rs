@@ -0,0 +1,29 @@
+//! Contains the RPC method request and response domain types for querying
+//! IBC port bindings.
+
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
+
+/// Defines the RPC method request type for querying the module bound to a
+/// port.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryPortRequest {
+    pub port_id: PortId,
+}
+
+/// Defines the RPC method response type when querying the module bound to a
+/// port.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryPortResponse {
+    pub module_id: String,
+}
+
+impl QueryPortResponse {
+    pub fn new(module_id: String) -> Self {
+        Self { module_id }
+    }
+}
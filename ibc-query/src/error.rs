@@ -1,10 +1,11 @@
 use alloc::string::{String, ToString};
 
 use displaydoc::Display;
+use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::client::types::error::ClientError;
 use ibc::core::connection::types::error::ConnectionError;
-use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::error::{ContextError, StoreError};
 use ibc::core::host::types::error::IdentifierError;
 use tonic::Status;
 
@@ -18,6 +19,12 @@ pub enum QueryError {
     ProofNotFound(String),
     /// Missing field: {0}
     MissingField(String),
+    /// Invalid argument: {0}
+    InvalidArgument(String),
+    /// Unimplemented: {0}
+    Unimplemented(String),
+    /// Token transfer error: {0}
+    TokenTransferError(TokenTransferError),
 }
 
 impl QueryError {
@@ -28,15 +35,37 @@ impl QueryError {
     pub fn missing_field<T: ToString>(description: T) -> Self {
         Self::MissingField(description.to_string())
     }
+
+    pub fn invalid_argument<T: ToString>(description: T) -> Self {
+        Self::InvalidArgument(description.to_string())
+    }
+
+    pub fn unimplemented<T: ToString>(description: T) -> Self {
+        Self::Unimplemented(description.to_string())
+    }
 }
 
 impl From<QueryError> for Status {
     fn from(e: QueryError) -> Self {
         match e {
+            QueryError::ContextError(ContextError::StoreError(
+                store_err @ StoreError::NotFound { .. },
+            )) => Self::not_found(store_err.to_string()),
+            QueryError::ContextError(ContextError::ConnectionError(conn_err)) => conn_err.into(),
             QueryError::ContextError(ctx_err) => Self::internal(ctx_err.to_string()),
-            QueryError::IdentifierError(id_err) => Self::internal(id_err.to_string()),
+            // identifiers are parsed from the request, so a failure is the
+            // caller's, and the message names the offending identifier
+            QueryError::IdentifierError(id_err) => Self::invalid_argument(id_err.to_string()),
             QueryError::ProofNotFound(description) => Self::not_found(description),
             QueryError::MissingField(description) => Self::invalid_argument(description),
+            QueryError::InvalidArgument(description) => Self::invalid_argument(description),
+            QueryError::Unimplemented(description) => Self::unimplemented(description),
+            QueryError::TokenTransferError(
+                transfer_err @ TokenTransferError::DenomTraceNotFound { .. },
+            ) => Self::not_found(transfer_err.to_string()),
+            QueryError::TokenTransferError(transfer_err) => {
+                Self::internal(transfer_err.to_string())
+            }
         }
     }
 }
@@ -76,3 +105,47 @@ impl From<IdentifierError> for QueryError {
         Self::IdentifierError(e)
     }
 }
+
+impl From<TokenTransferError> for QueryError {
+    fn from(e: TokenTransferError) -> Self {
+        Self::TokenTransferError(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use ibc::core::host::types::identifiers::ConnectionId;
+    use tonic::Code;
+
+    use super::*;
+
+    #[test]
+    fn test_into_status() {
+        let not_found = QueryError::from(ContextError::StoreError(StoreError::NotFound {
+            path: "clients/07-tendermint-0/clientState".to_owned(),
+        }));
+        assert_eq!(Status::from(not_found).code(), Code::NotFound);
+
+        let backend = QueryError::from(ContextError::StoreError(StoreError::Backend {
+            description: "disk unavailable".to_owned(),
+        }));
+        assert_eq!(Status::from(backend).code(), Code::Internal);
+
+        let conn_not_found = QueryError::from(ConnectionError::ConnectionNotFound {
+            connection_id: ConnectionId::zero(),
+        });
+        assert_eq!(Status::from(conn_not_found).code(), Code::NotFound);
+
+        let invalid_id = QueryError::from(IdentifierError::InvalidCharacter {
+            id: "channel/0".to_owned(),
+        });
+        let status = Status::from(invalid_id);
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("channel/0"));
+
+        let proof = QueryError::proof_not_found("no proof for height 10");
+        assert_eq!(Status::from(proof).code(), Code::NotFound);
+    }
+}
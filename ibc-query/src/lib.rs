@@ -9,6 +9,9 @@
 //! - [`ValidationContext`](ibc::core::host::ValidationContext)
 //! - [`ProvableContext`](crate::core::context::ProvableContext)
 //! - [`QueryContext`](crate::core::context::QueryContext)
+//!   - Hosts whose storage has to be awaited can instead implement
+//!     [`AsyncQueryContext`](crate::core::context::AsyncQueryContext) to serve
+//!     the channel queries
 //! - [`UpgradeValidationContext`](ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext)
 //!   - Only for
 //!     [`ClientQuery::upgraded_client_state`](ibc_proto::ibc::core::client::v1::query_server::Query::upgraded_client_state)
@@ -69,6 +72,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod apps;
 pub mod core;
 pub mod error;
 pub mod types;
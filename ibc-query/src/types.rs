@@ -3,6 +3,8 @@ use ibc_proto::cosmos::base::query::v1beta1::{
     PageRequest as RawPageRequest, PageResponse as RawPageResponse,
 };
 
+use crate::error::QueryError;
+
 pub type Proof = Vec<u8>;
 
 #[derive(Clone, Debug, Default)]
@@ -30,12 +32,62 @@ pub struct PageRequest {
 }
 
 impl PageRequest {
+    /// Number of results returned when a request leaves the limit unset, as
+    /// in the Cosmos SDK.
+    pub const DEFAULT_LIMIT: u64 = 100;
+
     pub fn all() -> Self {
         Self {
             limit: u64::MAX,
             ..Default::default()
         }
     }
+
+    /// Returns the page of `items` selected by this request, along with the
+    /// [`PageResponse`] to return for it.
+    ///
+    /// The `next_key` of the response holds the big-endian offset of the next
+    /// page, and takes precedence over `offset` when passed back as `key`.
+    pub fn paginate<T>(&self, mut items: Vec<T>) -> Result<(Vec<T>, PageResponse), QueryError> {
+        let offset = if self.key.is_empty() {
+            self.offset
+        } else {
+            let key = self.key.as_slice().try_into().map_err(|_| {
+                QueryError::invalid_argument(format!("invalid pagination key {:?}", self.key))
+            })?;
+            u64::from_be_bytes(key)
+        };
+        let limit = if self.limit == 0 {
+            Self::DEFAULT_LIMIT
+        } else {
+            self.limit
+        };
+
+        if self.reverse {
+            items.reverse();
+        }
+
+        let total = items.len() as u64;
+        let page: Vec<T> = items
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect();
+
+        let next_offset = offset.saturating_add(page.len() as u64);
+        let next_key = if next_offset < total {
+            next_offset.to_be_bytes().to_vec()
+        } else {
+            Vec::new()
+        };
+
+        let response = PageResponse {
+            next_key,
+            total: if self.count_total { total } else { 0 },
+        };
+
+        Ok((page, response))
+    }
 }
 
 impl From<PageRequest> for RawPageRequest {
@@ -1,7 +1,144 @@
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::State as ConnectionState;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
 use tonic::{Request, Response, Status};
 
+use crate::core::context::AsyncQueryContext;
 use crate::error::QueryError;
 
+/// gRPC metadata key under which clients request the height to query at.
+pub const BLOCK_HEIGHT_METADATA_KEY: &str = "x-cosmos-block-height";
+
+/// gRPC metadata key under which clients of the connections query request
+/// only the connections in a given state, as the numeric value of the
+/// `ibc.core.connection.v1.State` enum.
+pub const CONNECTION_STATE_METADATA_KEY: &str = "x-ibc-connection-state";
+
+/// Reads the connection state to filter on from the request's
+/// [`CONNECTION_STATE_METADATA_KEY`] metadata.
+///
+/// Returns `None` when the metadata is absent, in which case connections in
+/// every state are returned.
+pub fn connection_state_from_metadata<Raw>(
+    request: &Request<Raw>,
+) -> Result<Option<ConnectionState>, Status> {
+    let Some(value) = request.metadata().get(CONNECTION_STATE_METADATA_KEY) else {
+        return Ok(None);
+    };
+
+    let state = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .and_then(|value| ConnectionState::try_from_i32(value).ok())
+        .ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "invalid `{CONNECTION_STATE_METADATA_KEY}` metadata: {value:?}"
+            ))
+        })?;
+
+    Ok(Some(state))
+}
+
+/// Reads the query height from the request's [`BLOCK_HEIGHT_METADATA_KEY`]
+/// metadata, interpreting it within the host's current revision.
+///
+/// Returns `None` when the metadata is absent or zero, in which case the
+/// query should be served at the latest height.
+pub fn query_height_from_metadata<Raw, I>(
+    request: &Request<Raw>,
+    ibc_ctx: &I,
+) -> Result<Option<Height>, Status>
+where
+    I: ValidationContext,
+{
+    let Some(revision_height) = revision_height_from_metadata(request)? else {
+        return Ok(None);
+    };
+
+    let revision_number = ibc_ctx
+        .host_height()
+        .map_err(QueryError::from)?
+        .revision_number();
+
+    let height = Height::new(revision_number, revision_height).map_err(QueryError::from)?;
+
+    Ok(Some(height))
+}
+
+/// Same as [`query_height_from_metadata`], for hosts implementing
+/// [`AsyncQueryContext`].
+pub async fn async_query_height_from_metadata<Raw, I>(
+    request: &Request<Raw>,
+    ibc_ctx: &I,
+) -> Result<Option<Height>, Status>
+where
+    I: AsyncQueryContext,
+{
+    let Some(revision_height) = revision_height_from_metadata(request)? else {
+        return Ok(None);
+    };
+
+    let revision_number = ibc_ctx.query_host_height().await?.revision_number();
+
+    let height = Height::new(revision_number, revision_height).map_err(QueryError::from)?;
+
+    Ok(Some(height))
+}
+
+/// Height at which a query is served, as resolved by
+/// [`resolve_query_height`].
+pub(crate) enum QueryHeight {
+    /// The latest height of the host, served from its current state.
+    Latest(Height),
+    /// A past height, served from the historical state kept by the host.
+    Historical(Height),
+}
+
+/// Resolves the height requested by a query against the latest height of the
+/// host.
+///
+/// Queries without a height, or at the latest height, are served from the
+/// current state. Heights ahead of the latest height are rejected.
+pub(crate) fn resolve_query_height<I>(
+    ibc_ctx: &I,
+    query_height: Option<Height>,
+) -> Result<QueryHeight, QueryError>
+where
+    I: ValidationContext,
+{
+    let latest_height = ibc_ctx.host_height()?;
+
+    match query_height {
+        Some(height) if height > latest_height => Err(QueryError::invalid_argument(format!(
+            "query height {height} is ahead of the latest height {latest_height}"
+        ))),
+        Some(height) if height < latest_height => Ok(QueryHeight::Historical(height)),
+        _ => Ok(QueryHeight::Latest(latest_height)),
+    }
+}
+
+/// Parses the revision height carried by the request's
+/// [`BLOCK_HEIGHT_METADATA_KEY`] metadata, treating zero as absent.
+fn revision_height_from_metadata<Raw>(request: &Request<Raw>) -> Result<Option<u64>, Status> {
+    let Some(value) = request.metadata().get(BLOCK_HEIGHT_METADATA_KEY) else {
+        return Ok(None);
+    };
+
+    let revision_height: u64 = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "invalid `{BLOCK_HEIGHT_METADATA_KEY}` metadata: {value:?}"
+            ))
+        })?;
+
+    Ok((revision_height != 0).then_some(revision_height))
+}
+
 pub trait TryIntoDomain<T> {
     fn try_into_domain(self) -> Result<T, Status>;
 }
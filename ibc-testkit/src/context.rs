@@ -1,10 +1,13 @@
+use alloc::sync::Arc;
 use core::fmt::Debug;
 use core::time::Duration;
 
-use basecoin_store::context::ProvableStore;
+use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::InMemoryStore;
+use basecoin_store::types::Height as StoreHeight;
 use ibc::core::channel::types::channel::ChannelEnd;
-use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::commitment::{CommitmentHasher, PacketCommitment};
+use ibc::core::channel::types::upgrade::Upgrade;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::Height;
@@ -12,15 +15,22 @@ use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{dispatch, execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::handler::types::msgs::{MsgEnvelope, MsgSummary};
+use ibc::core::host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
 use ibc::core::host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, CommitmentPath, ConnectionPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
+    CHANNEL_END_PREFIX, CHANNEL_UPGRADE_PREFIX, CLIENT_PREFIX, CONNECTION_PREFIX,
+    NEXT_CHANNEL_SEQUENCE, NEXT_CLIENT_SEQUENCE, NEXT_CONNECTION_SEQUENCE, NEXT_SEQ_ACK_PREFIX,
+    NEXT_SEQ_RECV_PREFIX, NEXT_SEQ_SEND_PREFIX, PACKET_ACK_PREFIX, PACKET_COMMITMENT_PREFIX,
+    PACKET_RECEIPT_PREFIX,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::router::types::module::ModuleId;
 use ibc::primitives::prelude::*;
-use ibc::primitives::Timestamp;
+use ibc::primitives::{Signer, Timestamp};
 
 use super::testapp::ibc::core::types::{LightClientState, MockIbcStore};
 use crate::fixtures::core::context::TestContextConfig;
@@ -29,6 +39,7 @@ use crate::relayer::error::RelayerError;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::router::MockRouter;
 use crate::testapp::ibc::core::types::DEFAULT_BLOCK_TIME_SECS;
+use crate::utils::events::TypedEvent;
 
 /// A context implementing the dependencies necessary for testing any IBC module.
 #[derive(Debug)]
@@ -61,6 +72,42 @@ pub type MockContext = TestContext<MockHost>;
 /// A [`StoreGenericTestContext`] using [`MockStore`] and [`TendermintHost`].
 pub type TendermintContext = TestContext<TendermintHost>;
 
+/// The top-level prefixes of the paths the IBC store writes to.
+const IBC_STORE_PREFIXES: [&str; 13] = [
+    NEXT_CLIENT_SEQUENCE,
+    NEXT_CONNECTION_SEQUENCE,
+    NEXT_CHANNEL_SEQUENCE,
+    CLIENT_PREFIX,
+    CONNECTION_PREFIX,
+    CHANNEL_END_PREFIX,
+    CHANNEL_UPGRADE_PREFIX,
+    NEXT_SEQ_SEND_PREFIX,
+    NEXT_SEQ_RECV_PREFIX,
+    NEXT_SEQ_ACK_PREFIX,
+    PACKET_COMMITMENT_PREFIX,
+    PACKET_ACK_PREFIX,
+    PACKET_RECEIPT_PREFIX,
+];
+
+/// The paths of the IBC store that differ between two contexts, as computed
+/// by [`StoreGenericTestContext::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// Paths only present in the other context.
+    pub added: Vec<Path>,
+    /// Paths only present in this context.
+    pub removed: Vec<Path>,
+    /// Paths present in both contexts, under different values.
+    pub changed: Vec<Path>,
+}
+
+impl StateDiff {
+    /// Returns `true` if both contexts hold the same IBC state.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
 /// Returns a [`StoreGenericTestContext`] with bare minimum initialization: no clients, no connections, and no channels are
 /// present, and the chain has Height(5). This should be used sparingly, mostly for testing the
 /// creation of new domain objects.
@@ -249,6 +296,19 @@ where
         )
     }
 
+    /// Advances the host chain height by `n` blocks using default parameters.
+    pub fn advance_block_height_by(&mut self, n: u64) {
+        for _ in 0..n {
+            self.advance_block_height()
+        }
+    }
+
+    /// Advances the host chain height by a single block, produced `block_time`
+    /// after the latest one.
+    pub fn advance_block_time(&mut self, block_time: Duration) {
+        self.advance_block_height_with_params(block_time, &Default::default())
+    }
+
     /// Returns the latest height of the host chain.
     pub fn latest_height(&self) -> Height {
         let latest_ibc_height = self.ibc_store.host_height().expect("Never fails");
@@ -282,6 +342,66 @@ where
         self
     }
 
+    /// Freezes the client with the given [`ClientId`] at `frozen_height`.
+    ///
+    /// Tendermint clients record the frozen height in their client state, whereas
+    /// mock clients are only marked as frozen and have the height recorded in
+    /// the IBC store.
+    pub fn freeze_client(&mut self, client_id: &ClientId, frozen_height: Height) {
+        let client_state = self
+            .ibc_store
+            .client_state(client_id)
+            .expect("client state exists");
+
+        let frozen_client_state: AnyClientState = match client_state {
+            AnyClientState::Tendermint(cs) => {
+                cs.inner().clone().with_frozen_height(frozen_height).into()
+            }
+            AnyClientState::Mock(cs) => {
+                self.ibc_store
+                    .mock_frozen_heights
+                    .lock()
+                    .insert(client_id.clone(), frozen_height);
+                cs.frozen().into()
+            }
+        };
+
+        self.ibc_store
+            .store_client_state(ClientStatePath::new(client_id.clone()), frozen_client_state)
+            .expect("error writing to store");
+    }
+
+    /// Bootstraps the context with a frozen client. See [`Self::freeze_client`].
+    pub fn with_frozen_client(mut self, client_id: &ClientId, frozen_height: Height) -> Self {
+        self.freeze_client(client_id, frozen_height);
+        self
+    }
+
+    /// Returns the height at which the client with the given [`ClientId`] was
+    /// frozen, or `None` if the client is not frozen.
+    ///
+    /// Mock clients frozen by other means than [`Self::freeze_client`] (e.g. by
+    /// misbehaviour) report their latest height.
+    pub fn frozen_height(&self, client_id: &ClientId) -> Option<Height> {
+        let client_state = self
+            .ibc_store
+            .client_state(client_id)
+            .expect("client state exists");
+
+        match client_state {
+            AnyClientState::Tendermint(cs) => cs.inner().frozen_height,
+            AnyClientState::Mock(cs) if cs.is_frozen() => Some(
+                self.ibc_store
+                    .mock_frozen_heights
+                    .lock()
+                    .get(client_id)
+                    .copied()
+                    .unwrap_or_else(|| cs.latest_height()),
+            ),
+            AnyClientState::Mock(_) => None,
+        }
+    }
+
     /// Bootstraps the context with a consensus state and its corresponding [`ClientId`] and [`Height`].
     pub fn with_consensus_state(
         mut self,
@@ -381,6 +501,64 @@ where
         self
     }
 
+    /// Binds a port to the given module in this context.
+    ///
+    /// This only records the binding for queries; routing is still done by the
+    /// [`MockRouter`].
+    pub fn with_port_binding(self, port_id: PortId, module_id: ModuleId) -> Self {
+        self.ibc_store
+            .port_bindings
+            .lock()
+            .insert(port_id, module_id);
+        self
+    }
+
+    /// Caps the number of messages a single transaction may carry in this
+    /// context.
+    pub fn with_max_messages_per_tx(mut self, max: usize) -> Self {
+        self.ibc_store.max_messages_per_tx = Some(max);
+        self
+    }
+
+    /// Lets relayers prune the acknowledgements they proved relayed in this
+    /// context, keeping the given number of sequences below the pruned ones.
+    pub fn with_ack_retention_window(mut self, window: u64) -> Self {
+        self.ibc_store.ack_retention_window = Some(window);
+        self
+    }
+
+    /// Computes packet and acknowledgement commitments with the given hasher
+    /// in this context, instead of SHA-256.
+    pub fn with_commitment_hasher(
+        mut self,
+        hasher: impl CommitmentHasher + Send + Sync + 'static,
+    ) -> Self {
+        self.ibc_store.commitment_hasher = Arc::new(hasher);
+        self
+    }
+
+    /// Only accepts messages signed by bech32 accounts carrying the given
+    /// prefix in this context.
+    pub fn with_signer_bech32_prefix(mut self, hrp: impl Into<String>) -> Self {
+        self.ibc_store.signer_bech32_prefix = Some(hrp.into());
+        self
+    }
+
+    /// Only lets the given signer initiate channel upgrades, and cancel them
+    /// without the consent of the counterparty, in this context. By default,
+    /// any signer may.
+    pub fn with_upgrade_authority(mut self, signer: Signer) -> Self {
+        self.ibc_store.upgrade_authority = Some(signer);
+        self
+    }
+
+    /// Exposes the given chain identifier to the handlers as the one of the
+    /// host.
+    pub fn with_host_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.ibc_store.host_chain_id = Some(chain_id);
+        self
+    }
+
     /// Bootstraps a IBC channel to this context.
     ///
     /// This does not bootstrap any corresponding IBC connection or light client.
@@ -397,6 +575,39 @@ where
         self
     }
 
+    /// Bootstraps an upgrade proposed for a IBC channel to this context.
+    ///
+    /// This does not bootstrap the corresponding IBC channel, connection or light client.
+    pub fn with_channel_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let upgrade_path = ChannelUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_channel_upgrade(&upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps the upgrade a counterparty agreed to for a IBC channel to
+    /// this context.
+    ///
+    /// This does not bootstrap the corresponding IBC channel, connection or light client.
+    pub fn with_counterparty_channel_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let upgrade_path = ChannelCounterpartyUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_counterparty_channel_upgrade(&upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
     /// Bootstraps a send sequence to this context.
     ///
     /// This does not bootstrap any corresponding IBC channel, connection or light client.
@@ -493,15 +704,72 @@ where
         self.ibc_store.events.lock().clone()
     }
 
+    /// Returns the events of type `E` emitted by the context's IBC store, in
+    /// emission order.
+    pub fn events_of_type<E: TypedEvent>(&self) -> Vec<E> {
+        self.ibc_store
+            .events
+            .lock()
+            .iter()
+            .filter_map(E::from_ibc_event)
+            .cloned()
+            .collect()
+    }
+
     /// Returns all the logs that have been emitted by the context's IBC store.
     pub fn get_logs(&self) -> Vec<String> {
         self.ibc_store.logs.lock().clone()
     }
+
+    /// Returns the summaries of all the messages executed by the context's
+    /// IBC store, along with the events each emitted.
+    pub fn get_executed_msgs(&self) -> Vec<(MsgSummary, Vec<IbcEvent>)> {
+        self.ibc_store.executed_msgs.lock().clone()
+    }
+
+    /// Returns the pending entries of the context's IBC store, keyed by path.
+    fn ibc_store_entries(&self) -> BTreeMap<Path, Vec<u8>> {
+        let store = &self.ibc_store.store;
+
+        IBC_STORE_PREFIXES
+            .into_iter()
+            .flat_map(|prefix| store.get_keys(&prefix.to_owned().into()))
+            .filter_map(|key| {
+                let value = store.get(StoreHeight::Pending, &key)?;
+                let path = key.try_into().ok()?;
+                Some((path, value))
+            })
+            .collect()
+    }
+
+    /// Compares the IBC store of this context with the one of `other`,
+    /// listing the paths `other` added, removed or changed.
+    ///
+    /// Only the data written under ICS-24 paths is compared; the events,
+    /// logs and other bookkeeping of the contexts are ignored.
+    pub fn diff(&self, other: &Self) -> StateDiff {
+        let before = self.ibc_store_entries();
+        let mut after = other.ibc_store_entries();
+
+        let mut diff = StateDiff::default();
+
+        for (path, value) in before {
+            match after.remove(&path) {
+                Some(other_value) if other_value != value => diff.changed.push(path),
+                Some(_) => {}
+                None => diff.removed.push(path),
+            }
+        }
+        diff.added.extend(after.into_keys());
+
+        diff
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use ibc::core::client::context::consensus_state::ConsensusState;
+    use ibc_query::core::client::{query_host_params, QueryHostParamsRequest};
 
     use super::*;
     use crate::hosts::{HostConsensusState, MockHost, TendermintHost};
@@ -609,4 +877,24 @@ mod tests {
         run_tests::<MockHost>("Mock Host");
         run_tests::<TendermintHost>("Synthetic TM Host");
     }
+
+    #[test]
+    fn test_host_revision_number() {
+        // a host that resumed at the revision of an upgrade plan after upgrading
+        let chain_id = ChainId::new("mockgaia-1").expect("Never fails");
+
+        let ctx = TestContextConfig::builder()
+            .host(MockHost::builder().chain_id(chain_id.clone()).build())
+            .latest_height(Height::new(chain_id.revision_number(), 5).expect("Never fails"))
+            .build::<MockContext>();
+
+        let response =
+            query_host_params(&ctx.ibc_store, &QueryHostParamsRequest).expect("Never fails");
+
+        assert_eq!(response.revision_number, chain_id.revision_number());
+        assert_eq!(
+            response.query_height,
+            ctx.ibc_store.host_height().expect("Never fails")
+        );
+    }
 }
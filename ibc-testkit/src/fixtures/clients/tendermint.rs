@@ -5,19 +5,21 @@ use basecoin_store::avl::get_proof_spec as basecoin_proof_spec;
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::error::{Error as ClientError, Error};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
-#[cfg(feature = "serde")]
-use ibc::clients::tendermint::types::Header;
 use ibc::clients::tendermint::types::{
-    AllowUpdate, ClientState as ClientStateType, TrustThreshold,
+    AllowUpdate, ClientState as ClientStateType, Header, TrustThreshold,
 };
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::host::types::identifiers::ChainId;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
 use tendermint::block::Header as TmHeader;
 use typed_builder::TypedBuilder;
 
+use crate::context::TendermintContext;
+use crate::hosts::{TestBlock, TestHost};
+
 /// Returns a dummy tendermint `ClientState` by given `frozen_height`, for testing purposes only!
 pub fn dummy_tm_client_state_from_raw(frozen_height: RawHeight) -> Result<TmClientState, Error> {
     ClientStateType::try_from(dummy_raw_tm_client_state(frozen_height)).map(TmClientState::from)
@@ -172,6 +174,35 @@ pub fn dummy_ics07_header() -> Header {
     }
 }
 
+/// Returns two conflicting headers of the chain hosted by `ctx` at `height`,
+/// both trusting the consensus state at `trusted_height`.
+///
+/// The first one is the header of the block committed by the chain, the second
+/// one is the header of an equivocal block signed by the same validators.
+/// Submitted together as a `Misbehaviour`, they freeze a Tendermint client
+/// tracking that chain.
+pub fn dummy_conflicting_tm_headers(
+    ctx: &TendermintContext,
+    trusted_height: Height,
+    height: Height,
+) -> (Header, Header) {
+    let mut header_1 = ctx.host_block(&height).expect("block exists").into_header();
+    header_1.set_trusted_height(trusted_height);
+
+    let mut header_2 = ctx
+        .host
+        .generate_block(
+            Vec::new(),
+            height.revision_height(),
+            Timestamp::now(),
+            &Default::default(),
+        )
+        .into_header();
+    header_2.set_trusted_height(trusted_height);
+
+    (header_1.into(), header_2.into())
+}
+
 #[cfg(all(test, feature = "serde"))]
 mod tests {
 
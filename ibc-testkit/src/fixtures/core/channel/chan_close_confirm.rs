@@ -70,6 +70,14 @@ mod tests {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Upgraded counterparty channel".to_string(),
+                raw: RawMsgChannelCloseConfirm {
+                    counterparty_upgrade_sequence: 1,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
             Test {
                 name: "Correct channel identifier".to_string(),
                 raw: RawMsgChannelCloseConfirm {
@@ -0,0 +1,110 @@
+use ibc::core::channel::types::proto::v1::{
+    MsgChannelUpgradeAck as RawMsgChannelUpgradeAck, Timeout as RawTimeout, Upgrade as RawUpgrade,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade_fields};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawUpgrade` agreed to by a counterparty, timing out at
+/// the given timestamp, for testing purposes only!
+pub fn dummy_raw_upgrade(
+    version: &str,
+    timeout_timestamp: u64,
+    next_sequence_send: u64,
+) -> RawUpgrade {
+    RawUpgrade {
+        fields: Some(dummy_raw_upgrade_fields(version)),
+        timeout: Some(RawTimeout {
+            height: Some(Height {
+                revision_number: 0,
+                revision_height: 0,
+            }),
+            timestamp: timeout_timestamp,
+        }),
+        next_sequence_send,
+    }
+}
+
+/// Returns a dummy `RawMsgChannelUpgradeAck`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_ack(
+    proof_height: u64,
+    counterparty_upgrade: RawUpgrade,
+) -> RawMsgChannelUpgradeAck {
+    RawMsgChannelUpgradeAck {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_upgrade: Some(counterparty_upgrade),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeAck;
+
+    use super::*;
+
+    #[test]
+    fn channel_upgrade_ack_from_raw() {
+        let default_raw_msg =
+            dummy_raw_msg_chan_upgrade_ack(10, dummy_raw_upgrade("ics20-2", u64::MAX, 1));
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Missing counterparty upgrade",
+                RawMsgChannelUpgradeAck {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing upgrade proof",
+                RawMsgChannelUpgradeAck {
+                    proof_upgrade: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgChannelUpgradeAck {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeAck::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeAck::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_ack(10, dummy_raw_upgrade("ics20-2", u64::MAX, 1));
+        let msg = MsgChannelUpgradeAck::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeAck::from(msg.clone());
+        let msg_back = MsgChannelUpgradeAck::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
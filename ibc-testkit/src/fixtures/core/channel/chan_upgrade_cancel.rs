@@ -0,0 +1,62 @@
+use ibc::core::channel::types::proto::v1::{
+    ErrorReceipt as RawErrorReceipt, MsgChannelUpgradeCancel as RawMsgChannelUpgradeCancel,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeCancel` proving the error receipt of
+/// the counterparty for the given upgrade sequence, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_cancel(
+    proof_height: u64,
+    error_receipt_sequence: u64,
+) -> RawMsgChannelUpgradeCancel {
+    RawMsgChannelUpgradeCancel {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        error_receipt: Some(RawErrorReceipt {
+            sequence: error_receipt_sequence,
+            message: "upgrade aborted".to_string(),
+        }),
+        proof_error_receipt: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeCancel;
+
+    use super::*;
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_cancel(10, 1);
+        let msg = MsgChannelUpgradeCancel::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeCancel::from(msg.clone());
+        let msg_back = MsgChannelUpgradeCancel::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    #[test]
+    fn to_and_from_without_error_receipt() {
+        let raw = RawMsgChannelUpgradeCancel {
+            error_receipt: None,
+            proof_error_receipt: Vec::new(),
+            proof_height: None,
+            ..dummy_raw_msg_chan_upgrade_cancel(10, 1)
+        };
+        let msg = MsgChannelUpgradeCancel::try_from(raw.clone()).unwrap();
+        assert!(msg.proof_error_receipt_on_b.is_none());
+        let raw_back = RawMsgChannelUpgradeCancel::from(msg);
+        assert_eq!(raw, raw_back);
+    }
+}
@@ -0,0 +1,94 @@
+use ibc::core::channel::types::proto::v1::{
+    MsgChannelUpgradeConfirm as RawMsgChannelUpgradeConfirm, Upgrade as RawUpgrade,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeConfirm`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_confirm(
+    proof_height: u64,
+    counterparty_channel_state: i32,
+    counterparty_upgrade: RawUpgrade,
+) -> RawMsgChannelUpgradeConfirm {
+    RawMsgChannelUpgradeConfirm {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_channel_state,
+        counterparty_upgrade: Some(counterparty_upgrade),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeConfirm;
+
+    use super::*;
+    use crate::fixtures::core::channel::dummy_raw_upgrade;
+
+    #[test]
+    fn channel_upgrade_confirm_from_raw() {
+        let default_raw_msg =
+            dummy_raw_msg_chan_upgrade_confirm(10, 5, dummy_raw_upgrade("ics20-2", u64::MAX, 1));
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Invalid counterparty channel state",
+                RawMsgChannelUpgradeConfirm {
+                    counterparty_channel_state: 42,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing counterparty upgrade",
+                RawMsgChannelUpgradeConfirm {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgChannelUpgradeConfirm {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeConfirm::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeConfirm::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw =
+            dummy_raw_msg_chan_upgrade_confirm(10, 5, dummy_raw_upgrade("ics20-2", u64::MAX, 1));
+        let msg = MsgChannelUpgradeConfirm::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeConfirm::from(msg.clone());
+        let msg_back = MsgChannelUpgradeConfirm::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
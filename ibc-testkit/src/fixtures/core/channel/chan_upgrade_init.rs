@@ -0,0 +1,105 @@
+use ibc::core::channel::types::proto::v1::{
+    MsgChannelUpgradeInit as RawMsgChannelUpgradeInit, UpgradeFields as RawUpgradeFields,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawUpgradeFields` proposing an unordered channel over the
+/// default connection, for testing purposes only!
+pub fn dummy_raw_upgrade_fields(version: &str) -> RawUpgradeFields {
+    RawUpgradeFields {
+        ordering: 1,
+        connection_hops: vec![ConnectionId::zero().to_string()],
+        version: version.to_string(),
+    }
+}
+
+/// Returns a dummy `RawMsgChannelUpgradeInit`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_init() -> RawMsgChannelUpgradeInit {
+    RawMsgChannelUpgradeInit {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        fields: Some(dummy_raw_upgrade_fields("ics20-2")),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeInit;
+
+    use super::*;
+
+    #[test]
+    fn channel_upgrade_init_from_raw() {
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_init();
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Missing upgrade fields",
+                RawMsgChannelUpgradeInit {
+                    fields: None,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Empty upgrade version",
+                RawMsgChannelUpgradeInit {
+                    fields: Some(dummy_raw_upgrade_fields("")),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "None upgrade ordering",
+                RawMsgChannelUpgradeInit {
+                    fields: Some(RawUpgradeFields {
+                        ordering: 0,
+                        ..dummy_raw_upgrade_fields("ics20-2")
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Too many connection hops",
+                RawMsgChannelUpgradeInit {
+                    fields: Some(RawUpgradeFields {
+                        connection_hops: vec![
+                            ConnectionId::zero().to_string(),
+                            ConnectionId::new(1).to_string(),
+                        ],
+                        ..dummy_raw_upgrade_fields("ics20-2")
+                    }),
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeInit::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeInit::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_init();
+        let msg = MsgChannelUpgradeInit::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeInit::from(msg.clone());
+        let msg_back = MsgChannelUpgradeInit::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
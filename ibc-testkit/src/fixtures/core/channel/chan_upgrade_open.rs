@@ -0,0 +1,88 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeOpen`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_open(
+    proof_height: u64,
+    counterparty_channel_state: i32,
+    counterparty_upgrade_sequence: u64,
+) -> RawMsgChannelUpgradeOpen {
+    RawMsgChannelUpgradeOpen {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_channel_state,
+        counterparty_upgrade_sequence,
+        proof_channel: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeOpen;
+
+    use super::*;
+
+    #[test]
+    fn channel_upgrade_open_from_raw() {
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_open(10, 6, 1);
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Invalid counterparty channel state",
+                RawMsgChannelUpgradeOpen {
+                    counterparty_channel_state: 42,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing channel proof",
+                RawMsgChannelUpgradeOpen {
+                    proof_channel: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgChannelUpgradeOpen {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeOpen::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeOpen::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_open(10, 6, 1);
+        let msg = MsgChannelUpgradeOpen::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeOpen::from(msg.clone());
+        let msg_back = MsgChannelUpgradeOpen::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,82 @@
+use ibc::core::channel::types::proto::v1::{
+    Channel as RawChannel, MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeTimeout`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_timeout(
+    proof_height: u64,
+    counterparty_channel: RawChannel,
+) -> RawMsgChannelUpgradeTimeout {
+    RawMsgChannelUpgradeTimeout {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        counterparty_channel: Some(counterparty_channel),
+        proof_channel: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeTimeout;
+
+    use super::*;
+    use crate::fixtures::core::channel::dummy_raw_channel_end;
+
+    #[test]
+    fn channel_upgrade_timeout_from_raw() {
+        let default_raw_msg =
+            dummy_raw_msg_chan_upgrade_timeout(10, dummy_raw_channel_end(5, Some(0)));
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Missing counterparty channel",
+                RawMsgChannelUpgradeTimeout {
+                    counterparty_channel: None,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgChannelUpgradeTimeout {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeTimeout::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeTimeout::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_timeout(10, dummy_raw_channel_end(5, Some(0)));
+        let msg = MsgChannelUpgradeTimeout::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTimeout::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTimeout::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,97 @@
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade_fields};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeTry`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_try(
+    proof_height: u64,
+    counterparty_upgrade_sequence: u64,
+) -> RawMsgChannelUpgradeTry {
+    RawMsgChannelUpgradeTry {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        proposed_upgrade_connection_hops: vec![ConnectionId::zero().to_string()],
+        counterparty_upgrade_fields: Some(dummy_raw_upgrade_fields("ics20-2")),
+        counterparty_upgrade_sequence,
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeTry;
+
+    use super::*;
+
+    #[test]
+    fn channel_upgrade_try_from_raw() {
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_try(10, 1);
+
+        let tests = [
+            ("Good parameters", default_raw_msg.clone(), true),
+            (
+                "Missing counterparty upgrade fields",
+                RawMsgChannelUpgradeTry {
+                    counterparty_upgrade_fields: None,
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proposed connection hops",
+                RawMsgChannelUpgradeTry {
+                    proposed_upgrade_connection_hops: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing upgrade proof",
+                RawMsgChannelUpgradeTry {
+                    proof_upgrade: Vec::new(),
+                    ..default_raw_msg.clone()
+                },
+                false,
+            ),
+            (
+                "Missing proof height",
+                RawMsgChannelUpgradeTry {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                false,
+            ),
+        ];
+
+        for (name, raw, want_pass) in tests {
+            let res_msg = MsgChannelUpgradeTry::try_from(raw.clone());
+
+            assert_eq!(
+                want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeTry::try_from failed for test {name}, \nraw msg {raw:?} with error {:?}",
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_try(10, 1);
+        let msg = MsgChannelUpgradeTry::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTry::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTry::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
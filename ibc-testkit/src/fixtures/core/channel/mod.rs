@@ -5,6 +5,13 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
 mod packet;
 mod recv_packet;
 mod timeout;
@@ -23,6 +30,13 @@ pub use self::chan_open_ack::*;
 pub use self::chan_open_confirm::*;
 pub use self::chan_open_init::*;
 pub use self::chan_open_try::*;
+pub use self::chan_upgrade_ack::*;
+pub use self::chan_upgrade_cancel::*;
+pub use self::chan_upgrade_confirm::*;
+pub use self::chan_upgrade_init::*;
+pub use self::chan_upgrade_open::*;
+pub use self::chan_upgrade_timeout::*;
+pub use self::chan_upgrade_try::*;
 pub use self::packet::*;
 pub use self::recv_packet::*;
 pub use self::timeout::*;
@@ -57,6 +57,14 @@ mod tests {
                 raw: default_raw_msg.clone(),
                 want_pass: true,
             },
+            Test {
+                name: "Upgraded counterparty channel".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    counterparty_upgrade_sequence: 1,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
             Test {
                 name: "Missing packet".to_string(),
                 raw: RawMsgTimeoutOnClose {
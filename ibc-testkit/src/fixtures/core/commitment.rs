@@ -1,5 +1,6 @@
 use ibc::core::commitment_types::commitment::CommitmentProofBytes;
-use ibc::core::commitment_types::proto::ics23::CommitmentProof;
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{CommitmentProof, ExistenceProof};
 use ibc::core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
 use ibc::core::primitives::prelude::*;
 
@@ -12,3 +13,22 @@ pub fn dummy_commitment_proof_bytes() -> CommitmentProofBytes {
         .try_into()
         .expect("could not convert to CommitmentProofBytes")
 }
+
+/// Returns a mock proof that `value` is stored, which the mock client checks
+/// against the value it verifies, for testing purposes only!
+pub fn dummy_membership_proof_bytes(value: Vec<u8>) -> CommitmentProofBytes {
+    let parsed = CommitmentProof {
+        proof: Some(Proof::Exist(ExistenceProof {
+            key: Vec::new(),
+            value,
+            leaf: None,
+            path: Vec::new(),
+        })),
+    };
+    let raw_mp = RawMerkleProof {
+        proofs: vec![parsed],
+    };
+    raw_mp
+        .try_into()
+        .expect("could not convert to CommitmentProofBytes")
+}
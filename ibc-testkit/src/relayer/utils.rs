@@ -664,12 +664,19 @@ where
             .try_into()
             .expect("value merkle proof");
 
+        let counterparty_upgrade_sequence = ctx_a
+            .ibc_store()
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id_on_b))
+            .expect("channel end exists")
+            .upgrade_sequence();
+
         let msg_for_b = MsgEnvelope::Channel(ChannelMsg::CloseConfirm(MsgChannelCloseConfirm {
             port_id_on_b,
             chan_id_on_b,
             proof_chan_end_on_a,
             proof_height_on_a,
             signer,
+            counterparty_upgrade_sequence,
         }));
 
         ctx_b.deliver(msg_for_b).expect("success");
@@ -939,6 +946,12 @@ where
             .try_into()
             .expect("value merkle proof");
 
+        let counterparty_upgrade_sequence = ctx_b
+            .ibc_store()
+            .channel_end(&ChannelEndPath::new(&port_id_on_b, &chan_id_on_b))
+            .expect("channel end exists")
+            .upgrade_sequence();
+
         let msg_for_a = MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(MsgTimeoutOnClose {
             next_seq_recv_on_b: packet.seq_on_a,
             packet,
@@ -946,6 +959,7 @@ where
             proof_close_on_b,
             proof_height_on_b,
             signer,
+            counterparty_upgrade_sequence,
         }));
 
         ctx_a.deliver(msg_for_a).expect("success");
@@ -0,0 +1,97 @@
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::{Fee, PacketFee};
+use ibc::apps::transfer::types::{Amount, PrefixedCoin};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::path::FeesInEscrowPath;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+/// The dummy transfer module doubles as the fee context of the fee middleware
+/// wrapping it, with its fee escrow held in [`DummyTransferModule::fees`].
+impl FeeValidationContext for DummyTransferModule {
+    type AccountId = Signer;
+
+    fn fees_in_escrow(&self, path: &FeesInEscrowPath) -> Result<Vec<PacketFee>, FeeError> {
+        Ok(self
+            .fees
+            .fees_in_escrow
+            .get(path)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, FeeError> {
+        Ok(self
+            .fees
+            .fee_enabled_channels
+            .contains(&(port_id.clone(), channel_id.clone())))
+    }
+
+    fn escrow_fee_validate(&self, _payer: &Self::AccountId, _fee: &Fee) -> Result<(), FeeError> {
+        Ok(())
+    }
+}
+
+impl FeeExecutionContext for DummyTransferModule {
+    fn escrow_fee_execute(&mut self, _payer: &Self::AccountId, fee: &Fee) -> Result<(), FeeError> {
+        for coin in fee.total() {
+            let escrowed = self
+                .fees
+                .escrowed
+                .entry(coin.denom)
+                .or_insert_with(|| Amount::from(0));
+            *escrowed = escrowed
+                .checked_add(coin.amount)
+                .ok_or_else(|| FeeError::Other("escrowed amount overflow".to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        // compute the remaining balances first, so that nothing is moved on
+        // failure
+        let mut escrowed = self.fees.escrowed.clone();
+        for coin in coins {
+            let available =
+                escrowed
+                    .get_mut(&coin.denom)
+                    .ok_or_else(|| FeeError::InsufficientFunds {
+                        fee: coin.to_string(),
+                        available_funds: "0".to_string(),
+                    })?;
+            *available =
+                available
+                    .checked_sub(coin.amount)
+                    .ok_or_else(|| FeeError::InsufficientFunds {
+                        fee: coin.to_string(),
+                        available_funds: available.to_string(),
+                    })?;
+        }
+        self.fees.escrowed = escrowed;
+        self.fees
+            .distributed
+            .push((receiver.clone(), coins.to_vec()));
+        Ok(())
+    }
+
+    fn store_fees_in_escrow(
+        &mut self,
+        path: &FeesInEscrowPath,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        self.fees.fees_in_escrow.insert(path.clone(), packet_fees);
+        Ok(())
+    }
+
+    fn delete_fees_in_escrow(&mut self, path: &FeesInEscrowPath) -> Result<(), FeeError> {
+        self.fees.fees_in_escrow.remove(path);
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::apps::fee::types::PacketFee;
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::path::FeesInEscrowPath;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// Holds the fee escrow of the fee middleware.
+#[derive(Debug, Default)]
+pub struct DummyFeeContext {
+    /// Channels on which relayers may be incentivized.
+    pub fee_enabled_channels: BTreeSet<(PortId, ChannelId)>,
+    /// Fees escrowed per packet.
+    pub fees_in_escrow: BTreeMap<FeesInEscrowPath, Vec<PacketFee>>,
+    /// Tracks the amount of each denom held by the fee escrow.
+    pub escrowed: BTreeMap<PrefixedDenom, Amount>,
+    /// Coins paid out of the fee escrow, in order.
+    pub distributed: Vec<(Signer, Vec<PrefixedCoin>)>,
+}
+
+impl DummyFeeContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables fee incentivization on the given channel.
+    pub fn with_fee_enabled_channel(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.fee_enabled_channels.insert((port_id, channel_id));
+        self
+    }
+}
@@ -1,2 +1,4 @@
+pub mod fee;
 pub mod nft_transfer;
+pub mod rate_limit;
 pub mod transfer;
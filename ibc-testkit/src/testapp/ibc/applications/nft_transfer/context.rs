@@ -60,6 +60,10 @@ impl NftTransferValidationContext for DummyNftTransferModule {
         Ok(())
     }
 
+    fn max_memo_size(&self) -> Option<usize> {
+        self.max_memo_size
+    }
+
     fn create_or_update_class_validate(
         &self,
         _class_id: &PrefixedClassId,
@@ -113,6 +117,16 @@ impl NftTransferValidationContext for DummyNftTransferModule {
         Ok(())
     }
 
+    fn token_escrow_info(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Option<(PortId, ChannelId)> {
+        self.escrowed_nfts
+            .get(&(class_id.clone(), token_id.clone()))
+            .cloned()
+    }
+
     fn get_nft(
         &self,
         _class_id: &PrefixedClassId,
@@ -142,12 +156,16 @@ impl NftTransferExecutionContext for DummyNftTransferModule {
     fn escrow_nft_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), NftTransferError> {
+        self.escrowed_nfts.insert(
+            (class_id.clone(), token_id.clone()),
+            (port_id.clone(), channel_id.clone()),
+        );
         Ok(())
     }
 
@@ -156,9 +174,11 @@ impl NftTransferExecutionContext for DummyNftTransferModule {
         _to_account: &Self::AccountId,
         _port_id: &PortId,
         _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
     ) -> Result<(), NftTransferError> {
+        self.escrowed_nfts
+            .remove(&(class_id.clone(), token_id.clone()));
         Ok(())
     }
 
@@ -1,7 +1,17 @@
-use ibc::apps::nft_transfer::types::{ClassData, ClassId, ClassUri, TokenData, TokenId, TokenUri};
+use alloc::collections::BTreeMap;
+
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassId, ClassUri, PrefixedClassId, TokenData, TokenId, TokenUri,
+};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
 
 #[derive(Debug)]
-pub struct DummyNftTransferModule;
+pub struct DummyNftTransferModule {
+    /// Tracks the port and channel through which each NFT is escrowed.
+    pub escrowed_nfts: BTreeMap<(PrefixedClassId, TokenId), (PortId, ChannelId)>,
+    /// Maximum size, in bytes, of the memo accepted in NFT transfers.
+    pub max_memo_size: Option<usize>,
+}
 
 #[derive(Debug)]
 pub struct DummyNft {
@@ -50,7 +60,10 @@ impl Default for DummyNftClass {
 
 impl DummyNftTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            escrowed_nfts: BTreeMap::new(),
+            max_memo_size: None,
+        }
     }
 }
 
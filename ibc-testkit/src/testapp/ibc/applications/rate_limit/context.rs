@@ -0,0 +1,150 @@
+use ibc::apps::rate_limit::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use ibc::apps::rate_limit::error::RateLimitError;
+use ibc::apps::rate_limit::types::{Flow, Quota};
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Timestamp;
+
+use super::types::{DummyRateLimitContext, RateLimitKey};
+use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+fn key(port_id: &PortId, channel_id: &ChannelId, denom: &PrefixedDenom) -> RateLimitKey {
+    (port_id.clone(), channel_id.clone(), denom.clone())
+}
+
+impl RateLimitValidationContext for DummyRateLimitContext {
+    fn host_timestamp(&self) -> Result<Timestamp, RateLimitError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn quota(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Quota>, RateLimitError> {
+        Ok(self.quotas.get(&key(port_id, channel_id, denom)).cloned())
+    }
+
+    fn flow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Flow>, RateLimitError> {
+        Ok(self.flows.get(&key(port_id, channel_id, denom)).cloned())
+    }
+}
+
+impl RateLimitExecutionContext for DummyRateLimitContext {
+    fn store_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        quota: Quota,
+    ) -> Result<(), RateLimitError> {
+        self.quotas.insert(key(port_id, channel_id, denom), quota);
+        Ok(())
+    }
+
+    fn delete_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError> {
+        self.quotas.remove(&key(port_id, channel_id, denom));
+        Ok(())
+    }
+
+    fn store_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        flow: Flow,
+    ) -> Result<(), RateLimitError> {
+        self.flows.insert(key(port_id, channel_id, denom), flow);
+        Ok(())
+    }
+
+    fn delete_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError> {
+        self.flows.remove(&key(port_id, channel_id, denom));
+        Ok(())
+    }
+}
+
+/// The dummy transfer module doubles as the context of the rate-limit
+/// middleware wrapping it, with its quotas and flows held in
+/// [`DummyTransferModule::rate_limits`].
+impl RateLimitValidationContext for DummyTransferModule {
+    fn host_timestamp(&self) -> Result<Timestamp, RateLimitError> {
+        self.rate_limits.host_timestamp()
+    }
+
+    fn quota(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Quota>, RateLimitError> {
+        self.rate_limits.quota(port_id, channel_id, denom)
+    }
+
+    fn flow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Flow>, RateLimitError> {
+        self.rate_limits.flow(port_id, channel_id, denom)
+    }
+}
+
+impl RateLimitExecutionContext for DummyTransferModule {
+    fn store_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        quota: Quota,
+    ) -> Result<(), RateLimitError> {
+        self.rate_limits
+            .store_quota(port_id, channel_id, denom, quota)
+    }
+
+    fn delete_quota(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError> {
+        self.rate_limits.delete_quota(port_id, channel_id, denom)
+    }
+
+    fn store_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        flow: Flow,
+    ) -> Result<(), RateLimitError> {
+        self.rate_limits
+            .store_flow(port_id, channel_id, denom, flow)
+    }
+
+    fn delete_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError> {
+        self.rate_limits.delete_flow(port_id, channel_id, denom)
+    }
+}
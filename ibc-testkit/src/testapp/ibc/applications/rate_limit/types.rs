@@ -0,0 +1,28 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::rate_limit::types::{Flow, Quota};
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Timestamp;
+
+/// Identifies the quota and flow of a denom over a channel.
+pub type RateLimitKey = (PortId, ChannelId, PrefixedDenom);
+
+/// Holds the quotas and flows of the rate-limit middleware, along with a host
+/// timestamp that tests advance by hand.
+#[derive(Debug)]
+pub struct DummyRateLimitContext {
+    pub quotas: BTreeMap<RateLimitKey, Quota>,
+    pub flows: BTreeMap<RateLimitKey, Flow>,
+    pub host_timestamp: Timestamp,
+}
+
+impl DummyRateLimitContext {
+    pub fn new(host_timestamp: Timestamp) -> Self {
+        Self {
+            quotas: BTreeMap::new(),
+            flows: BTreeMap::new(),
+            host_timestamp,
+        }
+    }
+}
@@ -1,11 +1,56 @@
 use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use ibc::apps::transfer::types::error::TokenTransferError;
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{
+    Amount, Forwarding, Memo, PrefixedCoin, PrefixedDenom, TransferHook,
+};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
 use super::types::DummyTransferModule;
 
+impl DummyTransferModule {
+    fn supply_of(&self, denom: &PrefixedDenom) -> Amount {
+        self.denom_supply
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    fn increased_supply(&self, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+        self.supply_of(&coin.denom)
+            .checked_add(coin.amount)
+            .ok_or_else(|| TokenTransferError::AmountOverflow {
+                denom: coin.denom.to_string(),
+                amount: coin.amount.to_string(),
+            })
+    }
+
+    fn decreased_supply(&self, coin: &PrefixedCoin) -> Result<Amount, TokenTransferError> {
+        let supply = self.supply_of(&coin.denom);
+        supply
+            .checked_sub(coin.amount)
+            .ok_or_else(|| TokenTransferError::InsufficientFunds {
+                send_attempt: coin.amount.to_string(),
+                available_funds: supply.to_string(),
+            })
+    }
+
+    fn escrow_balance(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Amount {
+        self.escrowed
+            .get(&(port_id.clone(), channel_id.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+}
+
 impl TokenTransferValidationContext for DummyTransferModule {
     type AccountId = Signer;
 
@@ -44,18 +89,66 @@ impl TokenTransferValidationContext for DummyTransferModule {
     fn mint_coins_validate(
         &self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        self.increased_supply(coin).map(|_| ())
     }
 
     fn burn_coins_validate(
         &self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
-        Ok(())
+        self.decreased_supply(coin).map(|_| ())
+    }
+
+    fn get_denom_supply(
+        &self,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(Some(self.supply_of(denom)))
+    }
+
+    fn escrowed_amount(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        Ok(Some(self.escrow_balance(port_id, channel_id, denom)))
+    }
+
+    fn total_escrowed_amount(
+        &self,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<Amount>, TokenTransferError> {
+        self.escrowed
+            .iter()
+            .filter(|((_, _, escrowed_denom), _)| escrowed_denom == denom)
+            .try_fold(Amount::from(0), |total, (_, amount)| {
+                total
+                    .checked_add(*amount)
+                    .ok_or_else(|| TokenTransferError::AmountOverflow {
+                        denom: denom.to_string(),
+                        amount: amount.to_string(),
+                    })
+            })
+            .map(Some)
+    }
+
+    fn denom_trace(&self, hash: &str) -> Option<PrefixedDenom> {
+        self.denom_traces()
+            .into_iter()
+            .find(|denom| denom.denom_hash() == hash)
+    }
+
+    fn denom_traces(&self) -> Vec<PrefixedDenom> {
+        self.denom_supply
+            .keys()
+            .filter(|denom| !denom.trace_path.is_empty())
+            .cloned()
+            .collect()
     }
 }
 
@@ -63,38 +156,94 @@ impl TokenTransferExecutionContext for DummyTransferModule {
     fn escrow_coins_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
+        let escrowed = self
+            .escrow_balance(port_id, channel_id, &coin.denom)
+            .checked_add(coin.amount)
+            .ok_or_else(|| TokenTransferError::AmountOverflow {
+                denom: coin.denom.to_string(),
+                amount: coin.amount.to_string(),
+            })?;
+        self.escrowed.insert(
+            (port_id.clone(), channel_id.clone(), coin.denom.clone()),
+            escrowed,
+        );
         Ok(())
     }
 
     fn unescrow_coins_execute(
         &mut self,
         _to_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _coin: &PrefixedCoin,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
+        let escrowed = self.escrow_balance(port_id, channel_id, &coin.denom);
+        let remaining = escrowed.checked_sub(coin.amount).ok_or_else(|| {
+            TokenTransferError::EscrowInvariantViolated {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                denom: coin.denom.to_string(),
+                unescrow_attempt: coin.amount.to_string(),
+                escrowed: escrowed.to_string(),
+            }
+        })?;
+        self.escrowed.insert(
+            (port_id.clone(), channel_id.clone(), coin.denom.clone()),
+            remaining,
+        );
         Ok(())
     }
 
     fn mint_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
     ) -> Result<(), TokenTransferError> {
+        let supply = self.increased_supply(coin)?;
+        self.denom_supply.insert(coin.denom.clone(), supply);
         Ok(())
     }
 
     fn burn_coins_execute(
         &mut self,
         _account: &Self::AccountId,
-        _coin: &PrefixedCoin,
+        coin: &PrefixedCoin,
         _memo: &Memo,
     ) -> Result<(), TokenTransferError> {
+        let supply = self.decreased_supply(coin)?;
+        self.denom_supply.insert(coin.denom.clone(), supply);
+        Ok(())
+    }
+
+    fn forward_coins_execute(
+        &mut self,
+        _receiver: &Self::AccountId,
+        coin: &PrefixedCoin,
+        forwarding: &Forwarding,
+    ) -> Result<(), TokenTransferError> {
+        self.forwarded.push((coin.clone(), forwarding.clone()));
+        Ok(())
+    }
+
+    fn on_recv_transfer(
+        &mut self,
+        _packet: &Packet,
+        _data: &PacketData,
+        coin: &PrefixedCoin,
+        hook: TransferHook,
+    ) -> Result<(), TokenTransferError> {
+        if self.reject_recv_hooks {
+            return Err(TokenTransferError::Other(
+                "contract call rejected".to_string(),
+            ));
+        }
+
+        self.recv_hooks.push((hook, coin.clone()));
         Ok(())
     }
 }
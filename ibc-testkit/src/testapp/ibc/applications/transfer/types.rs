@@ -1,9 +1,52 @@
+use alloc::collections::BTreeMap;
+use core::fmt::Debug;
+
+use basecoin_store::context::ProvableStore;
+use ibc::apps::transfer::types::{Amount, Forwarding, PrefixedCoin, PrefixedDenom, TransferHook};
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ConnectionPath};
+use ibc::core::host::ExecutionContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use typed_builder::TypedBuilder;
+
+use crate::testapp::ibc::applications::fee::types::DummyFeeContext;
+use crate::testapp::ibc::applications::rate_limit::types::DummyRateLimitContext;
+use crate::testapp::ibc::core::types::MockIbcStore;
+
 #[derive(Debug)]
-pub struct DummyTransferModule;
+pub struct DummyTransferModule {
+    /// Tracks the supply of the IBC vouchers minted by this module.
+    pub denom_supply: BTreeMap<PrefixedDenom, Amount>,
+    /// Tracks the amount of each denom escrowed per channel.
+    pub escrowed: BTreeMap<(PortId, ChannelId, PrefixedDenom), Amount>,
+    /// Received coins handed off for forwarding, in order.
+    pub forwarded: Vec<(PrefixedCoin, Forwarding)>,
+    /// Coins credited by received transfers whose memo requested a contract
+    /// call, in order.
+    pub recv_hooks: Vec<(TransferHook, PrefixedCoin)>,
+    /// Makes the recv hook fail, as a contract call that errors out would.
+    pub reject_recv_hooks: bool,
+    /// The fee escrow, for when the module is wrapped in the fee middleware.
+    pub fees: DummyFeeContext,
+    /// The quotas and flows, for when the module is wrapped in the rate-limit
+    /// middleware.
+    pub rate_limits: DummyRateLimitContext,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            denom_supply: BTreeMap::new(),
+            escrowed: BTreeMap::new(),
+            forwarded: Vec::new(),
+            recv_hooks: Vec::new(),
+            reject_recv_hooks: false,
+            fees: DummyFeeContext::new(),
+            rate_limits: DummyRateLimitContext::new(Timestamp::none()),
+        }
     }
 }
 
@@ -12,3 +55,46 @@ impl Default for DummyTransferModule {
         Self::new()
     }
 }
+
+/// Builds a [`DummyTransferModule`] whose IBC store is seeded with the given
+/// connection and channel ends, so that the channel and connection lookups of
+/// the transfer handlers succeed without storing the ends by hand.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = init), build_method(into))]
+pub struct DummyTransferModuleBuilder<'a, S>
+where
+    S: ProvableStore + Debug,
+{
+    ibc_store: &'a mut MockIbcStore<S>,
+    #[builder(default)]
+    connections: Vec<(ConnectionId, ConnectionEnd)>,
+    #[builder(default)]
+    channels: Vec<(PortId, ChannelId, ChannelEnd)>,
+}
+
+impl<'a, S> From<DummyTransferModuleBuilder<'a, S>> for DummyTransferModule
+where
+    S: ProvableStore + Debug,
+{
+    fn from(builder: DummyTransferModuleBuilder<'a, S>) -> Self {
+        let DummyTransferModuleBuilder {
+            ibc_store,
+            connections,
+            channels,
+        } = builder;
+
+        for (connection_id, connection_end) in connections {
+            ibc_store
+                .store_connection(&ConnectionPath::new(&connection_id), connection_end)
+                .expect("error writing to store");
+        }
+
+        for (port_id, channel_id, channel_end) in channels {
+            ibc_store
+                .store_channel(&ChannelEndPath::new(&port_id, &channel_id), channel_end)
+                .expect("error writing to store");
+        }
+
+        Self::new()
+    }
+}
@@ -7,6 +7,10 @@ use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::ExistenceProof;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ClientType};
 use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
@@ -188,8 +192,8 @@ impl ClientStateCommon for MockClientState {
         &self,
         upgraded_client_state: Any,
         upgraded_consensus_state: Any,
-        _proof_upgrade_client: CommitmentProofBytes,
-        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
         _root: &CommitmentRoot,
     ) -> Result<(), ClientError> {
         let upgraded_mock_client_state = Self::try_from(upgraded_client_state)?;
@@ -200,17 +204,49 @@ impl ClientStateCommon for MockClientState {
                 client_height: upgraded_mock_client_state.latest_height(),
             })?;
         }
+
+        // There is no root to verify the proofs against, but they must at
+        // least be well-formed Merkle proofs.
+        MerkleProof::try_from(&proof_upgrade_client)
+            .map_err(UpgradeClientError::InvalidUpgradeClientProof)?;
+        MerkleProof::try_from(&proof_upgrade_consensus_state)
+            .map_err(UpgradeClientError::InvalidUpgradeConsensusStateProof)?;
+
         Ok(())
     }
 
     fn verify_membership(
         &self,
         _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
+        proof: &CommitmentProofBytes,
         _root: &CommitmentRoot,
         _path: Path,
-        _value: Vec<u8>,
+        value: Vec<u8>,
     ) -> Result<(), ClientError> {
+        // There is no root to verify the proof against. Existence proofs
+        // without a leaf op, which real stores never produce, are taken as
+        // mock proofs of their value, which must be the verified one.
+        let Ok(merkle_proof) = MerkleProof::try_from(proof) else {
+            return Ok(());
+        };
+
+        let proves_other_value = merkle_proof.proofs.into_iter().any(|commitment_proof| {
+            matches!(
+                commitment_proof.proof,
+                Some(Proof::Exist(ExistenceProof {
+                    value: proven_value,
+                    leaf: None,
+                    ..
+                })) if proven_value != value
+            )
+        });
+
+        if proves_other_value {
+            return Err(ClientError::Ics23Verification(
+                CommitmentError::VerificationFailure,
+            ));
+        }
+
         Ok(())
     }
 
@@ -263,10 +299,14 @@ where
                 let header_1 = misbehaviour.header1;
                 let header_2 = misbehaviour.header2;
 
+                // a mock header commits to nothing but its height and
+                // timestamp, so two headers at the same height conflict iff
+                // their timestamps differ
                 let header_heights_equal = header_1.height() == header_2.height();
+                let headers_conflict = header_1 != header_2;
                 let headers_are_in_future = self.latest_height() < header_1.height();
 
-                Ok(header_heights_equal && headers_are_in_future)
+                Ok(header_heights_equal && headers_conflict && headers_are_in_future)
             }
             header_type => Err(ClientError::UnknownHeaderType {
                 header_type: header_type.to_owned(),
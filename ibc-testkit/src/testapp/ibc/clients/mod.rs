@@ -93,19 +93,23 @@ pub enum AnyConsensusState {
     Mock(MockConsensusState),
 }
 
+/// Decodes a stored consensus state into its concrete type, based on its type
+/// URL.
+pub fn decode_consensus_state(any: Any) -> Result<AnyConsensusState, ClientError> {
+    match any.type_url.as_str() {
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(TmConsensusState::try_from(any)?.into()),
+        MOCK_CONSENSUS_STATE_TYPE_URL => MockConsensusState::try_from(any).map(Into::into),
+        _ => Err(ClientError::UnknownConsensusStateType {
+            consensus_state_type: any.type_url,
+        }),
+    }
+}
+
 impl TryFrom<Any> for AnyConsensusState {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
-            Ok(TmConsensusState::try_from(raw)?.into())
-        } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
-            MockConsensusState::try_from(raw).map(Into::into)
-        } else {
-            Err(ClientError::Other {
-                description: "failed to deserialize message".to_string(),
-            })
-        }
+        decode_consensus_state(raw)
     }
 }
 
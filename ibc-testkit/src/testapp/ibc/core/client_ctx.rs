@@ -180,9 +180,8 @@ where
         let consensus_state = self
             .consensus_state_store
             .get(StoreHeight::Pending, client_cons_state_path)
-            .ok_or(ClientError::ConsensusStateNotFound {
-                client_id: client_cons_state_path.client_id.clone(),
-                height,
+            .ok_or_else(|| {
+                self.missing_consensus_state_error(&client_cons_state_path.client_id, height)
             })?;
 
         Ok(consensus_state)
@@ -323,3 +322,40 @@ where
         Ok(())
     }
 }
+
+impl<S> MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    /// Tells apart a consensus state that was pruned, i.e. whose height is
+    /// below the oldest one retained for the client, from one that was never
+    /// stored.
+    fn missing_consensus_state_error(&self, client_id: &ClientId, height: Height) -> ClientError {
+        let path = format!("clients/{client_id}/consensusStates").into();
+
+        let oldest_height = self
+            .store
+            .get_keys(&path)
+            .into_iter()
+            .filter_map(|path| {
+                if let Ok(Path::ClientConsensusState(path)) = path.try_into() {
+                    Height::new(path.revision_number, path.revision_height).ok()
+                } else {
+                    None
+                }
+            })
+            .min();
+
+        match oldest_height {
+            Some(oldest_height) if height < oldest_height => ClientError::ConsensusStatePruned {
+                client_id: client_id.clone(),
+                height,
+                oldest_height,
+            },
+            _ => ClientError::ConsensusStateNotFound {
+                client_id: client_id.clone(),
+                height,
+            },
+        }
+    }
+}
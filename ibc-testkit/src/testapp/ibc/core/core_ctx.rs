@@ -1,35 +1,47 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use core::fmt::Debug;
+use core::iter;
 use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
 use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
-use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    AcknowledgementCommitment, CommitmentHasher, PacketCommitment,
+};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
-use ibc::core::channel::types::packet::{PacketState, Receipt};
+use ibc::core::channel::types::packet::{Packet, PacketState, Receipt};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
 use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::merkle::MerkleProof;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
-use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::error::{ContextError, StoreError};
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::handler::types::msgs::MsgSummary;
+use ibc::core::host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradeErrorPath,
+    ChannelUpgradePath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
+    ConnectionPath, NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath,
+    Path, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::types::module::ModuleId;
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
+use ibc_query::error::QueryError;
 
 use super::types::{MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
@@ -157,6 +169,47 @@ where
             .ok_or(ChannelError::MissingChannel)?)
     }
 
+    fn channel_upgrade(
+        &self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(self
+            .channel_upgrade_store
+            .get(StoreHeight::Pending, channel_upgrade_path))
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<Option<Upgrade>, ContextError> {
+        Ok(self
+            .channel_counterparty_upgrade_store
+            .get(StoreHeight::Pending, counterparty_upgrade_path))
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Ok(!self.packet_commitments(channel_end_path)?.is_empty())
+    }
+
+    fn validate_upgrade_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        match &self.upgrade_authority {
+            Some(authority) if authority != signer => {
+                Err(ChannelError::UnauthorizedUpgradeSigner {
+                    signer: signer.clone(),
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn is_channel_paused(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(self.paused_channels.lock().contains(channel_end_path))
+    }
+
     fn get_next_sequence_send(
         &self,
         seq_send_path: &SeqSendPath,
@@ -259,10 +312,30 @@ where
         Duration::from_secs(DEFAULT_BLOCK_TIME_SECS)
     }
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        if let Some(hrp) = &self.signer_bech32_prefix {
+            signer.validate_bech32(hrp)?;
+        }
+
         Ok(())
     }
 
+    fn max_messages_per_tx(&self) -> Option<usize> {
+        self.max_messages_per_tx
+    }
+
+    fn ack_retention_window(&self) -> Option<u64> {
+        self.ack_retention_window
+    }
+
+    fn commitment_hasher(&self) -> &dyn CommitmentHasher {
+        self.commitment_hasher.as_ref()
+    }
+
+    fn host_chain_id(&self) -> Option<ChainId> {
+        self.host_chain_id.clone()
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
@@ -464,6 +537,39 @@ where
             .collect()
     }
 
+    /// Returns the ids of the channels bound to the given port by scanning
+    /// the channel end keys under the port prefix.
+    fn channels_by_port(&self, port_id: &PortId) -> Result<Vec<ChannelId>, ContextError> {
+        let path = format!("channelEnds/ports/{port_id}")
+            .try_into()
+            .map_err(|_| ChannelError::Other {
+                description: "Invalid channel end path".into(),
+            })?;
+
+        Ok(self
+            .channel_end_store
+            .get_keys(&path)
+            .into_iter()
+            .filter_map(|path| match path.try_into() {
+                // the key scan is prefix based, so `transfer` also matches e.g. `transfer-2`
+                Ok(Path::ChannelEnd(channel_path)) if &channel_path.0 == port_id => {
+                    Some(channel_path.1)
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Returns the list of all ports bound to a module.
+    fn port_bindings(&self) -> Result<Vec<(PortId, ModuleId)>, QueryError> {
+        Ok(self
+            .port_bindings
+            .lock()
+            .iter()
+            .map(|(port_id, module_id)| (port_id.clone(), module_id.clone()))
+            .collect())
+    }
+
     /// Returns all the packet commitments associated with a channel.
     fn packet_commitments(
         &self,
@@ -570,9 +676,8 @@ where
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<Sequence>, ContextError> {
-        // QUESTION. Currently only works for unordered channels; ordered channels
-        // don't use receipts. However, ibc-go does it this way. Investigate if
-        // this query only ever makes sense on unordered channels.
+        // Only called for unordered channels; the query layer resolves ordered
+        // channels against the next receive sequence, as they don't use receipts.
 
         Ok(sequences
             .into_iter()
@@ -586,6 +691,43 @@ where
             .collect())
     }
 
+    /// Returns the unreceived IBC packets of a channel within a sequence range.
+    ///
+    /// Reads all the receipts of the channel once rather than looking up each
+    /// sequence of the range.
+    fn unreceived_in_range(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        start: Sequence,
+        end: Sequence,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let receipt_path_prefix = format!(
+            "receipts/ports/{}/channels/{}/sequences",
+            channel_end_path.0, channel_end_path.1
+        )
+        .try_into()
+        .map_err(|_| PacketError::Other {
+            description: "Invalid receipt path".into(),
+        })?;
+
+        let received: BTreeSet<Sequence> = self
+            .packet_receipt_store
+            .get_keys(&receipt_path_prefix)
+            .into_iter()
+            .filter_map(|path| {
+                if let Ok(Path::Receipt(receipt_path)) = path.try_into() {
+                    Some(receipt_path.sequence)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Sequence::range(start, end)
+            .filter(|seq| !received.contains(seq))
+            .collect())
+    }
+
     /// Returns all the unreceived IBC acknowledgements associated with a channel and sequences.
     ///
     /// Takes a sequence list as an argument.
@@ -634,6 +776,85 @@ where
             .map(|commitment_path| commitment_path.sequence)
             .collect())
     }
+
+    /// Returns the sent packets associated with a channel that still have a
+    /// packet commitment.
+    ///
+    /// The mock store only keeps packet commitment hashes, so the packets are
+    /// reconstructed from the emitted `SendPacket` events.
+    fn pending_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Packet>, QueryError> {
+        Ok(self
+            .events
+            .lock()
+            .iter()
+            .filter_map(|event| match event {
+                IbcEvent::SendPacket(send_packet)
+                    if send_packet.port_id_on_a() == &channel_end_path.0
+                        && send_packet.chan_id_on_a() == &channel_end_path.1 =>
+                {
+                    Some(Packet {
+                        seq_on_a: *send_packet.seq_on_a(),
+                        port_id_on_a: send_packet.port_id_on_a().clone(),
+                        chan_id_on_a: send_packet.chan_id_on_a().clone(),
+                        port_id_on_b: send_packet.port_id_on_b().clone(),
+                        chan_id_on_b: send_packet.chan_id_on_b().clone(),
+                        data: send_packet.packet_data().to_vec(),
+                        timeout_height_on_b: *send_packet.timeout_height_on_b(),
+                        timeout_timestamp_on_b: *send_packet.timeout_timestamp_on_b(),
+                    })
+                }
+                _ => None,
+            })
+            .filter(|packet| {
+                let commitment_path = CommitmentPath::new(
+                    &packet.port_id_on_a,
+                    &packet.chan_id_on_a,
+                    packet.seq_on_a,
+                );
+                self.packet_commitment_store
+                    .get(StoreHeight::Pending, &commitment_path)
+                    .is_some()
+            })
+            .collect())
+    }
+
+    fn channel_end_at_height(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        height: Height,
+    ) -> Result<ChannelEnd, QueryError> {
+        Ok(self
+            .channel_end_store
+            .get(height.revision_height().into(), channel_end_path)
+            .ok_or(ChannelError::MissingChannel)?)
+    }
+
+    fn packet_commitment_at_height(
+        &self,
+        commitment_path: &CommitmentPath,
+        height: Height,
+    ) -> Result<PacketCommitment, QueryError> {
+        Ok(self
+            .packet_commitment_store
+            .get(height.revision_height().into(), commitment_path)
+            .ok_or(PacketError::ImplementationSpecific)?)
+    }
+
+    fn packet_acknowledgement_at_height(
+        &self,
+        ack_path: &AckPath,
+        height: Height,
+    ) -> Result<AcknowledgementCommitment, QueryError> {
+        Ok(self
+            .packet_ack_store
+            .get(height.revision_height().into(), ack_path)
+            .ok_or(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            })?)
+    }
 }
 
 impl<S> ExecutionContext for MockIbcStore<S>
@@ -658,13 +879,57 @@ where
 
         self.client_counter
             .set(NextClientSequencePath, current_sequence + 1)
-            .map_err(|e| ClientError::Other {
-                description: format!("client counter update failed: {e:?}"),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{NextClientSequencePath}`: {e:?}"),
             })?;
 
         Ok(())
     }
 
+    /// Removes the consensus states of the given client whose expiry, based on
+    /// the trusting period of the client, is not after `now`.
+    fn prune_expired_consensus_states(
+        &mut self,
+        client_id: &ClientId,
+        now: Timestamp,
+    ) -> Result<usize, ContextError> {
+        let trusting_period = match self.client_state(client_id)? {
+            AnyClientState::Tendermint(cs) => cs.inner().trusting_period,
+            AnyClientState::Mock(cs) => cs.trusting_period,
+        };
+
+        let mut heights = QueryContext::consensus_state_heights(self, client_id)?;
+
+        heights.sort();
+
+        let mut pruned = 0;
+
+        for height in heights {
+            let consensus_state_path = ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            );
+
+            let expiry = (self.consensus_state(&consensus_state_path)?.timestamp()
+                + trusting_period)
+                .map_err(|_| ClientError::Other {
+                    description: "Consensus state expiry overflows".into(),
+                })?;
+
+            if expiry > now {
+                break;
+            }
+
+            self.delete_consensus_state(consensus_state_path)?;
+            self.delete_update_meta(client_id.clone(), height)?;
+
+            pruned += 1;
+        }
+
+        Ok(pruned)
+    }
+
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -673,8 +938,8 @@ where
     ) -> Result<(), ContextError> {
         self.connection_end_store
             .set(connection_path.clone(), connection_end)
-            .map_err(|_| ConnectionError::Other {
-                description: "Connection end store error".to_string(),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{connection_path}`: {e:?}"),
             })?;
         Ok(())
     }
@@ -692,8 +957,8 @@ where
         conn_ids.push(conn_id);
         self.connection_ids_store
             .set(client_connection_path.clone(), conn_ids)
-            .map_err(|_| ConnectionError::Other {
-                description: "Connection ids store error".to_string(),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{client_connection_path}`: {e:?}"),
             })?;
         Ok(())
     }
@@ -710,8 +975,8 @@ where
 
         self.conn_counter
             .set(NextConnectionSequencePath, current_sequence + 1)
-            .map_err(|e| ConnectionError::Other {
-                description: format!("connection counter update failed: {e:?}"),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{NextConnectionSequencePath}`: {e:?}"),
             })?;
 
         Ok(())
@@ -724,7 +989,9 @@ where
     ) -> Result<(), ContextError> {
         self.packet_commitment_store
             .set(commitment_path.clone(), commitment)
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{commitment_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -743,7 +1010,9 @@ where
     ) -> Result<(), ContextError> {
         self.packet_receipt_store
             .set_path(receipt_path.clone())
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{receipt_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -754,7 +1023,9 @@ where
     ) -> Result<(), ContextError> {
         self.packet_ack_store
             .set(ack_path.clone(), ack_commitment)
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{ack_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -763,6 +1034,25 @@ where
         Ok(())
     }
 
+    fn prune_acknowledgements(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        before_sequence: Sequence,
+    ) -> Result<usize, ContextError> {
+        let acks = QueryContext::packet_acknowledgements(self, channel_end_path, iter::empty())?;
+
+        let mut pruned = 0;
+
+        for ack in acks.into_iter().filter(|ack| ack.seq < before_sequence) {
+            self.packet_ack_store
+                .delete(AckPath::new(&ack.port_id, &ack.chan_id, ack.seq));
+
+            pruned += 1;
+        }
+
+        Ok(pruned)
+    }
+
     fn store_channel(
         &mut self,
         channel_end_path: &ChannelEndPath,
@@ -770,8 +1060,79 @@ where
     ) -> Result<(), ContextError> {
         self.channel_end_store
             .set(channel_end_path.clone(), channel_end)
-            .map_err(|_| ChannelError::Other {
-                description: "Channel end store error".to_string(),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{channel_end_path}`: {e:?}"),
+            })?;
+        Ok(())
+    }
+
+    fn set_channel_flow_status(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        paused: bool,
+    ) -> Result<(), ContextError> {
+        let mut paused_channels = self.paused_channels.lock();
+        if paused {
+            paused_channels.insert(channel_end_path.clone());
+        } else {
+            paused_channels.remove(channel_end_path);
+        }
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        channel_upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_store
+            .set(channel_upgrade_path.clone(), upgrade)
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{channel_upgrade_path}`: {e:?}"),
+            })?;
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        channel_upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_store
+            .delete(channel_upgrade_path.clone());
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.channel_counterparty_upgrade_store
+            .set(counterparty_upgrade_path.clone(), upgrade)
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{counterparty_upgrade_path}`: {e:?}"),
+            })?;
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &ChannelCounterpartyUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.channel_counterparty_upgrade_store
+            .delete(counterparty_upgrade_path.clone());
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_error_store
+            .set(upgrade_error_path.clone(), error_receipt)
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{upgrade_error_path}`: {e:?}"),
             })?;
         Ok(())
     }
@@ -783,7 +1144,9 @@ where
     ) -> Result<(), ContextError> {
         self.send_sequence_store
             .set(seq_send_path.clone(), seq)
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{seq_send_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -794,7 +1157,9 @@ where
     ) -> Result<(), ContextError> {
         self.recv_sequence_store
             .set(seq_recv_path.clone(), seq)
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{seq_recv_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -805,7 +1170,9 @@ where
     ) -> Result<(), ContextError> {
         self.ack_sequence_store
             .set(seq_ack_path.clone(), seq)
-            .map_err(|_| PacketError::ImplementationSpecific)?;
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{seq_ack_path}`: {e:?}"),
+            })?;
         Ok(())
     }
 
@@ -819,8 +1186,8 @@ where
 
         self.channel_counter
             .set(NextChannelSequencePath, current_sequence + 1)
-            .map_err(|e| ChannelError::Other {
-                description: format!("channel counter update failed: {e:?}"),
+            .map_err(|e| StoreError::Backend {
+                description: format!("failed to write at `{NextChannelSequencePath}`: {e:?}"),
             })?;
 
         Ok(())
@@ -831,6 +1198,36 @@ where
         Ok(())
     }
 
+    fn ibc_event_count(&self) -> Option<usize> {
+        Some(self.events.lock().len())
+    }
+
+    fn ibc_events_since(&self, start: usize) -> Option<Vec<IbcEvent>> {
+        self.events.lock().get(start..).map(<[IbcEvent]>::to_vec)
+    }
+
+    fn on_execute_complete(&mut self, msg_summary: MsgSummary, events: &[IbcEvent]) {
+        self.executed_msgs
+            .lock()
+            .push((msg_summary, events.to_vec()));
+    }
+
+    fn begin_batch(&mut self) -> Result<(), ContextError> {
+        self.store.apply().map_err(|e| StoreError::Backend {
+            description: format!("failed to apply pending writes: {e:?}"),
+        })?;
+        Ok(())
+    }
+
+    fn revert_batch(&mut self) {
+        // drops the writes made since `begin_batch` applied the pending ones
+        self.store.reset();
+    }
+
+    fn supports_revert_batch(&self) -> bool {
+        true
+    }
+
     fn log_message(&mut self, message: String) -> Result<(), ContextError> {
         self.logs.lock().push(message);
         Ok(())
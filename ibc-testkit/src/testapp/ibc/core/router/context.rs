@@ -1,6 +1,7 @@
 use alloc::sync::Arc;
 
 use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
 use ibc::core::router::router::Router;
 use ibc::core::router::types::module::ModuleId;
@@ -30,4 +31,16 @@ impl Router for MockRouter {
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
         self.port_to_module.get(port_id).cloned()
     }
+
+    fn module_ids(&self) -> Box<dyn Iterator<Item = &ModuleId> + '_> {
+        Box::new(self.router.keys())
+    }
+
+    fn bound_ports(&self) -> Box<dyn Iterator<Item = &PortId> + '_> {
+        Box::new(self.port_to_module.keys())
+    }
+
+    fn has_route(&self, module_id: &ModuleId) -> bool {
+        self.router.contains_key(module_id)
+    }
 }
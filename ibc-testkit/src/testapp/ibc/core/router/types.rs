@@ -22,7 +22,9 @@ impl MockRouter {
 
         let module_id = ModuleId::new(MODULE_ID_STR.to_string());
 
-        router.scope_port_to_module(PortId::transfer(), module_id.clone());
+        router
+            .scope_port_to_module(PortId::transfer(), module_id.clone())
+            .expect("Never fails");
 
         let transfer_mod = DummyTransferModule::new();
 
@@ -33,18 +35,37 @@ impl MockRouter {
         router
     }
 
+    /// Registers `module` under `module_id`, failing without replacing the
+    /// existing module if the id is already taken.
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
         module: impl Module + 'static,
     ) -> Result<(), String> {
-        match self.router.insert(module_id, Arc::new(module)) {
-            None => Ok(()),
-            Some(_) => Err("Duplicate module_id".to_owned()),
+        if self.router.contains_key(&module_id) {
+            return Err(format!("Duplicate module_id `{module_id}`"));
         }
+
+        self.router.insert(module_id, Arc::new(module));
+
+        Ok(())
     }
 
-    pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
+    /// Binds `port_id` to the module registered under `module_id`, failing
+    /// without rebinding it if the port is already bound.
+    pub fn scope_port_to_module(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<(), String> {
+        if let Some(bound_module_id) = self.port_to_module.get(&port_id) {
+            return Err(format!(
+                "Port `{port_id}` is already bound to module_id `{bound_module_id}`"
+            ));
+        }
+
         self.port_to_module.insert(port_id, module_id);
+
+        Ok(())
     }
 }
@@ -1,5 +1,6 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
 
@@ -7,22 +8,30 @@ use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
 use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedStore};
 use ibc::core::channel::types::channel::ChannelEnd;
-use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    AcknowledgementCommitment, CommitmentHasher, PacketCommitment, Sha256Hasher,
+};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::handler::types::msgs::MsgSummary;
+use ibc::core::host::types::identifiers::{ChainId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    AckPath, ChannelCounterpartyUpgradePath, ChannelEndPath, ChannelUpgradeErrorPath,
+    ChannelUpgradePath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::types::module::ModuleId;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannelEnd, ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade,
+};
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
 use ibc_proto::ics23::CommitmentProof;
@@ -39,6 +48,10 @@ pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 pub type DefaultIbcStore = MockIbcStore<MockStore>;
 
 /// An object that stores all IBC related data.
+///
+/// Its shared state sits behind `parking_lot` mutexes, which are not poisoned
+/// when a holder panics, so a failing test can't cascade into the others
+/// sharing the store.
 #[derive(Debug)]
 pub struct MockIbcStore<S>
 where
@@ -73,6 +86,15 @@ where
     pub connection_ids_store: JsonStore<SharedStore<S>, ClientConnectionPath, Vec<ConnectionId>>,
     /// A typed-store for ChannelEnd
     pub channel_end_store: ProtobufStore<SharedStore<S>, ChannelEndPath, ChannelEnd, RawChannelEnd>,
+    /// A typed-store for channel upgrades in progress
+    pub channel_upgrade_store:
+        ProtobufStore<SharedStore<S>, ChannelUpgradePath, Upgrade, RawUpgrade>,
+    /// A typed-store for the upgrades counterparties agreed to
+    pub channel_counterparty_upgrade_store:
+        ProtobufStore<SharedStore<S>, ChannelCounterpartyUpgradePath, Upgrade, RawUpgrade>,
+    /// A typed-store for the error receipts of aborted upgrades
+    pub channel_upgrade_error_store:
+        ProtobufStore<SharedStore<S>, ChannelUpgradeErrorPath, ErrorReceipt, RawErrorReceipt>,
     /// A typed-store for send sequences
     pub send_sequence_store: JsonStore<SharedStore<S>, SeqSendPath, Sequence>,
     /// A typed-store for receive sequences
@@ -89,10 +111,33 @@ where
     pub host_consensus_states: Arc<Mutex<BTreeMap<u64, AnyConsensusState>>>,
     /// Map of older ibc commitment proofs
     pub ibc_commiment_proofs: Arc<Mutex<BTreeMap<u64, CommitmentProof>>>,
+    /// Map of frozen heights of mock clients, as `MockClientState` only
+    /// tracks whether it is frozen
+    pub mock_frozen_heights: Arc<Mutex<BTreeMap<ClientId, Height>>>,
+    /// Map of bound ports to the modules they are routed to, as the routing
+    /// table lives in the router rather than in the store
+    pub port_bindings: Arc<Mutex<BTreeMap<PortId, ModuleId>>>,
+    /// Set of channel ends whose packet flow is paused
+    pub paused_channels: Arc<Mutex<BTreeSet<ChannelEndPath>>>,
+    /// Maximum number of messages per transaction, if any
+    pub max_messages_per_tx: Option<usize>,
+    /// Number of sequences for which relayed acknowledgements are retained,
+    /// if they are pruned at all
+    pub ack_retention_window: Option<u64>,
+    /// Chain identifier of the host, if exposed to the handlers
+    pub host_chain_id: Option<ChainId>,
+    /// Bech32 prefix that message signers must carry, if enforced
+    pub signer_bech32_prefix: Option<String>,
+    /// Only signer allowed to initiate and cancel channel upgrades, if any
+    pub upgrade_authority: Option<Signer>,
+    /// Hasher used to compute packet and acknowledgement commitments
+    pub commitment_hasher: Arc<dyn CommitmentHasher + Send + Sync>,
     /// IBC Events
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// Summaries of the executed messages, along with the events each emitted
+    pub executed_msgs: Arc<Mutex<Vec<(MsgSummary, Vec<IbcEvent>)>>>,
 }
 
 impl<S> MockIbcStore<S>
@@ -127,11 +172,23 @@ where
             client_processed_heights: TypedStore::new(shared_store.clone()),
             host_consensus_states: Arc::new(Mutex::new(Default::default())),
             ibc_commiment_proofs: Arc::new(Mutex::new(Default::default())),
+            mock_frozen_heights: Arc::new(Mutex::new(Default::default())),
+            port_bindings: Arc::new(Mutex::new(Default::default())),
+            paused_channels: Arc::new(Mutex::new(Default::default())),
+            max_messages_per_tx: None,
+            ack_retention_window: None,
+            host_chain_id: None,
+            signer_bech32_prefix: None,
+            upgrade_authority: None,
+            commitment_hasher: Arc::new(Sha256Hasher),
             client_state_store: TypedStore::new(shared_store.clone()),
             consensus_state_store: TypedStore::new(shared_store.clone()),
             connection_end_store: TypedStore::new(shared_store.clone()),
             connection_ids_store: TypedStore::new(shared_store.clone()),
             channel_end_store: TypedStore::new(shared_store.clone()),
+            channel_upgrade_store: TypedStore::new(shared_store.clone()),
+            channel_counterparty_upgrade_store: TypedStore::new(shared_store.clone()),
+            channel_upgrade_error_store: TypedStore::new(shared_store.clone()),
             send_sequence_store: TypedStore::new(shared_store.clone()),
             recv_sequence_store: TypedStore::new(shared_store.clone()),
             ack_sequence_store: TypedStore::new(shared_store.clone()),
@@ -140,6 +197,7 @@ where
             packet_ack_store: TypedStore::new(shared_store.clone()),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            executed_msgs: Arc::new(Mutex::new(Vec::new())),
             store: shared_store,
         }
     }
@@ -0,0 +1,61 @@
+use ibc::core::channel::types::events as channel;
+use ibc::core::client::types::events as client;
+use ibc::core::connection::types::events as connection;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::router::types::event::ModuleEvent;
+
+/// An event type wrapped by one of the [`IbcEvent`] variants.
+///
+/// Used by [`StoreGenericTestContext::events_of_type`](crate::context::StoreGenericTestContext::events_of_type)
+/// to pick the events of a given type out of the emitted ones.
+pub trait TypedEvent: Clone {
+    /// Returns the wrapped event if `event` is of this type.
+    fn from_ibc_event(event: &IbcEvent) -> Option<&Self>;
+}
+
+macro_rules! impl_typed_event {
+    ($($variant:ident => $event:ty),* $(,)?) => {
+        $(
+            impl TypedEvent for $event {
+                fn from_ibc_event(event: &IbcEvent) -> Option<&Self> {
+                    match event {
+                        IbcEvent::$variant(event) => Some(event),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_event! {
+    CreateClient => client::CreateClient,
+    UpdateClient => client::UpdateClient,
+    UpgradeClient => client::UpgradeClient,
+    ClientMisbehaviour => client::ClientMisbehaviour,
+    OpenInitConnection => connection::OpenInit,
+    OpenTryConnection => connection::OpenTry,
+    OpenAckConnection => connection::OpenAck,
+    OpenConfirmConnection => connection::OpenConfirm,
+    OpenInitChannel => channel::OpenInit,
+    OpenTryChannel => channel::OpenTry,
+    OpenAckChannel => channel::OpenAck,
+    OpenConfirmChannel => channel::OpenConfirm,
+    CloseInitChannel => channel::CloseInit,
+    CloseConfirmChannel => channel::CloseConfirm,
+    UpgradeInitChannel => channel::UpgradeInit,
+    UpgradeTryChannel => channel::UpgradeTry,
+    UpgradeAckChannel => channel::UpgradeAck,
+    UpgradeConfirmChannel => channel::UpgradeConfirm,
+    UpgradeOpenChannel => channel::UpgradeOpen,
+    UpgradeCancelChannel => channel::UpgradeCancel,
+    UpgradeTimeoutChannel => channel::UpgradeTimedOut,
+    SendPacket => channel::SendPacket,
+    ReceivePacket => channel::ReceivePacket,
+    WriteAcknowledgement => channel::WriteAcknowledgement,
+    AcknowledgePacket => channel::AcknowledgePacket,
+    TimeoutPacket => channel::TimeoutPacket,
+    ChannelClosed => channel::ChannelClosed,
+    Module => ModuleEvent,
+    Message => MessageEvent,
+}
@@ -1,6 +1,8 @@
 use ibc::primitives::Timestamp;
 use tendermint::Time;
 
+pub mod events;
+
 /// Returns a `Timestamp` representation of beginning of year 2023.
 ///
 /// This is introduced to initialize [`StoreGenericTestContext`](crate::context::StoreGenericTestContext)s
@@ -0,0 +1,331 @@
+use ibc::apps::fee::context::FeeValidationContext;
+use ibc::apps::fee::handler::{pay_packet_fee, pay_packet_fee_async};
+use ibc::apps::fee::middleware::FeeMiddleware;
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc::apps::fee::types::msgs::pay_packet_fee_async::MsgPayPacketFeeAsync;
+use ibc::apps::fee::types::{Fee, PacketFee};
+use ibc::apps::transfer::types::{Amount, PrefixedCoin};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::FeesInEscrowPath;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_channel_end;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::fee::types::DummyFeeContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+const NEXT_SEQUENCE: u64 = 4;
+
+fn coins(coins: &str) -> Vec<PrefixedCoin> {
+    PrefixedCoin::from_string_list(coins).unwrap()
+}
+
+fn dummy_fee() -> Fee {
+    Fee {
+        recv_fee: coins("10stake"),
+        ack_fee: coins("20stake"),
+        timeout_fee: coins("30stake"),
+    }
+}
+
+fn dummy_msg_pay_packet_fee() -> MsgPayPacketFee {
+    MsgPayPacketFee {
+        fee: dummy_fee(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        signer: dummy_account_id(),
+    }
+}
+
+fn dummy_msg_pay_packet_fee_async(seq_on_a: Sequence) -> MsgPayPacketFeeAsync {
+    MsgPayPacketFeeAsync {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        seq_on_a,
+        packet_fee: PacketFee::new(dummy_fee(), dummy_account_id(), Vec::new()),
+    }
+}
+
+fn fees_path(sequence: u64) -> FeesInEscrowPath {
+    FeesInEscrowPath::new(
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        Sequence::from(sequence),
+    )
+}
+
+fn dummy_packet() -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(NEXT_SEQUENCE),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: Vec::new(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+fn context_with_channel(state: i32) -> MockContext {
+    MockContext::default()
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            ChannelEnd::try_from(dummy_raw_channel_end(state, Some(0))).unwrap(),
+        )
+        .with_send_sequence(
+            PortId::transfer(),
+            ChannelId::zero(),
+            Sequence::from(NEXT_SEQUENCE),
+        )
+}
+
+/// Returns a transfer module acting as the fee context, with fees enabled on
+/// the test channel
+fn fee_enabled_module() -> DummyTransferModule {
+    let mut module = DummyTransferModule::new();
+    module.fees =
+        DummyFeeContext::new().with_fee_enabled_channel(PortId::transfer(), ChannelId::zero());
+    module
+}
+
+/// Returns a fee middleware whose escrow holds a fee for the next packet
+fn middleware_with_escrowed_fee() -> FeeMiddleware<DummyTransferModule> {
+    let mut ctx = context_with_channel(3);
+    let mut module = fee_enabled_module();
+
+    pay_packet_fee(&mut ctx.ibc_store, &mut module, dummy_msg_pay_packet_fee()).unwrap();
+
+    FeeMiddleware::new(module)
+}
+
+#[test]
+fn test_pay_packet_fee_escrows_for_next_sequence() {
+    let mut ctx = context_with_channel(3);
+    let mut module = fee_enabled_module();
+
+    let msg = dummy_msg_pay_packet_fee();
+
+    pay_packet_fee(&mut ctx.ibc_store, &mut module, msg.clone()).unwrap();
+    pay_packet_fee(&mut ctx.ibc_store, &mut module, msg).unwrap();
+
+    let packet_fee = PacketFee::new(dummy_fee(), dummy_account_id(), Vec::new());
+
+    assert_eq!(
+        module.fees_in_escrow(&fees_path(NEXT_SEQUENCE)).unwrap(),
+        vec![packet_fee.clone(), packet_fee]
+    );
+    assert_eq!(
+        module.fees.escrowed.values().copied().collect::<Vec<_>>(),
+        vec![Amount::from(120)]
+    );
+
+    let ibc_events = ctx.get_events();
+
+    assert!(matches!(ibc_events[0], IbcEvent::Module(_)));
+    assert_eq!(
+        ibc_events[1],
+        IbcEvent::Message(MessageEvent::Module("feeibc".to_string()))
+    );
+}
+
+#[test]
+fn test_pay_packet_fee_fail_no_channel() {
+    let mut ctx = MockContext::default();
+    let mut module = fee_enabled_module();
+
+    let res = pay_packet_fee(&mut ctx.ibc_store, &mut module, dummy_msg_pay_packet_fee());
+
+    assert!(matches!(res, Err(FeeError::ContextError(_))));
+    assert!(module.fees.fees_in_escrow.is_empty());
+}
+
+#[test]
+fn test_pay_packet_fee_fail_channel_not_open() {
+    let mut ctx = context_with_channel(1);
+    let mut module = fee_enabled_module();
+
+    let res = pay_packet_fee(&mut ctx.ibc_store, &mut module, dummy_msg_pay_packet_fee());
+
+    assert!(matches!(res, Err(FeeError::ContextError(_))));
+    assert!(module.fees.fees_in_escrow.is_empty());
+}
+
+#[test]
+fn test_pay_packet_fee_fail_fee_not_enabled() {
+    let mut ctx = context_with_channel(3);
+    let mut module = DummyTransferModule::new();
+
+    let res = pay_packet_fee(&mut ctx.ibc_store, &mut module, dummy_msg_pay_packet_fee());
+
+    assert!(matches!(res, Err(FeeError::FeeNotEnabled { .. })));
+    assert!(module.fees.fees_in_escrow.is_empty());
+}
+
+#[test]
+fn test_pay_packet_fee_async_escrows_for_named_sequence() {
+    let sequence = Sequence::from(NEXT_SEQUENCE - 1);
+    let mut ctx = context_with_channel(3).with_packet_commitment(
+        PortId::transfer(),
+        ChannelId::zero(),
+        sequence,
+        PacketCommitment::from(vec![1u8; 32]),
+    );
+    let mut module = fee_enabled_module();
+
+    pay_packet_fee_async(
+        &mut ctx.ibc_store,
+        &mut module,
+        dummy_msg_pay_packet_fee_async(sequence),
+    )
+    .unwrap();
+
+    assert_eq!(
+        module
+            .fees_in_escrow(&fees_path(NEXT_SEQUENCE - 1))
+            .unwrap(),
+        vec![PacketFee::new(dummy_fee(), dummy_account_id(), Vec::new())]
+    );
+    assert!(module
+        .fees_in_escrow(&fees_path(NEXT_SEQUENCE))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_pay_packet_fee_async_fail_packet_not_in_flight() {
+    let mut ctx = context_with_channel(3);
+    let mut module = fee_enabled_module();
+
+    let res = pay_packet_fee_async(
+        &mut ctx.ibc_store,
+        &mut module,
+        dummy_msg_pay_packet_fee_async(Sequence::from(NEXT_SEQUENCE - 1)),
+    );
+
+    assert!(matches!(res, Err(FeeError::ContextError(_))));
+    assert!(module.fees.fees_in_escrow.is_empty());
+}
+
+#[test]
+fn test_fee_middleware_distributes_fee_on_ack() {
+    let mut middleware = middleware_with_escrowed_fee();
+    let relayer = Signer::from("relayer".to_string());
+
+    let (extras, res) = middleware.on_acknowledgement_packet_execute(
+        &dummy_packet(),
+        &Acknowledgement::try_from(vec![1u8]).unwrap(),
+        &relayer,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 2);
+
+    let fees = &middleware.app().fees;
+
+    // without incentivized acks, the receive fee is refunded along with the
+    // unused timeout fee
+    assert_eq!(
+        fees.distributed,
+        vec![
+            (relayer, coins("20stake")),
+            (dummy_account_id(), coins("10stake,30stake")),
+        ]
+    );
+    assert!(fees.fees_in_escrow.is_empty());
+    assert_eq!(
+        fees.escrowed.values().copied().collect::<Vec<_>>(),
+        vec![Amount::from(0)]
+    );
+}
+
+#[test]
+fn test_fee_middleware_distributes_fee_on_timeout() {
+    let mut middleware = middleware_with_escrowed_fee();
+    let relayer = Signer::from("relayer".to_string());
+
+    let (extras, res) = middleware.on_timeout_packet_execute(&dummy_packet(), &relayer);
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 2);
+
+    let fees = &middleware.app().fees;
+
+    assert_eq!(
+        fees.distributed,
+        vec![
+            (relayer, coins("30stake")),
+            (dummy_account_id(), coins("10stake,20stake")),
+        ]
+    );
+    assert!(fees.fees_in_escrow.is_empty());
+}
+
+#[test]
+fn test_fee_middleware_keeps_undistributed_fee_in_escrow() {
+    let mut middleware = middleware_with_escrowed_fee();
+    let relayer = Signer::from("relayer".to_string());
+
+    // leave enough in escrow to pay the relayer, but not to refund the rest
+    for escrowed in middleware.app_mut().fees.escrowed.values_mut() {
+        *escrowed = Amount::from(25);
+    }
+
+    let (extras, res) = middleware.on_acknowledgement_packet_execute(
+        &dummy_packet(),
+        &Acknowledgement::try_from(vec![1u8]).unwrap(),
+        &relayer,
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 1);
+    assert_eq!(extras.log.len(), 1);
+
+    let fees = &middleware.app().fees;
+
+    assert_eq!(fees.distributed, vec![(relayer, coins("20stake"))]);
+
+    // only the refund, which failed, is left in escrow
+    let undistributed = Fee {
+        ack_fee: Vec::new(),
+        ..dummy_fee()
+    };
+    assert_eq!(
+        fees.fees_in_escrow.get(&fees_path(NEXT_SEQUENCE)),
+        Some(&vec![PacketFee::new(
+            undistributed,
+            dummy_account_id(),
+            Vec::new()
+        )])
+    );
+    assert_eq!(
+        fees.escrowed.values().copied().collect::<Vec<_>>(),
+        vec![Amount::from(5)]
+    );
+}
+
+#[test]
+fn test_fee_middleware_ignores_unincentivized_packet() {
+    let mut middleware = middleware_with_escrowed_fee();
+
+    let mut packet = dummy_packet();
+    packet.seq_on_a = Sequence::from(NEXT_SEQUENCE + 1);
+
+    let (extras, res) =
+        middleware.on_timeout_packet_execute(&packet, &Signer::from("relayer".to_string()));
+
+    assert!(res.is_ok());
+    assert!(extras.events.is_empty());
+    assert!(middleware.app().fees.distributed.is_empty());
+    assert_eq!(middleware.app().fees.fees_in_escrow.len(), 1);
+}
@@ -1,4 +1,7 @@
+pub mod fee;
 #[cfg(feature = "serde")]
 pub mod nft_transfer;
 #[cfg(feature = "serde")]
+pub mod rate_limit;
+#[cfg(feature = "serde")]
 pub mod transfer;
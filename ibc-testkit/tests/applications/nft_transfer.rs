@@ -1,12 +1,24 @@
+use ibc::apps::nft_transfer::context::NftTransferExecutionContext;
+use ibc::apps::nft_transfer::handler::{process_recv_packet_execute, send_nft_transfer_validate};
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
     on_chan_open_try_validate,
 };
-use ibc::apps::nft_transfer::types::VERSION;
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use ibc::apps::nft_transfer::types::{Memo, TokenIds, DEFAULT_MAX_TOKENS_PER_PACKET, VERSION};
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc::core::router::types::module::ModuleExtras;
+use ibc_query::apps::nft_transfer::{query_token_escrow, QueryTokenEscrowRequest};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
 
 fn get_defaults() -> (
@@ -24,7 +36,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyNftTransferModule,
+        DummyNftTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -133,3 +145,188 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+fn recv_packet(
+    ctx: &mut DummyNftTransferModule,
+    class_id: &str,
+    token_ids: Vec<String>,
+) -> Result<ModuleExtras, Box<(ModuleExtras, NftTransferError)>> {
+    recv_packet_with_memo(ctx, class_id, token_ids, "".into())
+}
+
+fn recv_packet_with_memo(
+    ctx: &mut DummyNftTransferModule,
+    class_id: &str,
+    token_ids: Vec<String>,
+    memo: Memo,
+) -> Result<ModuleExtras, Box<(ModuleExtras, NftTransferError)>> {
+    let data = PacketData::new(
+        class_id.parse().unwrap(),
+        None,
+        None,
+        TokenIds::try_from(token_ids).unwrap(),
+        vec![],
+        vec![],
+        dummy_account_id(),
+        dummy_account_id(),
+        memo,
+    )
+    .unwrap();
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    process_recv_packet_execute(ctx, &packet, data)
+}
+
+/// Receiving NFTs must fail when the class the host stores under the packet's
+/// class ID, after prefix manipulation, carries a different class ID.
+#[test]
+fn test_recv_packet_class_id_mismatch() {
+    let mut ctx = DummyNftTransferModule::new();
+
+    let token_ids = vec!["token_0".to_string()];
+
+    // The dummy host only stores `class_0`. Receiving it back from the
+    // counterparty unescrows into that very class.
+    assert!(recv_packet(&mut ctx, "transfer/channel-0/class_0", token_ids.clone()).is_ok());
+
+    // Minting vouchers for `class_1` resolves to the stored `class_0` instead.
+    let err = recv_packet(&mut ctx, "class_1", token_ids).unwrap_err();
+    assert!(matches!(err.1, NftTransferError::ClassIdMismatch { .. }));
+}
+
+/// Receiving a packet carrying more tokens than the host allows must fail.
+#[test]
+fn test_recv_packet_too_many_tokens() {
+    let mut ctx = DummyNftTransferModule::new();
+
+    let token_ids = |count: usize| (0..count).map(|i| format!("token_{i}")).collect();
+
+    assert!(recv_packet(
+        &mut ctx,
+        "transfer/channel-0/class_0",
+        token_ids(DEFAULT_MAX_TOKENS_PER_PACKET)
+    )
+    .is_ok());
+
+    let err = recv_packet(
+        &mut ctx,
+        "transfer/channel-0/class_0",
+        token_ids(DEFAULT_MAX_TOKENS_PER_PACKET + 1),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.1,
+        NftTransferError::TooManyTokens { count, .. } if count == DEFAULT_MAX_TOKENS_PER_PACKET as u64 + 1
+    ));
+}
+
+/// The escrow status of an NFT must follow it being escrowed on send and
+/// unescrowed when received back.
+#[test]
+fn test_token_escrow_info() {
+    let mut ctx = DummyNftTransferModule::new();
+
+    let request = QueryTokenEscrowRequest {
+        class_id: "class_0".parse().unwrap(),
+        token_id: "token_0".parse().unwrap(),
+    };
+
+    assert_eq!(query_token_escrow(&ctx, &request).escrow, None);
+
+    ctx.escrow_nft_execute(
+        &dummy_account_id(),
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        &request.class_id,
+        &request.token_id,
+        &"".into(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        query_token_escrow(&ctx, &request).escrow,
+        Some((PortId::transfer(), ChannelId::new(1)))
+    );
+
+    // Receiving the NFT back through the same channel unescrows it.
+    recv_packet(
+        &mut ctx,
+        "transfer/channel-0/class_0",
+        vec!["token_0".to_string()],
+    )
+    .unwrap();
+
+    assert_eq!(query_token_escrow(&ctx, &request).escrow, None);
+}
+
+/// Sending or receiving NFTs must fail when the memo exceeds the size the
+/// host allows.
+#[test]
+fn test_transfer_memo_too_large() {
+    let mut module = DummyNftTransferModule::new();
+    module.max_memo_size = Some(32);
+
+    let class_id = "transfer/channel-0/class_0";
+    let token_ids = vec!["token_0".to_string()];
+
+    assert!(recv_packet_with_memo(
+        &mut module,
+        class_id,
+        token_ids.clone(),
+        "a".repeat(32).into()
+    )
+    .is_ok());
+
+    let err = recv_packet_with_memo(
+        &mut module,
+        class_id,
+        token_ids.clone(),
+        "a".repeat(33).into(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.1,
+        NftTransferError::MemoTooLarge {
+            length: 33,
+            max_length: 32
+        }
+    ));
+
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        packet_data: PacketData::new(
+            class_id.parse().unwrap(),
+            None,
+            None,
+            TokenIds::try_from(token_ids).unwrap(),
+            vec![],
+            vec![],
+            dummy_account_id(),
+            dummy_account_id(),
+            "a".repeat(33).into(),
+        )
+        .unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let ctx = MockContext::default();
+    let res = send_nft_transfer_validate(&ctx.ibc_store, &module, msg);
+    assert!(matches!(
+        res,
+        Err(NftTransferError::MemoTooLarge {
+            length: 33,
+            max_length: 32
+        })
+    ));
+}
@@ -0,0 +1,276 @@
+use core::time::Duration;
+
+use ibc::apps::rate_limit::context::RateLimitValidationContext;
+use ibc::apps::rate_limit::error::{RateLimitError, QUOTA_EXCEEDED_ACK_ERROR};
+use ibc::apps::rate_limit::handler::{
+    record_flow_execute, remove_quota, send_transfer_execute, send_transfer_validate, set_quota,
+};
+use ibc::apps::rate_limit::middleware::RateLimitMiddleware;
+use ibc::apps::rate_limit::types::{FlowDirection, Quota};
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::testapp::ibc::applications::rate_limit::types::DummyRateLimitContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+fn timestamp(secs: u64) -> Timestamp {
+    Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap()
+}
+
+fn denom(denom: &str) -> PrefixedDenom {
+    denom.parse().unwrap()
+}
+
+/// Returns a context in which `stake` moving over `transfer/channel-0` is
+/// capped at 100 per window in each direction.
+fn context_with_quota() -> DummyRateLimitContext {
+    let mut ctx = DummyRateLimitContext::new(timestamp(1));
+
+    let quota = Quota::new(Amount::from(100), Amount::from(100), WINDOW).unwrap();
+    set_quota(
+        &mut ctx,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom("stake"),
+        quota,
+    )
+    .unwrap();
+
+    ctx
+}
+
+fn record(
+    ctx: &mut DummyRateLimitContext,
+    direction: FlowDirection,
+    amount: u64,
+) -> Result<(), RateLimitError> {
+    record_flow_execute(
+        ctx,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &denom("stake"),
+        direction,
+        Amount::from(amount),
+    )
+}
+
+fn current_flow(ctx: &DummyRateLimitContext) -> (Amount, Amount) {
+    let flow = ctx
+        .flow(&PortId::transfer(), &ChannelId::zero(), &denom("stake"))
+        .unwrap()
+        .unwrap();
+
+    (flow.inflow, flow.outflow)
+}
+
+fn transfer_packet(coin: &str) -> Packet {
+    let packet_data = PacketDataConfig::builder()
+        .token(coin.parse::<PrefixedCoin>().unwrap())
+        .build();
+
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&packet_data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+#[test]
+fn test_send_within_and_over_quota() {
+    let mut ctx = context_with_quota();
+
+    record(&mut ctx, FlowDirection::Outflow, 60).unwrap();
+    record(&mut ctx, FlowDirection::Outflow, 40).unwrap();
+
+    let res = record(&mut ctx, FlowDirection::Outflow, 1);
+
+    match res {
+        Err(RateLimitError::QuotaExceeded {
+            direction,
+            amount,
+            max_amount,
+            ..
+        }) => {
+            assert_eq!(direction, FlowDirection::Outflow);
+            assert_eq!(amount, Amount::from(1));
+            assert_eq!(max_amount, Amount::from(100));
+        }
+        other => panic!("expected a `QuotaExceeded` error, got {other:?}"),
+    }
+    assert_eq!(current_flow(&ctx), (Amount::from(0), Amount::from(100)));
+
+    // tokens received in the same window make room for more to be sent
+    record(&mut ctx, FlowDirection::Inflow, 30).unwrap();
+    record(&mut ctx, FlowDirection::Outflow, 30).unwrap();
+
+    assert_eq!(current_flow(&ctx), (Amount::from(30), Amount::from(130)));
+}
+
+#[test]
+fn test_send_without_quota_is_not_tracked() {
+    let mut ctx = DummyRateLimitContext::new(timestamp(1));
+
+    record(&mut ctx, FlowDirection::Outflow, 1_000).unwrap();
+
+    assert!(ctx.flows.is_empty());
+}
+
+#[test]
+fn test_window_reset() {
+    let mut ctx = context_with_quota();
+
+    record(&mut ctx, FlowDirection::Outflow, 100).unwrap();
+    assert!(record(&mut ctx, FlowDirection::Outflow, 10).is_err());
+
+    // the window is still open right before it ends
+    ctx.host_timestamp = (timestamp(1) + (WINDOW - Duration::from_nanos(1))).unwrap();
+    assert!(record(&mut ctx, FlowDirection::Outflow, 10).is_err());
+
+    ctx.host_timestamp = (timestamp(1) + WINDOW).unwrap();
+    record(&mut ctx, FlowDirection::Outflow, 10).unwrap();
+
+    assert_eq!(current_flow(&ctx), (Amount::from(0), Amount::from(10)));
+}
+
+#[test]
+fn test_update_quota() {
+    let mut ctx = context_with_quota();
+    let (port_id, channel_id, denom) = (PortId::transfer(), ChannelId::zero(), denom("stake"));
+
+    record(&mut ctx, FlowDirection::Outflow, 100).unwrap();
+
+    let quota = Quota::new(Amount::from(10), Amount::from(500), WINDOW).unwrap();
+    set_quota(&mut ctx, &port_id, &channel_id, &denom, quota.clone()).unwrap();
+
+    assert_eq!(
+        ctx.quota(&port_id, &channel_id, &denom).unwrap(),
+        Some(quota)
+    );
+    // the new quota applies from a fresh window
+    assert!(ctx.flows.is_empty());
+    record(&mut ctx, FlowDirection::Outflow, 500).unwrap();
+
+    remove_quota(&mut ctx, &port_id, &channel_id, &denom).unwrap();
+
+    assert_eq!(ctx.quota(&port_id, &channel_id, &denom).unwrap(), None);
+    assert!(ctx.flows.is_empty());
+}
+
+#[test]
+fn test_zero_window_rejected() {
+    assert!(matches!(
+        Quota::new(Amount::from(1), Amount::from(1), Duration::ZERO),
+        Err(RateLimitError::ZeroWindow)
+    ));
+}
+
+fn transfer_msg(coin: &str) -> MsgTransfer {
+    let packet_data = PacketDataConfig::builder()
+        .token(coin.parse::<PrefixedCoin>().unwrap())
+        .build();
+
+    MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: timestamp(1_000),
+    }
+}
+
+#[test]
+fn test_send_transfer_over_quota() {
+    let mut ctx = context_with_quota();
+
+    send_transfer_validate(&ctx, &transfer_msg("100stake")).unwrap();
+    send_transfer_execute(&mut ctx, &transfer_msg("100stake")).unwrap();
+
+    assert!(matches!(
+        send_transfer_validate(&ctx, &transfer_msg("1stake")),
+        Err(RateLimitError::QuotaExceeded {
+            direction: FlowDirection::Outflow,
+            ..
+        })
+    ));
+    assert!(send_transfer_execute(&mut ctx, &transfer_msg("1stake")).is_err());
+    assert_eq!(current_flow(&ctx), (Amount::from(0), Amount::from(100)));
+
+    // other denoms are not tracked
+    send_transfer_execute(&mut ctx, &transfer_msg("1000uatom")).unwrap();
+}
+
+#[test]
+fn test_middleware_rejects_recv_over_quota() {
+    let mut module = DummyTransferModule::new();
+    module.rate_limits.host_timestamp = timestamp(1);
+
+    // `uatom` doesn't originate from this chain, so it is received as a
+    // voucher traced back to the receiving channel
+    let voucher = denom("transfer/channel-0/uatom");
+    let quota = Quota::new(Amount::from(100), Amount::from(100), WINDOW).unwrap();
+    set_quota(
+        &mut module,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &voucher,
+        quota,
+    )
+    .unwrap();
+
+    let mut middleware = RateLimitMiddleware::new(module);
+    let relayer = Signer::from("relayer".to_string());
+
+    let (_, ack) = middleware.on_recv_packet_execute(&transfer_packet("80uatom"), &relayer);
+
+    assert_eq!(ack.as_ref(), &[1u8]);
+
+    let (extras, ack) = middleware.on_recv_packet_execute(&transfer_packet("30uatom"), &relayer);
+
+    let ack = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert_eq!(
+        ack,
+        AcknowledgementStatus::error(StatusValue::new(QUOTA_EXCEEDED_ACK_ERROR).unwrap())
+    );
+    assert_eq!(extras.log.len(), 1);
+
+    let flow = middleware
+        .app()
+        .flow(&PortId::transfer(), &ChannelId::zero(), &voucher)
+        .unwrap()
+        .unwrap();
+    assert_eq!(flow.inflow, Amount::from(80));
+}
+
+#[test]
+fn test_middleware_undoes_outflow_on_timeout() {
+    let mut module = DummyTransferModule::new();
+    module.rate_limits = context_with_quota();
+
+    send_transfer_execute(&mut module, &transfer_msg("100stake")).unwrap();
+
+    let mut middleware = RateLimitMiddleware::new(module);
+
+    let (_, res) = middleware.on_timeout_packet_execute(
+        &transfer_packet("40stake"),
+        &Signer::from("relayer".to_string()),
+    );
+
+    assert!(res.is_ok());
+    assert_eq!(
+        current_flow(&middleware.app().rate_limits),
+        (Amount::from(0), Amount::from(60))
+    );
+}
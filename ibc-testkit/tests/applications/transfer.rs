@@ -1,14 +1,47 @@
+use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc::apps::transfer::handler::{
+    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    send_transfer,
+};
 use ibc::apps::transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute,
+};
+use ibc::apps::transfer::types::error::{AckErrorReason, TokenTransferError};
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{
+    Amount, Forwarding, PrefixedCoin, PrefixedDenom, TransferHook, U256, VERSION,
 };
-use ibc::apps::transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
-use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_query::apps::transfer::{
+    query_denom_supply, query_denom_trace, query_denom_traces, query_total_escrow_for_denom,
+    QueryDenomSupplyRequest, QueryDenomTraceRequest, QueryDenomTracesRequest,
+    QueryTotalEscrowForDenomRequest,
+};
+use ibc_query::error::QueryError;
+use ibc_query::types::PageRequest;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::applications::transfer::{MsgTransferConfig, PacketDataConfig};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::{
+    DummyTransferModule, DummyTransferModuleBuilder,
+};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -26,7 +59,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyTransferModule,
+        DummyTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -35,6 +68,27 @@ fn get_defaults() -> (
     )
 }
 
+/// Returns a transfer of the given token with the given memo, sent over
+/// `transfer/channel-0` and received on `transfer/channel-1`, along with its
+/// packet data.
+fn recv_packet(token: PrefixedCoin, memo: &str) -> (Packet, PacketData) {
+    let data: PacketData = PacketDataConfig::builder()
+        .token(token)
+        .memo(memo.into())
+        .build();
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+    (packet, data)
+}
+
 #[test]
 fn test_cosmos_escrow_address() {
     fn assert_eq_escrow_address(port_id: &str, channel_id: &str, address: &str) {
@@ -166,3 +220,422 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// The supply of a voucher denom increases when it is minted on receive,
+/// decreases when it is burned on send, and is restored on refund.
+#[test]
+fn test_denom_supply() {
+    let mut ctx = DummyTransferModule::new();
+    let account = dummy_account_id();
+
+    let (recv_packet, recv_packet_data) = recv_packet("100uatom".parse().unwrap(), "");
+
+    let voucher: PrefixedCoin = "100transfer/channel-1/uatom".parse().unwrap();
+    let query_supply = |ctx: &DummyTransferModule| {
+        let request = QueryDenomSupplyRequest {
+            denom: voucher.denom.clone(),
+        };
+        query_denom_supply(ctx, &request).unwrap().amount.amount
+    };
+
+    assert_eq!(query_supply(&ctx), Amount::from(0));
+
+    process_recv_packet_execute(&mut ctx, &recv_packet, recv_packet_data).unwrap();
+    assert_eq!(query_supply(&ctx), Amount::from(100));
+
+    // sending the voucher back burns it
+    ctx.burn_coins_execute(&account, &voucher, &"".into())
+        .unwrap();
+    assert_eq!(query_supply(&ctx), Amount::from(0));
+
+    let send_packet_data: PacketData = PacketDataConfig::builder().token(voucher.clone()).build();
+    let send_packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        data: serde_json::to_vec(&send_packet_data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    refund_packet_token_execute(&mut ctx, &send_packet, &send_packet_data).unwrap();
+    assert_eq!(query_supply(&ctx), Amount::from(100));
+
+    // burning more than the supply is rejected
+    let excess: PrefixedCoin = "101transfer/channel-1/uatom".parse().unwrap();
+    assert!(ctx
+        .burn_coins_validate(&account, &excess, &"".into())
+        .is_err());
+}
+
+/// The denom traces of minted vouchers can be looked up by the hash in their
+/// `ibc/{hash}` denom, and listed page by page.
+#[test]
+fn test_denom_traces() {
+    let mut ctx = DummyTransferModule::new();
+    let account = dummy_account_id();
+
+    for coin in [
+        "100transfer/channel-0/uatom",
+        "100transfer/channel-1/uatom",
+        "100transfer/channel-1/transfer/channel-7/uosmo",
+    ] {
+        ctx.mint_coins_execute(&account, &coin.parse().unwrap())
+            .unwrap();
+    }
+
+    let voucher: PrefixedCoin = "100transfer/channel-0/uatom".parse().unwrap();
+    let ibc_denom = voucher.denom.ibc_denom();
+    assert_eq!(
+        ibc_denom,
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+    );
+    assert_eq!(
+        ctx.denom_hash_string(&voucher.denom).as_deref(),
+        ibc_denom.strip_prefix("ibc/")
+    );
+
+    // the hash is accepted with or without the `ibc/` prefix, in any case
+    for hash in [
+        ibc_denom.clone(),
+        voucher.denom.denom_hash(),
+        voucher.denom.denom_hash().to_lowercase(),
+    ] {
+        let response = query_denom_trace(&ctx, &QueryDenomTraceRequest { hash }).unwrap();
+        assert_eq!(response.denom_trace, voucher.denom);
+    }
+
+    let unknown = QueryDenomTraceRequest {
+        hash: "uatom".parse::<PrefixedDenom>().unwrap().denom_hash(),
+    };
+    assert!(matches!(
+        query_denom_trace(&ctx, &unknown),
+        Err(QueryError::TokenTransferError(
+            TokenTransferError::DenomTraceNotFound { .. }
+        ))
+    ));
+
+    let query_page = |pagination: PageRequest| {
+        query_denom_traces(
+            &ctx,
+            &QueryDenomTracesRequest {
+                pagination: Some(pagination),
+            },
+        )
+        .unwrap()
+    };
+
+    let first_page = query_page(PageRequest {
+        limit: 2,
+        count_total: true,
+        ..Default::default()
+    });
+    assert_eq!(
+        first_page.denom_traces,
+        vec![
+            "transfer/channel-0/uatom".parse().unwrap(),
+            "transfer/channel-1/transfer/channel-7/uosmo"
+                .parse()
+                .unwrap(),
+        ]
+    );
+    let first_page_info = first_page.pagination.unwrap();
+    assert_eq!(first_page_info.total, 3);
+
+    let second_page = query_page(PageRequest {
+        key: first_page_info.next_key,
+        limit: 2,
+        ..Default::default()
+    });
+    assert_eq!(
+        second_page.denom_traces,
+        vec!["transfer/channel-1/uatom".parse().unwrap()]
+    );
+    assert!(second_page.pagination.unwrap().next_key.is_empty());
+}
+
+/// Receiving vouchers must fail, without minting, when the amount would
+/// overflow the tracked supply of the denom.
+#[test]
+fn test_recv_packet_amount_overflow() {
+    let mut ctx = DummyTransferModule::new();
+
+    let uatom = |amount: U256| PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: amount.into(),
+    };
+
+    let (packet, data) = recv_packet(uatom(U256::MAX - 1), "");
+    process_recv_packet_execute(&mut ctx, &packet, data).unwrap();
+
+    let (packet, data) = recv_packet(uatom(U256::from(2)), "");
+    let (_, err) = process_recv_packet_execute(&mut ctx, &packet, data).unwrap_err();
+    assert!(matches!(err, TokenTransferError::AmountOverflow { .. }));
+
+    let voucher = "transfer/channel-1/uatom".parse().unwrap();
+    assert_eq!(
+        ctx.get_denom_supply(&voucher).unwrap(),
+        Some(Amount::from(U256::MAX - 1))
+    );
+}
+
+/// Received tokens whose memo carries packet-forward instructions are handed
+/// off to the forwarding hook once minted; other memos are left alone.
+#[test]
+fn test_recv_packet_forwarding() {
+    let mut ctx = DummyTransferModule::new();
+
+    let (packet, data) = recv_packet("100uatom".parse().unwrap(), "just a memo");
+    process_recv_packet_execute(&mut ctx, &packet, data).unwrap();
+
+    assert!(ctx.forwarded.is_empty());
+
+    let (packet, data) = recv_packet(
+        "100uatom".parse().unwrap(),
+        r#"{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-7"}}"#,
+    );
+    process_recv_packet_execute(&mut ctx, &packet, data).unwrap();
+
+    let voucher: PrefixedCoin = "100transfer/channel-1/uatom".parse().unwrap();
+    let forwarding = Forwarding {
+        receiver: "osmo1".to_string().into(),
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(7),
+        next: None,
+    };
+    assert_eq!(ctx.forwarded, vec![(voucher, forwarding)]);
+}
+
+/// Received transfers whose memo carries a hook directive invoke the recv hook
+/// once the tokens are credited; failed receipts and other memos don't.
+#[test]
+fn test_recv_packet_hooks() {
+    let mut ctx = DummyTransferModule::new();
+
+    let is_successful = |ack: &[u8]| {
+        serde_json::from_slice::<AcknowledgementStatus>(ack)
+            .unwrap()
+            .is_successful()
+    };
+
+    let (packet, _) = recv_packet("100uatom".parse().unwrap(), "just a memo");
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    assert!(is_successful(ack.as_ref()));
+    assert!(ctx.recv_hooks.is_empty());
+
+    // nothing is escrowed on the receiving channel, so unescrowing fails
+    let (packet, _) = recv_packet(
+        "100transfer/channel-0/uatom".parse().unwrap(),
+        r#"{"wasm":{"contract":"osmo1","msg":{}}}"#,
+    );
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    assert!(!is_successful(ack.as_ref()));
+    assert!(ctx.recv_hooks.is_empty());
+
+    let (packet, _) = recv_packet(
+        "100uatom".parse().unwrap(),
+        r#"{"evm":{"contract":"0x5f"}}"#,
+    );
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    // the hook is handed the voucher as minted on this chain
+    assert!(is_successful(ack.as_ref()));
+    assert_eq!(
+        ctx.recv_hooks,
+        vec![(
+            TransferHook::Evm,
+            "100transfer/channel-1/uatom".parse().unwrap()
+        )]
+    );
+}
+
+/// A failing recv hook takes the credited tokens back and writes an error
+/// acknowledgement, so that they are refunded on the sending chain.
+#[test]
+fn test_recv_packet_hook_failure() {
+    let mut ctx = DummyTransferModule::new();
+    ctx.reject_recv_hooks = true;
+
+    let (packet, _) = recv_packet(
+        "100uatom".parse().unwrap(),
+        r#"{"wasm":{"contract":"osmo1","msg":{}}}"#,
+    );
+    let (extras, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    let ack = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert_eq!(
+        AckErrorReason::from_ack_status(&ack),
+        Some(AckErrorReason::HookFailure)
+    );
+    assert!(extras
+        .log
+        .iter()
+        .any(|log| log.contains("contract call rejected")));
+
+    let voucher = "transfer/channel-1/uatom".parse().unwrap();
+    assert_eq!(
+        ctx.get_denom_supply(&voucher).unwrap(),
+        Some(Amount::from(0))
+    );
+    assert!(ctx.recv_hooks.is_empty());
+}
+
+/// Refunding a packet twice must be rejected once the escrowed amount of the
+/// channel is exhausted.
+#[test]
+fn test_escrow_invariant_double_refund() {
+    let mut ctx = DummyTransferModule::new();
+    let account = dummy_account_id();
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(0);
+
+    let coin: PrefixedCoin = "100uatom".parse().unwrap();
+    ctx.escrow_coins_execute(&account, &port_id, &channel_id, &coin, &"".into())
+        .unwrap();
+
+    let escrowed = |ctx: &DummyTransferModule| {
+        ctx.escrowed_amount(&port_id, &channel_id, &coin.denom)
+            .unwrap()
+            .unwrap()
+    };
+    assert_eq!(escrowed(&ctx), Amount::from(100));
+
+    let data: PacketData = PacketDataConfig::builder().token(coin.clone()).build();
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: port_id.clone(),
+        chan_id_on_a: channel_id.clone(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    refund_packet_token_validate(&ctx, &packet, &data).unwrap();
+    refund_packet_token_execute(&mut ctx, &packet, &data).unwrap();
+    assert_eq!(escrowed(&ctx), Amount::from(0));
+
+    let err = refund_packet_token_validate(&ctx, &packet, &data).unwrap_err();
+    assert!(matches!(
+        err,
+        TokenTransferError::EscrowInvariantViolated { .. }
+    ));
+}
+
+#[test]
+fn test_total_escrow_for_denom() {
+    let mut ctx = DummyTransferModule::new();
+    let account = dummy_account_id();
+    let port_id = PortId::transfer();
+
+    let total_escrow = |ctx: &DummyTransferModule, denom: &str| {
+        let request = QueryTotalEscrowForDenomRequest {
+            denom: denom.parse().unwrap(),
+        };
+        query_total_escrow_for_denom(ctx, &request)
+            .unwrap()
+            .amount
+            .amount
+    };
+
+    // never escrowed denoms are reported with a zero total
+    assert_eq!(total_escrow(&ctx, "uatom"), Amount::from(0));
+
+    for (channel_id, coin) in [
+        (ChannelId::new(0), "100uatom"),
+        (ChannelId::new(1), "50uatom"),
+        (ChannelId::new(1), "7uosmo"),
+    ] {
+        let coin: PrefixedCoin = coin.parse().unwrap();
+        ctx.escrow_coins_execute(&account, &port_id, &channel_id, &coin, &"".into())
+            .unwrap();
+    }
+
+    assert_eq!(total_escrow(&ctx, "uatom"), Amount::from(150));
+    assert_eq!(total_escrow(&ctx, "uosmo"), Amount::from(7));
+
+    let coin: PrefixedCoin = "30uatom".parse().unwrap();
+    ctx.unescrow_coins_execute(&account, &port_id, &ChannelId::new(0), &coin)
+        .unwrap();
+
+    assert_eq!(total_escrow(&ctx, "uatom"), Amount::from(120));
+
+    // vouchers are escrowed under their denom trace, and queried by their
+    // `ibc/{hash}` denom
+    let voucher: PrefixedCoin = "20transfer/channel-5/uatom".parse().unwrap();
+    ctx.mint_coins_execute(&account, &voucher).unwrap();
+    ctx.escrow_coins_execute(&account, &port_id, &ChannelId::new(1), &voucher, &"".into())
+        .unwrap();
+
+    let ibc_denom = voucher.denom.ibc_denom();
+    assert_eq!(total_escrow(&ctx, &ibc_denom), Amount::from(20));
+    assert_eq!(
+        total_escrow(&ctx, &ibc_denom.to_lowercase()),
+        Amount::from(20)
+    );
+    assert_eq!(total_escrow(&ctx, "uatom"), Amount::from(120));
+
+    // a voucher denom with an unknown hash has never been escrowed either
+    let unknown_ibc_denom = format!("ibc/{}", "0".repeat(64));
+    assert_eq!(total_escrow(&ctx, &unknown_ibc_denom), Amount::from(0));
+}
+
+#[test]
+fn test_send_transfer_with_seeded_module() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let mut module: DummyTransferModule = DummyTransferModuleBuilder::init()
+        .ibc_store(&mut ctx.ibc_store)
+        .connections(vec![(ConnectionId::zero(), conn_end_on_a)])
+        .channels(vec![(PortId::transfer(), ChannelId::zero(), chan_end_on_a)])
+        .build();
+
+    let coin: PrefixedCoin = "100uatom".parse().unwrap();
+    let msg = MsgTransferConfig::builder()
+        .packet_data(PacketDataConfig::builder().token(coin.clone()).build())
+        .timeout_height_on_b(TimeoutHeight::At(Height::new(0, 35).unwrap()))
+        .build();
+
+    send_transfer(&mut ctx.ibc_store, &mut module, msg).unwrap();
+
+    assert_eq!(
+        module
+            .escrowed_amount(&PortId::transfer(), &ChannelId::zero(), &coin.denom)
+            .unwrap(),
+        Some(Amount::from(100))
+    );
+}
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Layer, Registry};
+
+/// The fields recorded on a span, rendered as strings.
+#[derive(Clone, Debug, Default)]
+struct SpanFields(BTreeMap<&'static str, String>);
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+/// Collects the fields of every `ibc_handler` span once it is closed.
+#[derive(Clone, Default)]
+struct HandlerSpans(Arc<Mutex<Vec<SpanFields>>>);
+
+impl<S> Layer<S> for HandlerSpans
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != "ibc_handler" {
+            return;
+        }
+
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+
+        let span = ctx.span(id).expect("span exists");
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span exists");
+        if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+            values.record(fields);
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).expect("span exists");
+        if let Some(fields) = span.extensions().get::<SpanFields>() {
+            self.0.lock().unwrap().push(fields.clone());
+        }
+    }
+}
+
+/// Runs `f` and returns the fields of the `ibc_handler` spans it opened.
+fn handler_spans(f: impl FnOnce()) -> Vec<SpanFields> {
+    let spans = HandlerSpans::default();
+    let subscriber = Registry::default().with(spans.clone());
+
+    tracing::subscriber::with_default(subscriber, f);
+
+    let recorded = spans.0.lock().unwrap().clone();
+    recorded
+}
+
+pub struct Fixture {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelOpenInit,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg_conn_init = dummy_msg_conn_open_init();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Init,
+        msg_conn_init.client_id_on_a.clone(),
+        msg_conn_init.counterparty.clone(),
+        ConnectionVersion::compatibles(),
+        msg_conn_init.delay_period,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &tm_client_type().build_client_id(0),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a);
+
+    Fixture { ctx, router, msg }
+}
+
+#[rstest]
+fn handler_span_records_assigned_channel_id(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    let spans = handler_spans(|| {
+        let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+        execute(&mut ctx.ibc_store, &mut router, msg_envelope).unwrap();
+    });
+
+    assert_eq!(spans.len(), 1);
+
+    let fields = &spans[0].0;
+    assert_eq!(fields["stage"], "execute");
+    assert_eq!(fields["outcome"], "success");
+    assert_eq!(fields["channel_id"], "channel-0");
+}
+
+#[rstest]
+fn handler_span_records_routing_failure(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+    } = fixture;
+
+    msg.port_id_on_a = PortId::new("unknown".to_string()).unwrap();
+
+    let spans = handler_spans(|| {
+        let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+        assert!(validate(&ctx.ibc_store, &router, msg_envelope).is_err());
+    });
+
+    assert_eq!(spans.len(), 1);
+
+    let fields = &spans[0].0;
+    assert_eq!(fields["stage"], "validate");
+    assert_eq!(fields["outcome"], "failure");
+    assert!(!fields.contains_key("channel_id"));
+}
@@ -1,3 +1,6 @@
+use core::str::FromStr;
+use core::time::Duration;
+
 use basecoin_store::impls::InMemoryStore;
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ConsensusState as TmConsensusState,
@@ -11,10 +14,16 @@ use ibc::core::commitment_types::error::CommitmentError;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
 use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath};
 use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc::primitives::proto::Any;
+use ibc_query::core::client::{
+    query_client_periods, query_consensus_state_heights, QueryClientPeriodsRequest,
+    QueryConsensusStateHeightsRequest,
+};
 use ibc_query::core::context::ProvableContext;
+use ibc_query::types::PageRequest;
 use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
@@ -26,7 +35,9 @@ use ibc_testkit::testapp::ibc::clients::mock::client_state::{
 };
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
-use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::clients::{
+    decode_consensus_state, AnyClientState, AnyConsensusState,
+};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientBuilder, MockIbcStore};
 use test_log::test;
@@ -100,6 +111,179 @@ fn test_tm_create_client_ok() {
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_tm_create_self_tracking_client() {
+    let tm_header = dummy_tendermint_header();
+    let host_chain_id = ChainId::from_str(tm_header.chain_id.as_str()).unwrap();
+
+    let ctx = MockContext::default().with_host_chain_id(host_chain_id.clone());
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgCreateClient::new(
+        dummy_tm_client_state_from_header(tm_header.clone()).into(),
+        TmConsensusState::from(tm_header).into(),
+        dummy_account_id(),
+    );
+
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    );
+
+    #[cfg(not(feature = "loopback"))]
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::SelfTrackingNotAllowed { chain_id }))
+            if chain_id == host_chain_id
+    ));
+
+    #[cfg(feature = "loopback")]
+    assert!(res.is_ok(), "loopback clients are allowed");
+}
+
+#[test]
+fn test_tm_client_periods_query() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let tm_header = dummy_tendermint_header();
+    let tm_client_id = tm_client_type().build_client_id(ctx.client_counter().unwrap());
+    let msg = MsgCreateClient::new(
+        dummy_tm_client_state_from_header(tm_header.clone()).into(),
+        TmConsensusState::from(tm_header).into(),
+        dummy_account_id(),
+    );
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .unwrap();
+
+    let response = query_client_periods(
+        &ctx,
+        &QueryClientPeriodsRequest {
+            client_id: tm_client_id,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(response.trusting_period, Some(Duration::from_secs(64000)));
+    assert_eq!(
+        response.unbonding_period,
+        Some(Duration::from_secs(128_000))
+    );
+
+    let mock_header = MockHeader::new(Height::new(0, 42).unwrap());
+    let mock_client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+    let msg = MsgCreateClient::new(
+        MockClientState::new(mock_header).into(),
+        MockConsensusState::new(mock_header).into(),
+        dummy_account_id(),
+    );
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .unwrap();
+
+    let response = query_client_periods(
+        &ctx,
+        &QueryClientPeriodsRequest {
+            client_id: mock_client_id,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(response.trusting_period, None);
+    assert_eq!(response.unbonding_period, None);
+
+    let unknown_client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+    let res = query_client_periods(
+        &ctx,
+        &QueryClientPeriodsRequest {
+            client_id: unknown_client_id,
+        },
+    );
+
+    assert!(res.is_err(), "unknown clients have no periods");
+}
+
+#[test]
+fn test_consensus_state_heights_query() {
+    let client_id = mock_client_type().build_client_id(0);
+    let heights = [
+        Height::new(1, 1).unwrap(),
+        Height::new(0, 10).unwrap(),
+        Height::new(0, 2).unwrap(),
+        Height::new(0, 9).unwrap(),
+    ];
+
+    let ctx = heights.iter().fold(MockContext::default(), |ctx, &height| {
+        ctx.with_consensus_state(
+            &client_id,
+            height,
+            MockConsensusState::new(MockHeader::new(height)).into(),
+        )
+    });
+
+    let query = |pagination: Option<PageRequest>| {
+        query_consensus_state_heights(
+            &ctx.ibc_store,
+            &QueryConsensusStateHeightsRequest {
+                client_id: client_id.clone(),
+                pagination,
+            },
+        )
+        .unwrap()
+    };
+
+    let mut expected = heights.to_vec();
+    expected.sort();
+
+    assert_eq!(query(None).consensus_state_heights, expected);
+
+    let first_page = query(Some(PageRequest {
+        limit: 3,
+        count_total: true,
+        ..Default::default()
+    }));
+
+    assert_eq!(first_page.consensus_state_heights, expected[..3].to_vec());
+
+    let pagination = first_page.pagination.unwrap();
+    assert_eq!(pagination.total, 4);
+
+    let second_page = query(Some(PageRequest {
+        key: pagination.next_key,
+        limit: 3,
+        ..Default::default()
+    }));
+
+    assert_eq!(second_page.consensus_state_heights, expected[3..].to_vec());
+    assert!(second_page.pagination.unwrap().next_key.is_empty());
+}
+
+#[test]
+fn test_decode_consensus_state() {
+    let tm_consensus_state = TmConsensusState::from(dummy_tendermint_header());
+
+    let decoded = decode_consensus_state(tm_consensus_state.clone().into()).unwrap();
+
+    assert_eq!(decoded, AnyConsensusState::from(tm_consensus_state.clone()));
+
+    let mut unknown: Any = tm_consensus_state.into();
+    unknown.type_url = "/unknown.ConsensusState".to_string();
+
+    assert!(matches!(
+        decode_consensus_state(unknown),
+        Err(ClientError::UnknownConsensusStateType { consensus_state_type })
+            if consensus_state_type == "/unknown.ConsensusState"
+    ));
+}
+
 #[test]
 fn test_invalid_frozen_tm_client_creation() {
     let signer = dummy_account_id();
@@ -11,21 +11,23 @@ use ibc::clients::tendermint::types::{
 };
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
 use ibc::core::host::types::path::ClientConsensusStatePath;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::context::{MockContext, TendermintContext, TestContext};
-use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+use ibc_testkit::fixtures::clients::tendermint::{dummy_conflicting_tm_headers, ClientStateConfig};
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::tendermint::BlockParams;
@@ -41,7 +43,7 @@ use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as Mock
 use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
-    DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
+    DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore, DEFAULT_BLOCK_TIME_SECS,
 };
 use rstest::*;
 use tendermint_testgen::Validator as TestgenValidator;
@@ -70,12 +72,23 @@ fn fixture() -> Fixture {
 fn msg_update_client(client_id: &ClientId) -> MsgEnvelope {
     let timestamp = Timestamp::now();
     let height = Height::new(0, 46).unwrap();
+    let header = MockHeader::new(height).with_timestamp(timestamp);
+    let conflicting_header = header.with_timestamp((timestamp + Duration::from_secs(1)).unwrap());
+
+    msg_mock_misbehaviour(client_id, header, conflicting_header)
+}
+
+fn msg_mock_misbehaviour(
+    client_id: &ClientId,
+    header1: MockHeader,
+    header2: MockHeader,
+) -> MsgEnvelope {
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
         client_message: MockMisbehaviour {
             client_id: client_id.clone(),
-            header1: MockHeader::new(height).with_timestamp(timestamp),
-            header2: MockHeader::new(height).with_timestamp(timestamp),
+            header1,
+            header2,
         }
         .into(),
         signer: dummy_account_id(),
@@ -318,6 +331,86 @@ fn test_consensus_state_pruning() {
     );
 }
 
+#[rstest]
+fn test_prune_expired_consensus_states() {
+    let chain_id = ChainId::new("mockgaiaA-1").unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let trusting_period = Duration::from_secs(3);
+
+    let consensus_heights: Vec<_> = (1..=3).map(|h| Height::new(1, h).unwrap()).collect();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id.clone()).build())
+        .latest_height(Height::new(1, 3).unwrap())
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id).build())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<TendermintContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights(consensus_heights.clone())
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(trusting_period)
+                        .build(),
+                )
+                .build(),
+        );
+
+    // The first two consensus states are expired by then; the third is not.
+    let now = (ctx_b.timestamp_at(consensus_heights[1]) + trusting_period).unwrap();
+
+    let pruned = ctx
+        .ibc_store
+        .prune_expired_consensus_states(&client_id, now)
+        .unwrap();
+
+    assert_eq!(pruned, 2);
+
+    for (i, &height) in consensus_heights.iter().enumerate() {
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let res = ctx.ibc_store.consensus_state(&client_cons_state_path);
+
+        if i < pruned {
+            // below the retained window, hence reported as pruned
+            assert!(matches!(
+                res,
+                Err(ContextError::ClientError(ClientError::ConsensusStatePruned {
+                    height: h,
+                    oldest_height,
+                    ..
+                })) if h == height && oldest_height == consensus_heights[pruned]
+            ));
+            assert!(ctx
+                .ibc_store
+                .client_update_meta(&client_id, &height)
+                .is_err());
+        } else {
+            assert!(res.is_ok());
+        }
+    }
+
+    // a height within the retained window that was never stored
+    let client_cons_state_path = ClientConsensusStatePath::new(client_id, 1, 4);
+
+    assert!(matches!(
+        ctx.ibc_store.consensus_state(&client_cons_state_path),
+        Err(ContextError::ClientError(
+            ClientError::ConsensusStateNotFound { .. }
+        ))
+    ));
+}
+
 #[rstest]
 fn test_update_nonexisting_client(fixture: Fixture) {
     let Fixture { ctx, router } = fixture;
@@ -1178,8 +1271,8 @@ fn ensure_misbehaviour<S: ProvableStore + Debug>(
 
 /// Tests misbehaviour handling for the mock client.
 ///
-/// Misbehaviour evidence consists of identical headers - mock misbehaviour handler
-/// considers it a valid proof of misbehaviour.
+/// Misbehaviour evidence consists of two headers at the same height with
+/// different timestamps.
 #[rstest]
 fn test_misbehaviour_client_ok(fixture: Fixture) {
     let Fixture {
@@ -1199,6 +1292,27 @@ fn test_misbehaviour_client_ok(fixture: Fixture) {
     ensure_misbehaviour(&ctx.ibc_store, &client_id, &mock_client_type());
 }
 
+/// Identical headers are no evidence of misbehaviour, so they don't freeze the
+/// mock client.
+#[rstest]
+fn test_misbehaviour_client_identical_headers(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let header = MockHeader::new(Height::new(0, 46).unwrap()).with_current_timestamp();
+    let msg_envelope = msg_mock_misbehaviour(&client_id, header, header);
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_err());
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    let status = client_state.status(&ctx.ibc_store, &client_id).unwrap();
+    assert!(!status.is_frozen(), "client_state status: {status}");
+}
+
 #[rstest]
 fn test_submit_misbehaviour_nonexisting_client(fixture: Fixture) {
     let Fixture { router, .. } = fixture;
@@ -1269,29 +1383,9 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
 
     let mut router_a = MockRouter::new_with_transfer();
 
-    // Get chain-B's header at `misbehaviour_height`
-    let header1: TmHeader = {
-        let block = ctx_b.host_block(&misbehaviour_height).unwrap();
-        let mut block = block.into_header();
-        block.set_trusted_height(client_height);
-        block.into()
-    };
-
-    // Generate an equivocal header for chain-B at `misbehaviour_height`
-    let header2 = {
-        let mut tm_block = TendermintHost::builder()
-            .chain_id(chain_id_b)
-            .build()
-            .generate_block(
-                Vec::new(),
-                misbehaviour_height.revision_height(),
-                Timestamp::now(),
-                &Default::default(),
-            )
-            .into_header();
-        tm_block.set_trusted_height(client_height);
-        tm_block.into()
-    };
+    // Get chain-B's header at `misbehaviour_height`, along with an equivocal one
+    let (header1, header2) =
+        dummy_conflicting_tm_headers(&ctx_b, client_height, misbehaviour_height);
 
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
@@ -1307,6 +1401,70 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
     ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
 }
 
+/// Once frozen by conflicting headers, the synthetic Tendermint client rejects
+/// any further update.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_rejects_updates() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(misbehaviour_height)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let (header1, header2) =
+        dummy_conflicting_tm_headers(&ctx_b, client_height, misbehaviour_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id.clone(), header1.clone(), header2).into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope).unwrap();
+    ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
+
+    // the header committed by chain-B is no longer accepted either
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header1.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::ClientNotActive { status })) if status.is_frozen()
+    ));
+}
+
 #[rstest]
 fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1444,6 +1602,93 @@ fn test_expired_client() {
         .is_expired());
 }
 
+/// A client expires once a single block produced past its trusting period
+/// moves the host time beyond it.
+#[rstest]
+fn test_expired_client_after_block_time() {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let client_height = Height::new(1, 18).unwrap();
+
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let trusting_period = Duration::from_secs(64);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(client_height)
+        .latest_timestamp(timestamp)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(trusting_period)
+                        .build(),
+                )
+                .build(),
+        );
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_active());
+
+    ctx.advance_block_time(trusting_period + Duration::from_secs(1));
+
+    assert!(client_state
+        .status(&ctx.ibc_store, &client_id)
+        .unwrap()
+        .is_expired());
+}
+
+#[rstest]
+fn test_host_stepping() {
+    let mut ctx = MockContext::default();
+
+    let start_height = ctx.latest_height();
+    let start_timestamp = ctx.latest_timestamp();
+
+    ctx.advance_block_height_by(3);
+
+    assert_eq!(ctx.latest_height(), start_height.add(3));
+    assert_eq!(
+        ctx.latest_timestamp(),
+        (start_timestamp + Duration::from_secs(3 * DEFAULT_BLOCK_TIME_SECS)).unwrap()
+    );
+
+    let block_time = Duration::from_secs(3600);
+    ctx.advance_block_time(block_time);
+
+    let host_height = ctx.ibc_store.host_height().unwrap();
+    let host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
+
+    assert_eq!(host_height, start_height.add(4));
+    assert_eq!(
+        host_timestamp,
+        (start_timestamp + (Duration::from_secs(3 * DEFAULT_BLOCK_TIME_SECS) + block_time))
+            .unwrap()
+    );
+    assert_eq!(host_timestamp, ctx.latest_timestamp());
+    assert_eq!(host_timestamp, ctx.timestamp_at(host_height));
+}
+
 #[rstest]
 fn test_client_update_max_clock_drift() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
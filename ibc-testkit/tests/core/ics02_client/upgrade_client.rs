@@ -1,8 +1,10 @@
 use ibc::clients::tendermint::types::client_type;
+use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::{ClientValidationContext, ExtClientValidationContext};
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpgradeClient};
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
@@ -29,6 +31,7 @@ enum Msg {
     Default,
     LowUpgradeHeight,
     UnknownUpgradedClientStateType,
+    InvalidUpgradeClientProof,
 }
 
 fn msg_upgrade_client_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgUpgradeClient> {
@@ -55,10 +58,17 @@ fn msg_upgrade_client_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<Msg
         ..msg_default.clone()
     };
 
+    // not a protobuf-encoded Merkle proof
+    let msg_with_invalid_proof = MsgUpgradeClient {
+        proof_upgrade_client: CommitmentProofBytes::try_from(vec![0xff]).unwrap(),
+        ..msg_default.clone()
+    };
+
     let msg = match msg_variant {
         Msg::Default => msg_default,
         Msg::LowUpgradeHeight => msg_with_low_upgrade_height,
         Msg::UnknownUpgradedClientStateType => msg_with_unknown_upgraded_cs,
+        Msg::InvalidUpgradeClientProof => msg_with_invalid_proof,
     };
 
     Fixture { ctx, msg }
@@ -170,3 +180,26 @@ fn upgrade_client_fail_unknown_upgraded_client_state() {
     });
     upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+#[test]
+fn upgrade_client_fail_invalid_proof() {
+    let mut fxt = msg_upgrade_client_fixture(Ctx::WithClient, Msg::InvalidUpgradeClientProof);
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(fxt.msg.clone()));
+
+    let res = validate(&fxt.ctx, &router, msg_envelope);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::Upgrade(
+            UpgradeClientError::InvalidUpgradeClientProof(_)
+        )))
+    ));
+
+    // the client is left untouched
+    let client_state = fxt.ctx.client_state(&fxt.msg.client_id).unwrap();
+    assert_eq!(client_state.latest_height(), Height::new(0, 42).unwrap());
+
+    fxt.msg = dummy_msg_upgrade_client(fxt.msg.client_id.clone(), Height::new(1, 26).unwrap());
+    upgrade_client_validate(&fxt, Expect::Success);
+}
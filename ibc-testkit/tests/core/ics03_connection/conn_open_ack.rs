@@ -18,6 +18,8 @@ use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_ack;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
 use test_log::test;
@@ -212,3 +214,26 @@ fn conn_open_ack_connection_mismatch() {
     });
     conn_open_ack_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+/// A consensus height above the latest height of the proven client state is
+/// rejected before any proof is verified.
+#[test]
+fn conn_open_ack_consensus_height_above_client_height() {
+    let mut fxt = conn_open_ack_fixture(Ctx::NewWithConnection);
+    let client_height = fxt.msg.consensus_height_of_a_on_b.sub(1).unwrap();
+    fxt.msg.client_state_of_a_on_b = MockClientState::new(MockHeader::new(client_height)).into();
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+
+    match validate(&fxt.ctx, &router, msg_envelope) {
+        Err(ContextError::ConnectionError(ConnectionError::ConsensusHeightAboveClientHeight {
+            consensus_height,
+            client_height: height,
+        })) => {
+            assert_eq!(consensus_height, fxt.msg.consensus_height_of_a_on_b);
+            assert_eq!(height, client_height);
+        }
+        other => panic!("expected a `ConsensusHeightAboveClientHeight` error, got {other:?}"),
+    }
+}
@@ -21,6 +21,7 @@ use test_log::test;
 enum Ctx {
     Default,
     WithClient,
+    WithFrozenClient,
 }
 
 enum Msg {
@@ -51,6 +52,17 @@ fn conn_open_init_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConn
                 )
                 .ibc_store
         }
+        Ctx::WithFrozenClient => {
+            let frozen_height = Height::new(0, 8).unwrap();
+            let ctx = ctx_default
+                .with_light_client(
+                    &msg.client_id_on_a,
+                    LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+                )
+                .with_frozen_client(&msg.client_id_on_a, frozen_height);
+            assert_eq!(ctx.frozen_height(&msg.client_id_on_a), Some(frozen_height));
+            ctx.ibc_store
+        }
         _ => ctx_default.ibc_store,
     };
 
@@ -127,6 +139,12 @@ fn conn_open_init_no_context() {
     conn_open_init_validate(&fxt, Expect::Failure(None));
 }
 
+#[test]
+fn conn_open_init_frozen_client() {
+    let fxt = conn_open_init_fixture(Ctx::WithFrozenClient, Msg::Default);
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+}
+
 #[test]
 fn conn_open_init_no_version() {
     let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::NoVersion);
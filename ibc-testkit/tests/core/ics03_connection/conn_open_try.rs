@@ -1,16 +1,29 @@
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
-use ibc::core::connection::types::State;
+use ibc::core::connection::types::version::Version;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc_query::core::connection::{query_connections, QueryConnectionsRequest};
 use ibc_testkit::context::MockContext;
-use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_try;
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_try, dummy_raw_counterparty_conn,
+};
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
 use test_log::test;
@@ -136,10 +149,22 @@ fn conn_open_try_height_advanced() {
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
 
+/// The host can't check the consensus state the counterparty stored of it at
+/// a height whose own consensus state was pruned.
 #[test]
 fn conn_open_try_height_old() {
     let fxt = conn_open_try_fixture(Ctx::WithClient, Msg::HeightOld);
     conn_open_try_validate(&fxt, Expect::Failure(None));
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+
+    match validate(&fxt.ctx, &router, msg_envelope) {
+        Err(ContextError::ClientError(ClientError::MissingLocalConsensusState { height })) => {
+            assert_eq!(height, fxt.msg.consensus_height_of_b_on_a)
+        }
+        other => panic!("expected a `MissingLocalConsensusState` error, got {other:?}"),
+    }
 }
 
 #[test]
@@ -148,8 +173,71 @@ fn conn_open_try_proof_height_missing() {
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
 
+/// A consensus height above the latest height of the proven client state is
+/// rejected before any proof is verified.
+#[test]
+fn conn_open_try_consensus_height_above_client_height() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    let client_height = fxt.msg.consensus_height_of_b_on_a.sub(1).unwrap();
+    fxt.msg.client_state_of_b_on_a = MockClientState::new(MockHeader::new(client_height)).into();
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+
+    match validate(&fxt.ctx, &router, msg_envelope) {
+        Err(ContextError::ConnectionError(ConnectionError::ConsensusHeightAboveClientHeight {
+            consensus_height,
+            client_height: height,
+        })) => {
+            assert_eq!(consensus_height, fxt.msg.consensus_height_of_b_on_a);
+            assert_eq!(height, client_height);
+        }
+        other => panic!("expected a `ConsensusHeightAboveClientHeight` error, got {other:?}"),
+    }
+}
+
 #[test]
 fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+#[test]
+fn conn_open_try_query_connections_by_state() {
+    let client_id = mock_client_type().build_client_id(0);
+
+    let ctx = [State::Init, State::TryOpen, State::Open, State::TryOpen]
+        .into_iter()
+        .enumerate()
+        .fold(MockContext::default(), |ctx, (i, state)| {
+            let conn_end = ConnectionEnd::new(
+                state,
+                client_id.clone(),
+                Counterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+                Version::compatibles(),
+                ZERO_DURATION,
+            )
+            .unwrap();
+
+            ctx.with_connection(ConnectionId::new(i as u64), conn_end)
+        });
+
+    let request = QueryConnectionsRequest::new(None).with_state(State::TryOpen);
+    let mut connection_ids: Vec<_> = query_connections(&ctx.ibc_store, &request)
+        .unwrap()
+        .connections
+        .into_iter()
+        .map(|connection| connection.connection_id)
+        .collect();
+    connection_ids.sort();
+
+    assert_eq!(
+        connection_ids,
+        vec![ConnectionId::new(1), ConnectionId::new(3)]
+    );
+
+    let request = QueryConnectionsRequest::new(None);
+    let response = query_connections(&ctx.ibc_store, &request).unwrap();
+
+    assert_eq!(response.connections.len(), 4);
+}
@@ -1,14 +1,18 @@
+use core::time::Duration;
+
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
 use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
@@ -17,7 +21,7 @@ use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, DEFAULT_BLOCK_TIME_SECS};
 use rstest::*;
 use test_log::test;
 
@@ -184,6 +188,99 @@ fn ack_success_happy_path(fixture: Fixture) {
     )
 }
 
+/// An acknowledgement can only be processed once both the time and the number
+/// of blocks of the connection delay period have passed since the client was
+/// updated at the proof height.
+#[rstest]
+fn ack_delay_period(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        chan_end_on_a_unordered,
+        client_height,
+        ..
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let delay_blocks = 3;
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+    )
+    .unwrap();
+
+    let mut ctx = ctx
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay period hasn't passed. err: {res:?}"
+    );
+
+    // the whole delay time passes within a single block
+    ctx.advance_block_height_with_params(
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+        &Default::default(),
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay blocks haven't passed. err: {res:?}"
+    );
+
+    for _ in 1..delay_blocks {
+        ctx.advance_block_height();
+    }
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the delay period has passed. err: {res:?}"
+    );
+}
+
 #[rstest]
 fn ack_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
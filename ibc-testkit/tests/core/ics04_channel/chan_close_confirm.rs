@@ -1,11 +1,14 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseConfirm};
 use ibc::core::channel::types::Version;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
@@ -140,3 +143,97 @@ fn test_chan_close_confirm_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseConfirmChannel(_)));
 }
+
+/// Builds a context with an open channel whose counterparty client on the host
+/// has its latest (and only) consensus state at the host height, along with a
+/// `MsgChannelCloseConfirm` carrying the given proof height.
+fn close_confirm_context_with_proof_height(proof_height: u64) -> (MockContext, MsgEnvelope) {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let default_context = MockContext::default();
+    let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_confirm =
+        MsgChannelCloseConfirm::try_from(dummy_raw_msg_chan_close_confirm(proof_height)).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg_chan_close_confirm.port_id_on_b.clone(),
+            Some(msg_chan_close_confirm.chan_id_on_b.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+        )
+        .with_connection(conn_id, conn_end)
+        .with_channel(
+            msg_chan_close_confirm.port_id_on_b.clone(),
+            msg_chan_close_confirm.chan_id_on_b.clone(),
+            chan_end,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm));
+
+    (context, msg_envelope)
+}
+
+#[test]
+fn test_chan_close_confirm_validate_future_proof_height() {
+    let host_height = MockContext::default().ibc_store.host_height().unwrap();
+
+    let (context, msg_envelope) =
+        close_confirm_context_with_proof_height(host_height.increment().revision_height());
+
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::InvalidProofHeight { .. }
+            ))
+        ),
+        "Validation expected to fail for a proof height beyond the client latest height. Result: {res:?}"
+    );
+}
+
+#[test]
+fn test_chan_close_confirm_validate_pruned_proof_height() {
+    let host_height = MockContext::default().ibc_store.host_height().unwrap();
+
+    let (context, msg_envelope) =
+        close_confirm_context_with_proof_height(host_height.revision_height() - 1);
+
+    let router = MockRouter::new_with_transfer();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::MissingConsensusStateAtProofHeight { .. }
+            ))
+        ),
+        "Validation expected to fail for a proof height without consensus state. Result: {res:?}"
+    );
+}
@@ -1,4 +1,5 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::events::CloseInit;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseInit};
 use ibc::core::channel::types::Version;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -6,14 +7,17 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_init;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -117,10 +121,10 @@ fn test_chan_close_init_execute() {
                 &client_id,
                 LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
             )
-            .with_connection(conn_id, conn_end)
+            .with_connection(conn_id.clone(), conn_end)
             .with_channel(
                 msg_chan_close_init.port_id_on_a.clone(),
-                msg_chan_close_init.chan_id_on_a,
+                msg_chan_close_init.chan_id_on_a.clone(),
                 chan_end,
             )
     };
@@ -141,4 +145,198 @@ fn test_chan_close_init_execute() {
     ));
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseInitChannel(_)));
+
+    assert_eq!(
+        context.events_of_type::<CloseInit>(),
+        vec![CloseInit::new(
+            msg_chan_close_init.port_id_on_a.clone(),
+            msg_chan_close_init.chan_id_on_a.clone(),
+            msg_chan_close_init.port_id_on_a,
+            msg_chan_close_init.chan_id_on_a,
+            conn_id,
+        )]
+    );
+    assert_eq!(
+        context.events_of_type::<MessageEvent>(),
+        vec![MessageEvent::Channel]
+    );
+}
+
+#[test]
+fn test_chan_close_init_execute_only_closes_channel() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg_chan_close_init.port_id_on_a.clone(),
+            Some(msg_chan_close_init.chan_id_on_a.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let build_context = || {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+        default_context
+            .with_light_client(
+                &client_id,
+                LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            )
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_channel(
+                msg_chan_close_init.port_id_on_a.clone(),
+                msg_chan_close_init.chan_id_on_a.clone(),
+                chan_end.clone(),
+            )
+    };
+
+    let context_before = build_context();
+    let mut context = build_context();
+
+    assert!(context_before.diff(&context).is_empty());
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init.clone()));
+
+    execute(&mut context.ibc_store, &mut router, msg_envelope).unwrap();
+
+    let diff = context_before.diff(&context);
+
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        diff.changed,
+        vec![Path::ChannelEnd(ChannelEndPath::new(
+            &msg_chan_close_init.port_id_on_a,
+            &msg_chan_close_init.chan_id_on_a,
+        ))]
+    );
+}
+
+#[test]
+fn test_chan_close_init_validate_bech32_signer() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg_chan_close_init.port_id_on_a.clone(),
+            Some(msg_chan_close_init.chan_id_on_a.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let build_context = |hrp: &str| {
+        let default_context = MockContext::default().with_signer_bech32_prefix(hrp);
+        let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+        default_context
+            .with_light_client(
+                &client_id,
+                LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            )
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_channel(
+                msg_chan_close_init.port_id_on_a.clone(),
+                msg_chan_close_init.chan_id_on_a.clone(),
+                chan_end.clone(),
+            )
+    };
+
+    let router = MockRouter::new_with_transfer();
+
+    let context = build_context("cosmos");
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init.clone()));
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "bech32 signer with the host prefix is accepted"
+    );
+
+    // the permissive `Signer` parse accepts hex accounts
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(MsgChannelCloseInit {
+        signer: dummy_account_id(),
+        ..msg_chan_close_init.clone()
+    }));
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::SignerError(SignerError::InvalidBech32 { .. }))
+    ));
+
+    let context = build_context("osmo");
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init));
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::SignerError(
+            SignerError::MismatchedPrefix { .. }
+        ))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_chan_close_init_serde_roundtrip() {
+    use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+    use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+    let msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).unwrap();
+
+    let json = serde_json::to_string(&msg_chan_close_init).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MsgChannelCloseInit>(&json).unwrap(),
+        msg_chan_close_init
+    );
+
+    // packet data and proofs are hex-encoded and read back as bytes
+    for msg in [
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init)),
+        MsgEnvelope::from(PacketMsg::from(msg_recv_packet)),
+    ] {
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(serde_json::from_str::<MsgEnvelope>(&json).unwrap(), msg);
+    }
 }
@@ -1,5 +1,6 @@
 use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenAck};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -7,13 +8,16 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::primitives::proto::Protobuf;
 use ibc::core::primitives::*;
 use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_ack;
+use ibc_testkit::fixtures::core::commitment::dummy_membership_proof_bytes;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
@@ -185,6 +189,50 @@ fn chan_open_ack_fail_no_connection(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn chan_open_ack_fail_connection_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        mut conn_end_on_a,
+        chan_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    conn_end_on_a.set_state(ConnectionState::Init);
+
+    let context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    match res {
+        Err(ContextError::ChannelError(ChannelError::ConnectionNotOpen {
+            connection_id,
+            state,
+        })) => {
+            assert_eq!(connection_id, conn_id_on_a);
+            assert_eq!(state, ConnectionState::Init);
+        }
+        other => panic!("expected a `ConnectionNotOpen` error, got {other:?}"),
+    }
+}
+
 #[rstest]
 fn chan_open_ack_fail_no_channel(fixture: Fixture) {
     let Fixture {
@@ -256,3 +304,113 @@ fn chan_open_ack_fail_channel_wrong_state(fixture: Fixture) {
         "Validation fails because channel is in the wrong state"
     )
 }
+
+/// Replaying an `OpenAck` must not reopen a channel that is already past `Init`.
+#[rstest]
+#[case::open(State::Open)]
+#[case::closed(State::Closed)]
+fn chan_open_ack_fail_replayed(fixture: Fixture, #[case] state: State) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    let chan_end = ChannelEnd::new(
+        state,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_b.clone())),
+        vec![conn_id_on_a.clone()],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+    let context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidState { .. }
+            ))
+        ),
+        "Validation fails because channel is in the {state} state"
+    )
+}
+
+/// Both ends must agree on the ordering: the counterparty's channel end is
+/// only proven if it has the ordering of the local one.
+#[rstest]
+#[case::same_ordering(Order::Unordered, true)]
+#[case::mismatched_ordering(Order::Ordered, false)]
+fn chan_open_ack_ordering(
+    fixture: Fixture,
+    #[case] ordering_on_b: Order,
+    #[case] expect_success: bool,
+) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::TryOpen,
+        ordering_on_b,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        vec![ConnectionId::zero()],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+    msg.proof_chan_end_on_b = dummy_membership_proof_bytes(chan_end_on_b.encode_vec());
+
+    let context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    if expect_success {
+        assert!(res.is_ok(), "Validation succeeds: {res:?}");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ChannelError(
+                    ChannelError::VerifyChannelFailed(_)
+                ))
+            ),
+            "Validation fails because the orderings differ: {res:?}"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenConfirm};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
@@ -7,12 +8,15 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId};
+use ibc::core::primitives::proto::Protobuf;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_confirm;
+use ibc_testkit::fixtures::core::commitment::dummy_membership_proof_bytes;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
@@ -210,3 +214,109 @@ fn chan_open_confirm_fail_channel_wrong_state(fixture: Fixture) {
         "Validation fails because channel is in the wrong state"
     )
 }
+
+/// Replaying an `OpenConfirm` must be rejected once the channel is already
+/// `Open` or `Closed`.
+#[rstest]
+#[case::open(State::Open)]
+#[case::closed(State::Closed)]
+fn chan_open_confirm_fail_replayed(fixture: Fixture, #[case] state: State) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        proof_height,
+        ..
+    } = fixture;
+
+    let chan_end = ChannelEnd::new(
+        state,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_b.clone(), Some(ChannelId::zero())),
+        vec![conn_id_on_b.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+    let context = context
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(msg.port_id_on_b.clone(), ChannelId::zero(), chan_end);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidState { .. }
+            ))
+        ),
+        "Validation fails because channel is in the {state} state"
+    )
+}
+
+/// Both ends must agree on the ordering: the counterparty's channel end is
+/// only proven if it has the ordering of the local one.
+#[rstest]
+#[case::same_ordering(Order::Unordered, true)]
+#[case::mismatched_ordering(Order::Ordered, false)]
+fn chan_open_confirm_ordering(
+    fixture: Fixture,
+    #[case] ordering_on_a: Order,
+    #[case] expect_success: bool,
+) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_end_on_b,
+        proof_height,
+    } = fixture;
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        ordering_on_a,
+        Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+        vec![ConnectionId::zero()],
+        chan_end_on_b.version.clone(),
+    )
+    .unwrap();
+    msg.proof_chan_end_on_a = dummy_membership_proof_bytes(chan_end_on_a.encode_vec());
+
+    let context = context
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(msg.port_id_on_b.clone(), ChannelId::zero(), chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    if expect_success {
+        assert!(res.is_ok(), "Validation succeeds: {res:?}");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ChannelError(
+                    ChannelError::VerifyChannelFailed(_)
+                ))
+            ),
+            "Validation fails because the orderings differ: {res:?}"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use ibc::clients::tendermint::types::client_type as tm_client_type;
-use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit, CHAN_OPEN_INIT_TYPE_URL};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
@@ -110,6 +110,38 @@ fn chan_open_init_execute_happy_path(fixture: Fixture) {
     assert!(matches!(ibc_events[1], IbcEvent::OpenInitChannel(_)));
 }
 
+#[rstest]
+fn chan_open_init_execute_complete_hook(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    let signer = msg.signer().clone();
+
+    execute(&mut ctx.ibc_store, &mut router, msg).unwrap();
+
+    let executed_msgs = ctx.get_executed_msgs();
+
+    assert_eq!(executed_msgs.len(), 1);
+
+    let (msg_summary, events) = &executed_msgs[0];
+
+    assert_eq!(msg_summary.type_url, CHAN_OPEN_INIT_TYPE_URL);
+    assert_eq!(msg_summary.signer, signer);
+    assert_eq!(events, &ctx.get_events());
+
+    // a failed execution is not reported to the hook
+    let msg = MsgEnvelope::from(ChannelMsg::from(MsgChannelOpenInit {
+        port_id_on_a: "unbound".parse().unwrap(),
+        ..MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap()
+    }));
+
+    assert!(execute(&mut ctx.ibc_store, &mut router, msg).is_err());
+    assert_eq!(ctx.get_executed_msgs().len(), 1);
+}
+
 #[rstest]
 fn chan_open_init_fail_no_connection(fixture: Fixture) {
     let Fixture { router, msg, .. } = fixture;
@@ -121,3 +153,26 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_fail_frozen_client(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        router,
+        msg,
+    } = fixture;
+
+    let client_id_on_a = tm_client_type().build_client_id(0);
+    let frozen_height = Height::new(0, 9).unwrap();
+
+    ctx.freeze_client(&client_id_on_a, frozen_height);
+
+    assert_eq!(ctx.frozen_height(&client_id_on_a), Some(frozen_height));
+
+    let res = validate(&ctx.ibc_store, &router, msg);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the client is frozen"
+    )
+}
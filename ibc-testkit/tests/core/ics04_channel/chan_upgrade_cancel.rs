@@ -0,0 +1,227 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::events::UpgradeCancel;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeCancel};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_upgrade_cancel, dummy_raw_upgrade_fields,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeCancel,
+    pub client_id_on_a: ClientId,
+    pub conn_id_on_a: ConnectionId,
+    pub conn_end_on_a: ConnectionEnd,
+    pub chan_end_on_a: ChannelEnd,
+    pub upgrade_on_a: Upgrade,
+    pub proof_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgChannelUpgradeCancel::try_from(dummy_raw_msg_chan_upgrade_cancel(proof_height, 1))
+        .unwrap();
+
+    let client_id_on_a = mock_client_type().build_client_id(45);
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut chan_end_on_a = ChannelEnd::new(
+        State::Flushing,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    chan_end_on_a.set_upgrade_sequence(1);
+
+    let upgrade_on_a = Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields("ics20-2")).unwrap(),
+        UpgradeTimeout::none(),
+        Sequence::from(1),
+    );
+
+    Fixture {
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+        upgrade_on_a,
+        proof_height,
+    }
+}
+
+fn build_context(fixture: &Fixture, chan_end_on_a: ChannelEnd) -> MockContext {
+    MockContext::default()
+        .with_light_client(
+            &fixture.client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(
+                Height::new(0, fixture.proof_height).unwrap(),
+            ),
+        )
+        .with_connection(fixture.conn_id_on_a.clone(), fixture.conn_end_on_a.clone())
+        .with_channel(
+            fixture.msg.port_id_on_a.clone(),
+            fixture.msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_channel_upgrade(
+            fixture.msg.port_id_on_a.clone(),
+            fixture.msg.chan_id_on_a.clone(),
+            fixture.upgrade_on_a.clone(),
+        )
+}
+
+#[rstest]
+fn chan_upgrade_cancel_validate_happy_path(fixture: Fixture) {
+    let context = build_context(&fixture, fixture.chan_end_on_a.clone());
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path")
+}
+
+#[rstest]
+fn chan_upgrade_cancel_execute_happy_path(fixture: Fixture) {
+    let mut context = build_context(&fixture, fixture.chan_end_on_a.clone());
+    let Fixture {
+        mut router, msg, ..
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = context.ibc_store.channel_end(&chan_end_path_on_a).unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+    assert_eq!(
+        chan_end_on_a.version(),
+        &Version::new("ics20-1".to_string())
+    );
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade = context
+        .ibc_store
+        .channel_upgrade(&upgrade_path_on_a)
+        .unwrap();
+
+    assert!(upgrade.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeCancelChannel(_)));
+
+    assert_eq!(context.events_of_type::<UpgradeCancel>().len(), 1);
+}
+
+#[rstest]
+fn chan_upgrade_cancel_by_authority_without_error_receipt(mut fixture: Fixture) {
+    fixture.msg.error_receipt_on_b = None;
+    fixture.msg.proof_error_receipt_on_b = None;
+    fixture.msg.proof_height_on_b = None;
+
+    let context = build_context(&fixture, fixture.chan_end_on_a.clone());
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds because the upgrade authority cancels the upgrade"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_cancel_fail_unauthorized_without_error_receipt(mut fixture: Fixture) {
+    fixture.msg.error_receipt_on_b = None;
+    fixture.msg.proof_error_receipt_on_b = None;
+    fixture.msg.proof_height_on_b = None;
+
+    let context = build_context(&fixture, fixture.chan_end_on_a.clone())
+        .with_upgrade_authority(Signer::from("authority".to_string()));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::MissingErrorReceipt
+            ))
+        ),
+        "Validation fails because only the upgrade authority may cancel without an error receipt"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_cancel_fail_flush_complete_stale_error_receipt(fixture: Fixture) {
+    let mut chan_end_on_a = fixture.chan_end_on_a.clone();
+    chan_end_on_a.set_state(State::FlushComplete);
+    chan_end_on_a.set_upgrade_sequence(2);
+
+    let context = build_context(&fixture, chan_end_on_a);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidErrorReceiptSequence { .. }
+            ))
+        ),
+        "Validation fails because the error receipt is for an earlier upgrade"
+    )
+}
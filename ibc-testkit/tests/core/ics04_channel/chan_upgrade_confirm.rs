@@ -0,0 +1,222 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::events::{UpgradeConfirm, UpgradeOpen};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeConfirm};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_upgrade_confirm, dummy_raw_upgrade, dummy_raw_upgrade_fields,
+};
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeConfirm,
+    pub client_id_on_b: ClientId,
+    pub conn_id_on_b: ConnectionId,
+    pub conn_end_on_b: ConnectionEnd,
+    pub chan_end_on_b: ChannelEnd,
+    pub upgrade_on_b: Upgrade,
+    pub proof_height: u64,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgChannelUpgradeConfirm::try_from(dummy_raw_msg_chan_upgrade_confirm(
+        proof_height,
+        State::FlushComplete as i32,
+        dummy_raw_upgrade("ics20-2", u64::MAX, 1),
+    ))
+    .unwrap();
+
+    let client_id_on_b = mock_client_type().build_client_id(45);
+    let conn_id_on_b = ConnectionId::zero();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut chan_end_on_b = ChannelEnd::new(
+        State::Flushing,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_b.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    chan_end_on_b.set_upgrade_sequence(1);
+
+    let upgrade_on_b = Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields("ics20-2")).unwrap(),
+        UpgradeTimeout::none(),
+        Sequence::from(1),
+    );
+
+    Fixture {
+        router,
+        msg,
+        client_id_on_b,
+        conn_id_on_b,
+        conn_end_on_b,
+        chan_end_on_b,
+        upgrade_on_b,
+        proof_height,
+    }
+}
+
+fn build_context(fixture: &Fixture, chan_end_on_b: ChannelEnd) -> MockContext {
+    MockContext::default()
+        .with_light_client(
+            &fixture.client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(
+                Height::new(0, fixture.proof_height).unwrap(),
+            ),
+        )
+        .with_connection(fixture.conn_id_on_b.clone(), fixture.conn_end_on_b.clone())
+        .with_channel(
+            fixture.msg.port_id_on_b.clone(),
+            fixture.msg.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_channel_upgrade(
+            fixture.msg.port_id_on_b.clone(),
+            fixture.msg.chan_id_on_b.clone(),
+            fixture.upgrade_on_b.clone(),
+        )
+}
+
+#[rstest]
+fn chan_upgrade_confirm_validate_happy_path(fixture: Fixture) {
+    let context = build_context(&fixture, fixture.chan_end_on_b.clone());
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path")
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_opens_flushed_channel(fixture: Fixture) {
+    let mut context = build_context(&fixture, fixture.chan_end_on_b.clone());
+    let Fixture {
+        mut router, msg, ..
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    // Both ends flushed their packets, so the upgrade completes right away.
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = context.ibc_store.channel_end(&chan_end_path_on_b).unwrap();
+
+    assert_eq!(chan_end_on_b.state(), &State::Open);
+    assert_eq!(
+        chan_end_on_b.version(),
+        &Version::new("ics20-2".to_string())
+    );
+    assert_eq!(chan_end_on_b.upgrade_sequence(), 1);
+
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade = context
+        .ibc_store
+        .channel_upgrade(&upgrade_path_on_b)
+        .unwrap();
+
+    assert!(upgrade.is_none());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+    assert!(matches!(ibc_events[3], IbcEvent::UpgradeOpenChannel(_)));
+
+    assert_eq!(context.events_of_type::<UpgradeConfirm>().len(), 1);
+    assert_eq!(context.events_of_type::<UpgradeOpen>().len(), 1);
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_waits_for_counterparty(mut fixture: Fixture) {
+    fixture.msg.counterparty_chan_state = State::Flushing;
+
+    let mut context = build_context(&fixture, fixture.chan_end_on_b.clone());
+    let Fixture {
+        mut router, msg, ..
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = context.ibc_store.channel_end(&chan_end_path_on_b).unwrap();
+
+    assert_eq!(chan_end_on_b.state(), &State::FlushComplete);
+    assert_eq!(
+        chan_end_on_b.version(),
+        &Version::new("ics20-1".to_string())
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+}
+
+#[rstest]
+fn chan_upgrade_confirm_fail_channel_not_flushing(fixture: Fixture) {
+    let mut chan_end_on_b = fixture.chan_end_on_b.clone();
+    chan_end_on_b.set_state(State::Open);
+
+    let context = build_context(&fixture, chan_end_on_b);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(fixture.msg));
+
+    let res = validate(&context.ibc_store, &fixture.router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidState { .. }
+            ))
+        ),
+        "Validation fails because the channel is not flushing"
+    )
+}
@@ -0,0 +1,289 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeInit};
+use ibc::core::channel::types::Version;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_init;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeInit,
+    pub conn_id_on_a: ConnectionId,
+    pub conn_end_on_a: ConnectionEnd,
+    pub chan_end_on_a: ChannelEnd,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let context = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init()).unwrap();
+
+    let conn_id_on_a = ConnectionId::zero();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        mock_client_type().build_client_id(0),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id_on_a.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    Fixture {
+        context,
+        router,
+        msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_init_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let context = context
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path")
+}
+
+#[rstest]
+fn chan_upgrade_init_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let mut context = context
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = context.ibc_store.channel_end(&chan_end_path_on_a).unwrap();
+
+    // The channel stays open until the counterparty agrees to the upgrade.
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+    assert_eq!(chan_end_on_a.upgrade_sequence(), 1);
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade = context
+        .ibc_store
+        .channel_upgrade(&upgrade_path_on_a)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(upgrade.fields, msg.fields);
+    assert!(!upgrade.timeout.is_set());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeInitChannel(_)));
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_fields_unchanged(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    msg.fields.version = chan_end_on_a.version.clone();
+
+    let context = context
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::UpgradeFieldsUnchanged
+            ))
+        ),
+        "Validation fails because the upgrade changes nothing"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_channel_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        mut chan_end_on_a,
+    } = fixture;
+
+    chan_end_on_a.set_state(State::Init);
+
+    let context = context
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidState { .. }
+            ))
+        ),
+        "Validation fails because the channel is not open"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_connection_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_id_on_a,
+        mut conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    conn_end_on_a.set_state(ConnectionState::TryOpen);
+
+    let context = context
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::ConnectionNotOpen { .. }
+            ))
+        ),
+        "Validation fails because the proposed connection is not open"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_unauthorized_signer(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let context = context
+        .with_upgrade_authority(Signer::from("authority".to_string()))
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::UnauthorizedUpgradeSigner { .. }
+            ))
+        ),
+        "Validation fails because only the upgrade authority may initiate upgrades"
+    )
+}
@@ -0,0 +1,124 @@
+use ibc::core::channel::types::channel::{ChannelEnd, State};
+use ibc::core::host::types::error::IdentifierError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc_proto::ibc::core::channel::v1::QueryChannelRequest as RawQueryChannelRequest;
+use ibc_query::core::channel::{
+    query_channel, query_port_channels, QueryChannelRequest, QueryPortChannelsRequest,
+};
+use ibc_query::error::QueryError;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_channel_end;
+use rstest::*;
+use test_log::test;
+
+/// Returns a channel end in the `Init` state.
+fn init_channel_end() -> ChannelEnd {
+    ChannelEnd::try_from(dummy_raw_channel_end(1, None)).unwrap()
+}
+
+#[test]
+fn query_channels_by_port() {
+    // `transfer-2` shares the `transfer` prefix but is a distinct port
+    let ctx = MockContext::default()
+        .with_channel(PortId::transfer(), ChannelId::new(0), init_channel_end())
+        .with_channel(PortId::transfer(), ChannelId::new(1), init_channel_end())
+        .with_channel(
+            "transfer-2".parse().unwrap(),
+            ChannelId::new(7),
+            init_channel_end(),
+        )
+        .with_channel(
+            "oracle".parse().unwrap(),
+            ChannelId::new(3),
+            init_channel_end(),
+        );
+
+    let request = QueryPortChannelsRequest {
+        port_id: PortId::transfer(),
+    };
+    let mut channel_ids = query_port_channels(&ctx.ibc_store, &request)
+        .unwrap()
+        .channel_ids;
+    channel_ids.sort();
+
+    assert_eq!(channel_ids, vec![ChannelId::new(0), ChannelId::new(1)]);
+
+    let request = QueryPortChannelsRequest {
+        port_id: "oracle".parse().unwrap(),
+    };
+    let channel_ids = query_port_channels(&ctx.ibc_store, &request)
+        .unwrap()
+        .channel_ids;
+
+    assert_eq!(channel_ids, vec![ChannelId::new(3)]);
+}
+
+#[test]
+fn query_channel_at_height() {
+    let mut ctx = MockContext::default().with_channel(
+        PortId::transfer(),
+        ChannelId::zero(),
+        init_channel_end(),
+    );
+    ctx.advance_block_height();
+
+    let init_height = ctx.latest_height();
+
+    // move the channel to `Open` in a later block
+    let channel_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+    let mut channel_end = ctx.ibc_store.channel_end(&channel_end_path).unwrap();
+    channel_end.set_state(State::Open);
+    ctx.ibc_store
+        .store_channel(&channel_end_path, channel_end)
+        .unwrap();
+    ctx.advance_block_height();
+
+    let mut request = QueryChannelRequest {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::zero(),
+        query_height: Some(init_height),
+    };
+    let response = query_channel(&ctx.ibc_store, &request).unwrap();
+
+    assert_eq!(response.channel.state(), &State::Init);
+    assert_eq!(response.proof_height, init_height);
+
+    request.query_height = None;
+    let response = query_channel(&ctx.ibc_store, &request).unwrap();
+
+    assert_eq!(response.channel.state(), &State::Open);
+    assert_eq!(response.proof_height, ctx.latest_height());
+
+    request.query_height = Some(ctx.latest_height().increment());
+    let err = query_channel(&ctx.ibc_store, &request).unwrap_err();
+
+    assert!(matches!(err, QueryError::InvalidArgument(_)));
+}
+
+/// A malformed identifier in a query request is reported with the reason it
+/// was rejected.
+#[rstest]
+#[case("t", "channel-0", "t")]
+#[case("trans/fer", "channel-0", "trans/fer")]
+#[case("transfer", "chan-007x", "chan-007x")]
+fn query_channel_invalid_identifier(
+    #[case] port_id: &str,
+    #[case] channel_id: &str,
+    #[case] invalid_id: &str,
+) {
+    let raw_request = RawQueryChannelRequest {
+        port_id: port_id.to_string(),
+        channel_id: channel_id.to_string(),
+    };
+
+    match QueryChannelRequest::try_from(raw_request) {
+        Err(QueryError::IdentifierError(
+            IdentifierError::InvalidLength { ref id, .. }
+            | IdentifierError::InvalidCharacter { ref id }
+            | IdentifierError::InvalidPrefix { prefix: ref id },
+        )) => assert_eq!(id, invalid_id),
+        other => panic!("expected an identifier error, got {other:?}"),
+    }
+}
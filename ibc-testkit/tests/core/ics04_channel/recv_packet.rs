@@ -1,24 +1,62 @@
+use core::time::Duration;
+
+#[cfg(feature = "serde")]
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+#[cfg(feature = "serde")]
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::entrypoint::{
+    dispatch, execute, prune_acknowledgements, recv_packets_execute, validate,
+};
+use ibc::core::handler::dry_run::{execute_dry_run, StateValue};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+#[cfg(feature = "serde")]
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::types::path::{AckPath, ChannelEndPath, Path, ReceiptPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
+#[cfg(feature = "serde")]
+use ibc::core::router::router::Router;
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc_proto::ibc::core::connection::v1::Version as RawConnectionVersion;
+use ibc_query::core::channel::{
+    query_packet_acknowledgements, query_unreceived_packets, QueryPacketAcknowledgementsRequest,
+    QueryUnreceivedPacketsRequest,
+};
+#[cfg(feature = "serde")]
+use ibc_query::core::context::ProvableContext;
+use ibc_query::core::context::QueryContext;
 use ibc_testkit::context::MockContext;
+#[cfg(feature = "serde")]
+use ibc_testkit::context::TestContext;
+#[cfg(feature = "serde")]
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::MockHost;
+#[cfg(feature = "serde")]
+use ibc_testkit::hosts::TendermintHost;
+#[cfg(feature = "serde")]
+use ibc_testkit::relayer::context::RelayerContext;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, DEFAULT_BLOCK_TIME_SECS};
 use rstest::*;
 use test_log::test;
 
@@ -151,6 +189,215 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_fail_channel_paused(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    context
+        .ibc_store
+        .set_channel_flow_status(&chan_end_path_on_b, true)
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::ChannelPaused { .. }
+            ))
+        ),
+        "Validation fails because the channel is paused"
+    );
+
+    context
+        .ibc_store
+        .set_channel_flow_status(&chan_end_path_on_b, false)
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the channel is resumed. err: {res:?}"
+    )
+}
+
+/// On ordered channels, a packet relayed ahead of the next expected sequence
+/// is rejected, as receiving it would skip the packets in between.
+#[rstest]
+fn recv_packet_fail_ordered_sequence_gap(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    let next_seq_recv = msg.packet.seq_on_a;
+    msg.packet.seq_on_a = next_seq_recv.increment();
+
+    let packet = &msg.packet;
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let context = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            next_seq_recv,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    match res {
+        Err(ContextError::ChannelError(ChannelError::UnexpectedPacketSequence {
+            expected,
+            got,
+        })) => {
+            assert_eq!(expected, next_seq_recv);
+            assert_eq!(got, next_seq_recv.increment());
+        }
+        other => panic!("expected an `UnexpectedPacketSequence` error, got {other:?}"),
+    }
+}
+
+#[rstest]
+fn recv_packet_fail_order_mismatch(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    // the connection only negotiated unordered channels
+    let unordered_version = ConnectionVersion::try_from(RawConnectionVersion {
+        identifier: "1".to_string(),
+        features: vec!["ORDER_UNORDERED".to_string()],
+    })
+    .unwrap();
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        vec![unordered_version],
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let context = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_send_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            1.into(),
+        )
+        .advance_block_up_to_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::OrderMismatch { .. }))
+        ),
+        "Validation fails because the channel order was not negotiated by the connection. err: {res:?}"
+    )
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -241,3 +488,678 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+/// A dry run reports the receipt and acknowledgement a packet would leave
+/// behind, without storing them.
+#[rstest]
+fn recv_packet_dry_run(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .advance_block_up_to_height(host_height);
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    let receipt_path =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    let diff = execute_dry_run(&mut ctx.ibc_store, &router, msg_env.clone()).unwrap();
+
+    assert!(matches!(
+        diff.writes.get(&Path::from(receipt_path)),
+        Some(Some(StateValue::Receipt(Receipt::Ok)))
+    ));
+    assert!(matches!(
+        diff.writes.get(&Path::from(ack_path.clone())),
+        Some(Some(StateValue::Acknowledgement(_)))
+    ));
+    assert_eq!(diff.events.len(), 4);
+
+    // nothing was kept
+    assert!(ctx.ibc_store.get_packet_acknowledgement(&ack_path).is_err());
+    assert!(ctx.get_events().is_empty());
+    assert!(ctx.get_executed_msgs().is_empty());
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).unwrap();
+
+    // the actual acknowledgement is only produced by the module
+    assert_eq!(ctx.get_events().len(), diff.events.len());
+}
+
+/// A dry run never runs the execution callbacks of the module, which keeps
+/// its own state.
+///
+/// Requires `serde` feature because of the dummy transfer packet.
+#[cfg(feature = "serde")]
+#[rstest]
+fn recv_packet_dry_run_keeps_module_state(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    // a transfer of a token the module mints vouchers for
+    let data: PacketData = PacketDataConfig::builder()
+        .token("100uatom".parse().unwrap())
+        .build();
+    msg.packet.data = serde_json::to_vec(&data).unwrap();
+
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .advance_block_up_to_height(host_height);
+
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let module_state = |router: &MockRouter| format!("{:?}", router.get_route(&module_id).unwrap());
+    let module_state_before = module_state(&router);
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute_dry_run(&mut ctx.ibc_store, &router, msg_env.clone()).unwrap();
+
+    assert_eq!(module_state(&router), module_state_before);
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).unwrap();
+
+    assert_ne!(
+        module_state(&router),
+        module_state_before,
+        "executing the packet mints the vouchers"
+    );
+}
+
+/// A dry run validates the message first, and fails without executing it if
+/// the validation does.
+#[rstest]
+fn recv_packet_dry_run_fail_no_channel(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+        ..
+    } = fixture;
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute_dry_run(&mut context.ibc_store, &router, msg_env);
+
+    assert!(res.is_err(), "dry run should fail on an unknown channel");
+    assert!(context.get_events().is_empty());
+}
+
+/// Relaying a packet again on an unordered channel is a no-op: the module
+/// callback only fires for the first receipt, whose acknowledgement is kept.
+#[rstest]
+fn recv_packet_unordered_replay_is_noop(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    dispatch(&mut ctx.ibc_store, &mut router, msg_envelope.clone()).unwrap();
+
+    let ack = ctx.ibc_store.get_packet_acknowledgement(&ack_path).unwrap();
+    let events = ctx.get_events();
+
+    dispatch(&mut ctx.ibc_store, &mut router, msg_envelope).unwrap();
+
+    // the core handler only emits `ReceivePacket` along with the module
+    // callback
+    let recv_events = ctx
+        .get_events()
+        .into_iter()
+        .filter(|event| matches!(event, IbcEvent::ReceivePacket(_)))
+        .count();
+
+    assert_eq!(recv_events, 1);
+    assert_eq!(ctx.get_events(), events);
+    assert_eq!(
+        ctx.ibc_store.get_packet_acknowledgement(&ack_path).unwrap(),
+        ack
+    );
+    assert_eq!(
+        ctx.get_logs().last().map(String::as_str),
+        Some("no-op: packet already received")
+    );
+}
+
+/// A packet can only be received once both the time and the number of blocks
+/// of the connection delay period have passed since the client was updated at
+/// the proof height.
+#[rstest]
+fn recv_packet_delay_period(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        chan_end_on_b,
+        client_height,
+        client_id,
+        ..
+    } = fixture;
+
+    let delay_blocks = 3;
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+    )
+    .unwrap();
+
+    // keep the packet from timing out while the host advances
+    msg.packet.timeout_height_on_b = TimeoutHeight::At(Height::new(0, 1000).unwrap());
+    msg.packet.timeout_timestamp_on_b = Timestamp::none();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay period hasn't passed. err: {res:?}"
+    );
+
+    // the whole delay time passes within a single block
+    context.advance_block_height_with_params(
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+        &Default::default(),
+    );
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay blocks haven't passed. err: {res:?}"
+    );
+
+    for _ in 1..delay_blocks {
+        context.advance_block_height();
+    }
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the delay period has passed. err: {res:?}"
+    );
+}
+
+/// A packet sent to a port whose module isn't registered with the router is
+/// rejected, rather than acknowledged.
+#[rstest]
+fn recv_packet_fail_module_not_found(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    // the port is bound to a module which was never added to the router
+    let mut router = MockRouter::default();
+    router
+        .scope_port_to_module(PortId::transfer(), ModuleId::new(MODULE_ID_STR.to_string()))
+        .unwrap();
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    for res in [
+        validate(&ctx.ibc_store, &router, msg_env.clone()),
+        execute(&mut ctx.ibc_store, &mut router, msg_env),
+    ] {
+        match res {
+            Err(ContextError::RouterError(RouterError::ModuleNotFound { port_id })) => {
+                assert_eq!(port_id, PortId::transfer())
+            }
+            other => panic!("expected a `ModuleNotFound` error, got {other:?}"),
+        }
+    }
+
+    let receipt_path = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+    assert!(ctx.ibc_store.get_packet_receipt(&receipt_path).is_err());
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn prune_acknowledgements_on_proof_of_relay(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    for seq in 1..=5u64 {
+        let ack_path = AckPath::new(&PortId::transfer(), &ChannelId::zero(), seq.into());
+        ctx.ibc_store
+            .store_packet_acknowledgement(&ack_path, vec![1].into())
+            .unwrap();
+    }
+
+    let channel_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+    let acked_sequences = |ctx: &MockContext| {
+        let request = QueryPacketAcknowledgementsRequest {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            packet_commitment_sequences: Vec::new(),
+            pagination: None,
+        };
+        let mut sequences: Vec<u64> = query_packet_acknowledgements(&ctx.ibc_store, &request)
+            .unwrap()
+            .acknowledgements
+            .into_iter()
+            .map(|ack| ack.seq.value())
+            .collect();
+        sequences.sort();
+        sequences
+    };
+    let relayed = |sequences: &[u64]| -> Vec<_> {
+        sequences
+            .iter()
+            .map(|seq| (Sequence::from(*seq), msg.proof_commitment_on_a.clone()))
+            .collect()
+    };
+
+    // pruning is opt-in
+    let res = prune_acknowledgements(
+        &mut ctx.ibc_store,
+        &channel_end_path,
+        5.into(),
+        &relayed(&[1, 2, 3, 4]),
+        msg.proof_height_on_a,
+    );
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ChannelError(
+            ChannelError::AckPruningNotSupportedByHost
+        ))
+    ));
+
+    // the ack of packet 4 is retained, so only acks 1 to 3 are pruned
+    let mut ctx = ctx.with_ack_retention_window(1);
+
+    // every ack to prune must be proven relayed
+    let res = prune_acknowledgements(
+        &mut ctx.ibc_store,
+        &channel_end_path,
+        5.into(),
+        &relayed(&[1, 2]),
+        msg.proof_height_on_a,
+    );
+
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(PacketError::MissingRelayProof { sequence }))
+            if sequence == Sequence::from(3)
+    ));
+
+    // proofs at a height the client doesn't know of are rejected
+    let res = prune_acknowledgements(
+        &mut ctx.ibc_store,
+        &channel_end_path,
+        5.into(),
+        &relayed(&[1, 2, 3]),
+        client_height.add(5),
+    );
+
+    assert!(res.is_err());
+    assert_eq!(acked_sequences(&ctx), vec![1, 2, 3, 4, 5]);
+
+    let pruned = prune_acknowledgements(
+        &mut ctx.ibc_store,
+        &channel_end_path,
+        5.into(),
+        &relayed(&[1, 2, 3]),
+        msg.proof_height_on_a,
+    )
+    .unwrap();
+
+    assert_eq!(pruned, 3);
+    assert_eq!(acked_sequences(&ctx), vec![4, 5]);
+}
+
+#[rstest]
+fn recv_packet_unreceived_packets_by_ordering(fixture: Fixture) {
+    let Fixture {
+        context,
+        chan_end_on_b,
+        ..
+    } = fixture;
+
+    let chan_end_on_b_ordered = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        chan_end_on_b.counterparty().clone(),
+        chan_end_on_b.connection_hops().clone(),
+        chan_end_on_b.version().clone(),
+    )
+    .unwrap();
+
+    // no receipts are written for either channel; the ordered one has
+    // received everything below sequence 3
+    let ctx = context
+        .with_channel(PortId::transfer(), ChannelId::new(0), chan_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::new(1), chan_end_on_b_ordered)
+        .with_recv_sequence(PortId::transfer(), ChannelId::new(1), 3.into());
+
+    let unreceived_packets = |channel_id: ChannelId| {
+        let request = QueryUnreceivedPacketsRequest {
+            port_id: PortId::transfer(),
+            channel_id,
+            packet_commitment_sequences: (1..=4u64).map(Into::into).collect(),
+        };
+        query_unreceived_packets(&ctx.ibc_store, &request)
+            .unwrap()
+            .sequences
+    };
+
+    assert_eq!(
+        unreceived_packets(ChannelId::new(0)),
+        (1..=4u64).map(Into::into).collect::<Vec<Sequence>>()
+    );
+    assert_eq!(
+        unreceived_packets(ChannelId::new(1)),
+        vec![Sequence::from(3), Sequence::from(4)]
+    );
+}
+
+#[rstest]
+fn recv_packet_unreceived_in_range(fixture: Fixture) {
+    let Fixture {
+        context,
+        chan_end_on_b,
+        ..
+    } = fixture;
+
+    let mut ctx = context.with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    // every third packet of the range has been received
+    let end = 10_000u64;
+    for seq in (1..=end).step_by(3) {
+        let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), seq.into());
+        ctx.ibc_store
+            .store_packet_receipt(&receipt_path, Receipt::Ok)
+            .unwrap();
+    }
+
+    let channel_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+
+    let in_range = ctx
+        .ibc_store
+        .unreceived_in_range(&channel_end_path, 1.into(), end.into())
+        .unwrap();
+
+    let sequences: Vec<Sequence> = (1..=end).map(Into::into).collect();
+    let point_lookups = ctx
+        .ibc_store
+        .unreceived_packets(&channel_end_path, sequences.into_iter())
+        .unwrap();
+
+    assert_eq!(in_range, point_lookups);
+    assert_eq!(in_range.len(), 6_666);
+    assert!(in_range.iter().all(|seq| seq.value() % 3 != 1));
+
+    // the range bounds are inclusive
+    assert_eq!(
+        ctx.ibc_store
+            .unreceived_in_range(&channel_end_path, 2.into(), 4.into())
+            .unwrap(),
+        vec![Sequence::from(2), Sequence::from(3)]
+    );
+
+    // the query answers the same whether or not the requested sequences form
+    // a contiguous range
+    let unreceived_packets = |sequences: &[u64]| {
+        let request = QueryUnreceivedPacketsRequest {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            packet_commitment_sequences: sequences.iter().copied().map(Into::into).collect(),
+        };
+        query_unreceived_packets(&ctx.ibc_store, &request)
+            .unwrap()
+            .sequences
+    };
+
+    assert_eq!(unreceived_packets(&(1..=end).collect::<Vec<_>>()), in_range);
+    assert_eq!(
+        unreceived_packets(&[2, 3, 4]),
+        vec![Sequence::from(2), Sequence::from(3)]
+    );
+    assert_eq!(
+        unreceived_packets(&[2, 4, 5, 7]),
+        vec![Sequence::from(2), Sequence::from(5)]
+    );
+}
+
+#[rstest]
+fn recv_packets_execute_fail_too_many_packets(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        ..
+    } = fixture;
+
+    let mut context = context.with_max_messages_per_tx(1);
+
+    let mut second_packet = msg.packet.clone();
+    second_packet.seq_on_a = second_packet.seq_on_a.increment();
+
+    let res = recv_packets_execute(
+        &mut context.ibc_store,
+        &mut router,
+        vec![
+            (msg.packet.clone(), msg.proof_commitment_on_a.clone()),
+            (second_packet, msg.proof_commitment_on_a.clone()),
+        ],
+        msg.proof_height_on_a,
+        msg.signer,
+    );
+
+    assert!(
+        matches!(
+            res,
+            Err((1, ContextError::TooManyMessages { count: 2, max: 1 }))
+        ),
+        "batches over the cap must be rejected before any packet is processed"
+    );
+}
+
+/// A batch of packets proven at the same height is received up to the first
+/// packet whose commitment proof does not verify.
+///
+/// Requires `serde` feature because of the dummy transfer packets.
+#[cfg(feature = "serde")]
+#[test]
+fn recv_packets_execute_stops_at_invalid_proof() {
+    let signer = dummy_account_id();
+
+    let mut relayer = RelayerContext::new(
+        TestContext::<TendermintHost>::default(),
+        TestContext::<TendermintHost>::default(),
+    );
+
+    let client_id_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_on_a, conn_id_on_b) =
+        relayer.create_connection_on_a(client_id_on_a, client_id_on_b.clone(), signer.clone());
+
+    let (chan_id_on_a, chan_id_on_b) = relayer.create_channel_on_a(
+        conn_id_on_a,
+        PortId::transfer(),
+        conn_id_on_b,
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    let packets: Vec<Packet> = (0..3)
+        .map(|_| relayer.send_dummy_transfer_packet_on_a(chan_id_on_a.clone(), signer.clone()))
+        .collect();
+
+    relayer.update_client_on_b_with_sync(client_id_on_b, signer.clone());
+
+    let proof_height_on_a = relayer.get_ctx_a().latest_height();
+
+    let mut proofs: Vec<CommitmentProofBytes> = packets
+        .iter()
+        .map(|packet| {
+            relayer
+                .get_ctx_a()
+                .ibc_store()
+                .get_proof(
+                    proof_height_on_a,
+                    &CommitmentPath::new(
+                        &packet.port_id_on_a,
+                        &packet.chan_id_on_a,
+                        packet.seq_on_a,
+                    )
+                    .into(),
+                )
+                .expect("commitment proof exists")
+                .try_into()
+                .expect("value merkle proof")
+        })
+        .collect();
+
+    // the second packet is relayed with the proof of the first one
+    proofs[1] = proofs[0].clone();
+
+    let ctx_b = relayer.get_ctx_b_mut();
+
+    let res = recv_packets_execute(
+        &mut ctx_b.ibc_store,
+        &mut ctx_b.ibc_router,
+        packets.iter().cloned().zip(proofs).collect(),
+        proof_height_on_a,
+        signer,
+    );
+
+    match res {
+        Err((
+            1,
+            ContextError::PacketError(PacketError::Channel(
+                ChannelError::PacketVerificationFailed { sequence, .. },
+            )),
+        )) => assert_eq!(sequence, packets[1].seq_on_a),
+        other => panic!("expected the second packet to fail verification, got {other:?}"),
+    }
+
+    let is_received = |packet: &Packet| {
+        ctx_b
+            .ibc_store
+            .get_packet_receipt(&ReceiptPath::new(
+                &PortId::transfer(),
+                &chan_id_on_b,
+                packet.seq_on_a,
+            ))
+            .is_ok()
+    };
+
+    assert!(is_received(&packets[0]));
+    assert!(!is_received(&packets[1]));
+    assert!(!is_received(&packets[2]));
+}
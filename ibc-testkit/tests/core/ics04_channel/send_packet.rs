@@ -3,6 +3,8 @@ use core::time::Duration;
 
 use ibc::core::channel::handler::send_packet;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{CommitmentHasher, HashAlgorithm};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
@@ -12,9 +14,17 @@ use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
+use ibc_query::core::channel::{
+    query_channel_flow_status, query_channel_params, query_commitment_sequence_bounds,
+    QueryChannelFlowStatusRequest, QueryChannelParamsRequest, QueryCommitmentSequenceBoundsRequest,
+};
+use ibc_query::core::context::QueryContext;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
 use ibc_testkit::hosts::MockHost;
@@ -206,3 +216,339 @@ fn send_packet_processing() {
         }
     }
 }
+
+#[test]
+fn send_packet_pending_packets() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let packets: Vec<Packet> = (1..=2u64)
+        .map(|seq| {
+            let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+                .try_into()
+                .unwrap();
+            packet.seq_on_a = seq.into();
+            packet.data = vec![seq as u8];
+            packet
+        })
+        .collect();
+
+    for packet in packets.iter() {
+        send_packet(&mut ctx.ibc_store, packet.clone()).expect("no error");
+    }
+
+    let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+
+    let pending_packets = ctx.ibc_store.pending_packets(&chan_end_path).unwrap();
+    assert_eq!(pending_packets, packets);
+
+    // Once the commitment of the first packet is cleared, only the second one is pending.
+    ctx.ibc_store
+        .delete_packet_commitment(&CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            1.into(),
+        ))
+        .expect("no error");
+
+    let pending_packets = ctx.ibc_store.pending_packets(&chan_end_path).unwrap();
+    assert_eq!(pending_packets, packets[1..]);
+
+    // No packets were sent over other channels.
+    let other_chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(1));
+    assert!(ctx
+        .ibc_store
+        .pending_packets(&other_chan_end_path)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn send_packet_commitment_sequence_bounds() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let request = QueryCommitmentSequenceBoundsRequest {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::zero(),
+    };
+    let bounds = |ctx: &MockContext| {
+        query_commitment_sequence_bounds(&ctx.ibc_store, &request)
+            .unwrap()
+            .bounds
+    };
+
+    // nothing was sent yet
+    assert_eq!(bounds(&ctx), None);
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    for seq in 1..=5u64 {
+        let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+            .try_into()
+            .unwrap();
+        packet.seq_on_a = seq.into();
+        packet.data = vec![seq as u8];
+
+        send_packet(&mut ctx.ibc_store, packet).expect("no error");
+    }
+
+    // clear the commitments of the first and third packets, leaving `2, 4, 5`
+    for seq in [1u64, 3] {
+        ctx.ibc_store
+            .delete_packet_commitment(&CommitmentPath::new(
+                &PortId::transfer(),
+                &ChannelId::zero(),
+                seq.into(),
+            ))
+            .expect("no error");
+    }
+
+    let response = query_commitment_sequence_bounds(&ctx.ibc_store, &request).unwrap();
+    assert_eq!(response.min_sequence(), Some(2.into()));
+    assert_eq!(response.max_sequence(), Some(5.into()));
+
+    ctx.ibc_store
+        .delete_packet_commitment(&CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            5.into(),
+        ))
+        .expect("no error");
+
+    assert_eq!(bounds(&ctx), Some((2.into(), 4.into())));
+}
+
+#[test]
+fn send_packet_commitment_algorithm_query() {
+    let ctx = MockContext::default();
+
+    let response = query_channel_params(&ctx.ibc_store, &QueryChannelParamsRequest).unwrap();
+
+    assert_eq!(response.packet_commitment_algorithm, HashAlgorithm::Sha256);
+    assert_eq!(response.packet_commitment_algorithm.to_string(), "sha256");
+}
+
+#[test]
+fn send_packet_paused_channel() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = vec![0];
+
+    let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+    let flow_status_request = QueryChannelFlowStatusRequest {
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::zero(),
+    };
+
+    ctx.ibc_store
+        .set_channel_flow_status(&chan_end_path, true)
+        .expect("no error");
+
+    assert!(
+        query_channel_flow_status(&ctx.ibc_store, &flow_status_request)
+            .unwrap()
+            .paused
+    );
+
+    let res = send_packet(&mut ctx.ibc_store, packet.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::ChannelPaused { .. }
+            ))
+        ),
+        "sending fails while the channel is paused"
+    );
+
+    ctx.ibc_store
+        .set_channel_flow_status(&chan_end_path, false)
+        .expect("no error");
+
+    assert!(
+        !query_channel_flow_status(&ctx.ibc_store, &flow_status_request)
+            .unwrap()
+            .paused
+    );
+
+    send_packet(&mut ctx.ibc_store, packet).expect("sending succeeds once the channel is resumed");
+}
+
+/// Returns its input unchanged, so that commitments are predictable.
+#[derive(Debug)]
+struct IdentityHasher;
+
+impl CommitmentHasher for IdentityHasher {
+    fn hash(&self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Other("identity".to_string())
+    }
+}
+
+#[test]
+fn send_packet_custom_commitment_hasher() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+        .with_commitment_hasher(IdentityHasher);
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+    let packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+
+    send_packet(&mut ctx.ibc_store, packet.clone()).expect("no error");
+
+    let commitment = ctx
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ))
+        .expect("no error");
+
+    // The identity hasher leaves the packet data as the tail of the commitment.
+    assert_eq!(commitment, packet.commitment_with(&IdentityHasher));
+    assert!(commitment.as_ref().ends_with(&packet.data));
+    assert_ne!(commitment, packet.commitment());
+
+    let response = query_channel_params(&ctx.ibc_store, &QueryChannelParamsRequest).unwrap();
+    assert_eq!(
+        response.packet_commitment_algorithm,
+        HashAlgorithm::Other("identity".to_string())
+    );
+}
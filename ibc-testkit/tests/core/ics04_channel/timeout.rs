@@ -1,15 +1,21 @@
+use core::time::Duration;
+
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
@@ -19,7 +25,7 @@ use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, DEFAULT_BLOCK_TIME_SECS};
 use rstest::*;
 
 struct Fixture {
@@ -225,10 +231,139 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
 
     assert!(
             res.is_err(),
-            "Validation should fail because the timeout height was reached, but the timestamp hasn't been reached. Both the height and timestamp need to be reached for the packet to be considered timed out"
+            "Validation should fail because neither the timeout height nor the timeout timestamp has been reached"
         )
 }
 
+/// A packet is timed out as soon as either its timeout height or its timeout
+/// timestamp has passed on the counterparty, regardless of the channel ordering.
+#[rstest]
+#[case::only_height_passed(true, false)]
+#[case::only_timestamp_passed(false, true)]
+#[case::both_passed(true, true)]
+fn timeout_height_or_timestamp_passed(
+    fixture: Fixture,
+    #[case] height_passed: bool,
+    #[case] timestamp_passed: bool,
+    #[values(Order::Ordered, Order::Unordered)] ordering: Order,
+) {
+    let Fixture {
+        ctx,
+        mut router,
+        mut msg,
+        conn_end_on_a,
+        chan_end_on_a_unordered: mut chan_end_on_a,
+        ..
+    } = fixture;
+
+    // the fixture's timeout height is above the proof height, while its
+    // timeout timestamp is below the counterparty's timestamp
+    if height_passed {
+        msg.packet.timeout_height_on_b =
+            TimeoutHeight::At(Height::new(0, msg.proof_height_on_b.revision_height() - 1).unwrap());
+    }
+    if !timestamp_passed {
+        msg.packet.timeout_timestamp_on_b =
+            (msg.packet.timeout_timestamp_on_b + core::time::Duration::new(10, 0)).unwrap();
+    }
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+
+    chan_end_on_a.ordering = ordering;
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation should succeed once either the timeout height or timestamp has passed"
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+    assert!(ctx
+        .get_events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::TimeoutPacket(_))));
+}
+
+/// A packet without a timeout height times out once the counterparty's
+/// timestamp at the proof height has passed its timeout timestamp, and is
+/// otherwise rejected with a timestamp-specific error.
+#[rstest]
+#[case::timestamp_before(false)]
+#[case::timestamp_after(true)]
+fn timeout_timestamp_only(fixture: Fixture, #[case] timestamp_passed: bool) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    msg.packet.timeout_height_on_b = TimeoutHeight::Never;
+    if !timestamp_passed {
+        msg.packet.timeout_timestamp_on_b =
+            (msg.packet.timeout_timestamp_on_b + core::time::Duration::new(10, 0)).unwrap();
+    }
+    let packet_commitment = compute_packet_commitment(
+        &msg.packet.data,
+        &msg.packet.timeout_height_on_b,
+        &msg.packet.timeout_timestamp_on_b,
+    );
+    let timeout_timestamp_on_b = msg.packet.timeout_timestamp_on_b;
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    if timestamp_passed {
+        assert!(
+            res.is_ok(),
+            "Validation should succeed once the timeout timestamp has passed. err: {res:?}"
+        );
+    } else {
+        match res {
+            Err(ContextError::PacketError(PacketError::PacketTimeoutTimestampNotReached {
+                timeout_timestamp,
+                ..
+            })) => assert_eq!(timeout_timestamp, timeout_timestamp_on_b),
+            other => panic!("expected a `PacketTimeoutTimestampNotReached` error, got {other:?}"),
+        }
+    }
+}
+
 /// NO-OP case
 #[rstest]
 fn timeout_success_no_packet_commitment(fixture: Fixture) {
@@ -334,6 +469,98 @@ fn timeout_ordered_channel_validate(fixture: Fixture) {
     assert!(res.is_ok(), "Good parameters for unordered channels")
 }
 
+/// A timeout can only be processed once both the time and the number of
+/// blocks of the connection delay period have passed since the client was
+/// updated at the proof height.
+#[rstest]
+fn timeout_delay_period(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        chan_end_on_a_unordered,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let delay_blocks = 3;
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+    )
+    .unwrap();
+
+    let mut ctx = ctx
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay period hasn't passed. err: {res:?}"
+    );
+
+    // the whole delay time passes within a single block
+    ctx.advance_block_height_with_params(
+        Duration::from_secs(delay_blocks * DEFAULT_BLOCK_TIME_SECS),
+        &Default::default(),
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        ),
+        "Validation fails because the delay blocks haven't passed. err: {res:?}"
+    );
+
+    for _ in 1..delay_blocks {
+        ctx.advance_block_height();
+    }
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the delay period has passed. err: {res:?}"
+    );
+}
+
 #[rstest]
 fn timeout_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
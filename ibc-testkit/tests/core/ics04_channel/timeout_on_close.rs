@@ -8,9 +8,12 @@ use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
-use ibc::core::entrypoint::validate;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout_on_close;
@@ -160,3 +163,61 @@ fn timeout_on_close_success_happy_path(fixture: Fixture) {
         "Happy path: validation should succeed. err: {res:?}"
     )
 }
+
+/// Once the ordered channel is closed on the counterparty, a pending packet is
+/// timed out: its commitment is deleted and the channel closed on our side.
+#[rstest]
+fn timeout_on_close_execute_ordered_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let commitment_path = CommitmentPath::new(
+        &msg.packet.port_id_on_a,
+        &msg.packet.chan_id_on_a,
+        msg.packet.seq_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    validate(&context.ibc_store, &router, msg_envelope.clone()).unwrap();
+    execute(&mut context.ibc_store, &mut router, msg_envelope).unwrap();
+
+    assert!(context
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_err());
+
+    let chan_end_on_a = context
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Closed);
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+    assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
+}
@@ -1,3 +1,4 @@
+pub mod entrypoint;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
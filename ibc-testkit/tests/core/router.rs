@@ -3,7 +3,7 @@ use core::ops::Add;
 use ibc::apps::transfer::handler::send_transfer;
 use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
-use ibc::apps::transfer::types::{BaseCoin, U256};
+use ibc::apps::transfer::types::{BaseCoin, MODULE_ID_STR, U256};
 use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
@@ -13,15 +13,20 @@ use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::ConnectionMsg;
-use ibc::core::entrypoint::dispatch;
+use ibc::core::entrypoint::{dispatch, dispatch_batch};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::router::Router;
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc_query::core::port::{query_port, QueryPortRequest};
+use ibc_query::error::QueryError;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
@@ -418,11 +423,13 @@ fn routing_module_and_keepers() {
     for test in tests {
         let res = match test.msg.clone() {
             TestMsg::Ics26(msg) => dispatch(&mut ctx.ibc_store, &mut router, msg),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule, msg)
-                .map_err(|e: TokenTransferError| ChannelError::AppModule {
-                    description: e.to_string(),
-                })
-                .map_err(ContextError::from),
+            TestMsg::Ics20(msg) => {
+                send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule::new(), msg)
+                    .map_err(|e: TokenTransferError| ChannelError::AppModule {
+                        description: e.to_string(),
+                    })
+                    .map_err(ContextError::from)
+            }
         };
 
         assert_eq!(
@@ -446,3 +453,195 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn query_port_binding() {
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let oracle_port_id: PortId = "oracle".parse().unwrap();
+
+    let ctx = MockContext::default().with_port_binding(PortId::transfer(), module_id.clone());
+
+    let request = QueryPortRequest {
+        port_id: PortId::transfer(),
+    };
+    let response = query_port(&ctx.ibc_store, &request).unwrap();
+
+    assert_eq!(response.module_id, module_id.to_string());
+
+    let request = QueryPortRequest {
+        port_id: oracle_port_id,
+    };
+    let res = query_port(&ctx.ibc_store, &request);
+
+    assert!(
+        matches!(
+            res,
+            Err(QueryError::ContextError(ContextError::RouterError(
+                RouterError::UnknownPort { .. }
+            )))
+        ),
+        "unbound ports must not resolve to a module"
+    );
+}
+
+#[test]
+fn dispatch_batch_too_many_messages() {
+    let mut ctx = MockContext::default().with_max_messages_per_tx(1);
+    let mut router = MockRouter::new_with_transfer();
+
+    let create_client_msg = |revision_height| {
+        let header =
+            MockHeader::new(Height::new(0, revision_height).unwrap()).with_current_timestamp();
+        MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+            MockClientState::new(header).into(),
+            MockConsensusState::new(header).into(),
+            dummy_account_id(),
+        )))
+    };
+
+    let res = dispatch_batch(
+        &mut ctx.ibc_store,
+        &mut router,
+        vec![create_client_msg(42), create_client_msg(43)],
+    );
+
+    assert!(
+        matches!(res, Err(ContextError::TooManyMessages { count: 2, max: 1 })),
+        "transactions over the cap must be rejected"
+    );
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 0);
+
+    dispatch_batch(&mut ctx.ibc_store, &mut router, vec![create_client_msg(42)])
+        .expect("transactions within the cap are dispatched");
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 1);
+}
+
+#[test]
+fn dispatch_batch_reverts_on_failure() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let create_client_msg = |revision_height| {
+        let header =
+            MockHeader::new(Height::new(0, revision_height).unwrap()).with_current_timestamp();
+        MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+            MockClientState::new(header).into(),
+            MockConsensusState::new(header).into(),
+            dummy_account_id(),
+        )))
+    };
+
+    // fails since the connection's client doesn't exist
+    let conn_open_init_msg = MsgEnvelope::from(ConnectionMsg::from(dummy_msg_conn_open_init()));
+
+    let res = dispatch_batch(
+        &mut ctx.ibc_store,
+        &mut router,
+        vec![
+            create_client_msg(42),
+            conn_open_init_msg,
+            create_client_msg(43),
+        ],
+    );
+
+    assert!(
+        matches!(res, Err(ContextError::ClientError(_))),
+        "the batch fails on its second message, got {res:?}"
+    );
+    assert_eq!(
+        ctx.ibc_store.client_counter().unwrap(),
+        0,
+        "the client created by the first message must be reverted"
+    );
+
+    dispatch_batch(
+        &mut ctx.ibc_store,
+        &mut router,
+        vec![create_client_msg(42), create_client_msg(43)],
+    )
+    .expect("a batch of valid messages is dispatched");
+    assert_eq!(ctx.ibc_store.client_counter().unwrap(), 2);
+}
+
+/// A module only receives callbacks for the ports it is bound to, even if the
+/// router routes another port to it.
+#[test]
+fn dispatch_rejects_unbound_port() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let oracle_port_id: PortId = "oracle".parse().unwrap();
+    router
+        .scope_port_to_module(
+            oracle_port_id.clone(),
+            ModuleId::new(MODULE_ID_STR.to_string()),
+        )
+        .unwrap();
+
+    let msg = MsgEnvelope::from(ChannelMsg::from(MsgChannelOpenInit {
+        port_id_on_a: oracle_port_id.clone(),
+        ..MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap()
+    }));
+
+    match dispatch(&mut ctx.ibc_store, &mut router, msg) {
+        Err(ContextError::RouterError(RouterError::PortNotBound { port_id })) => {
+            assert_eq!(port_id, oracle_port_id)
+        }
+        other => panic!("expected a `PortNotBound` error, got {other:?}"),
+    }
+}
+
+#[test]
+fn router_rejects_duplicate_registrations() {
+    let mut router = MockRouter::new_with_transfer();
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+
+    assert!(
+        router
+            .add_route(module_id.clone(), DummyTransferModule::new())
+            .is_err(),
+        "a module id can only be registered once"
+    );
+    assert_eq!(router.router.len(), 1);
+
+    let other_module_id = ModuleId::new("othermodule".to_string());
+
+    assert!(
+        router
+            .scope_port_to_module(PortId::transfer(), other_module_id.clone())
+            .is_err(),
+        "a port can only be bound once"
+    );
+    assert_eq!(router.lookup_module(&PortId::transfer()), Some(module_id));
+
+    router
+        .scope_port_to_module("oracle".parse().unwrap(), other_module_id.clone())
+        .expect("the oracle port is unbound");
+    assert_eq!(
+        router.lookup_module(&"oracle".parse().unwrap()),
+        Some(other_module_id)
+    );
+}
+
+#[test]
+fn router_introspection() {
+    let mut router = MockRouter::new_with_transfer();
+    let transfer_module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let oracle_port_id: PortId = "oracle".parse().unwrap();
+
+    router
+        .scope_port_to_module(oracle_port_id.clone(), transfer_module_id.clone())
+        .unwrap();
+
+    assert_eq!(
+        router.module_ids().collect::<Vec<_>>(),
+        vec![&transfer_module_id]
+    );
+    assert_eq!(
+        router.bound_ports().collect::<Vec<_>>(),
+        vec![&oracle_port_id, &PortId::transfer()]
+    );
+
+    assert!(router.has_route(&transfer_module_id));
+    assert!(!router.has_route(&ModuleId::new("othermodule".to_string())));
+}